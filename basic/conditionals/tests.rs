@@ -0,0 +1,241 @@
+// 条件语句的隐藏测试
+// cargo run -- check conditionals 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数
+
+mod check_age {
+    use super::*;
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(check_age(0), "儿童");
+        assert_eq!(check_age(12), "儿童", "12 岁还是儿童");
+        assert_eq!(check_age(13), "青少年", "13 岁开始是青少年");
+        assert_eq!(check_age(17), "青少年", "17 岁还是青少年");
+        assert_eq!(check_age(18), "成年人", "18 岁开始是成年人");
+        assert_eq!(check_age(64), "成年人", "64 岁还是成年人");
+        assert_eq!(check_age(65), "老年人", "65 岁开始是老年人");
+    }
+}
+
+mod get_weekday {
+    use super::*;
+
+    #[test]
+    fn valid_days() {
+        assert_eq!(get_weekday(1), "星期一");
+        assert_eq!(get_weekday(3), "星期三");
+        assert_eq!(get_weekday(7), "星期日");
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(get_weekday(0).contains("无效"), "0 不是有效的星期");
+        assert!(get_weekday(8).contains("无效"), "8 不是有效的星期");
+    }
+}
+
+mod is_even {
+    use super::*;
+
+    #[test]
+    fn even_and_odd() {
+        assert_eq!(is_even(0), "偶数");
+        assert_eq!(is_even(4), "偶数");
+        assert_eq!(is_even(7), "奇数");
+        assert_eq!(is_even(-3), "奇数", "负奇数的余数是 -1，不是 1");
+    }
+}
+
+mod get_absolute_value {
+    use super::*;
+
+    #[test]
+    fn positive_negative_zero() {
+        assert_eq!(get_absolute_value(5), 5);
+        assert_eq!(get_absolute_value(-5), 5);
+        assert_eq!(get_absolute_value(0), 0);
+    }
+}
+
+mod check_access {
+    use super::*;
+
+    #[test]
+    fn allowed() {
+        assert_eq!(check_access("alice", "secret", true), "访问允许");
+    }
+
+    #[test]
+    fn denied() {
+        assert_ne!(check_access("", "secret", true), "访问允许", "缺少用户名");
+        assert_ne!(check_access("alice", "", true), "访问允许", "缺少密码");
+        assert_ne!(
+            check_access("alice", "secret", false),
+            "访问允许",
+            "账户未激活"
+        );
+    }
+}
+
+mod get_user_permissions {
+    use super::*;
+
+    fn user(role: Role, age: u32, is_active: bool) -> User {
+        User {
+            name: "测试用户".to_string(),
+            age,
+            role,
+            is_active,
+        }
+    }
+
+    #[test]
+    fn admin_has_everything() {
+        let permissions = get_user_permissions(&user(Role::Admin, 30, true));
+        for expected in ["login", "read", "write", "delete", "manage_users"] {
+            assert!(
+                permissions.contains(&expected),
+                "管理员应该有 {} 权限",
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn guest_can_only_read() {
+        let permissions = get_user_permissions(&user(Role::Guest, 30, true));
+        assert!(permissions.contains(&"read"));
+        assert!(!permissions.contains(&"write"), "访客不能写入");
+    }
+
+    #[test]
+    fn adult_content_needs_age() {
+        let adult = get_user_permissions(&user(Role::User, 18, true));
+        let minor = get_user_permissions(&user(Role::User, 17, true));
+        assert!(adult.contains(&"access_adult_content"));
+        assert!(!minor.contains(&"access_adult_content"));
+    }
+
+    #[test]
+    fn inactive_has_none() {
+        let permissions = get_user_permissions(&user(Role::Admin, 30, false));
+        assert!(permissions.is_empty(), "未激活的账户没有任何权限");
+    }
+}
+
+mod process_value {
+    use super::*;
+
+    #[test]
+    fn text() {
+        assert!(process_value(Value::Text("hello".to_string())).contains("HELLO"));
+        assert_eq!(process_value(Value::Text(String::new())), "空字符串");
+    }
+
+    #[test]
+    fn number() {
+        assert!(process_value(Value::Number(42)).contains("正数"));
+        assert!(process_value(Value::Number(-1)).contains("负数"));
+        assert_eq!(process_value(Value::Number(0)), "零");
+    }
+
+    #[test]
+    fn boolean() {
+        assert_eq!(process_value(Value::Boolean(true)), "真值");
+        assert_eq!(process_value(Value::Boolean(false)), "假值");
+    }
+}
+
+mod process_optional_value {
+    use super::*;
+
+    #[test]
+    fn some_empty_none() {
+        assert_eq!(process_optional_value(Some("hello")), "有值");
+        assert_eq!(process_optional_value(Some("")), "空字符串");
+        assert_eq!(process_optional_value(None), "无值");
+    }
+}
+
+mod analyze_data {
+    use super::*;
+
+    #[test]
+    fn by_length() {
+        assert_eq!(analyze_data(&[]), "空数组");
+        assert_eq!(analyze_data(&[1]), "单元素数组");
+        assert_eq!(analyze_data(&[1, 2, 3, 4, 5]), "小数组");
+        assert_eq!(analyze_data(&[0; 6]), "中等数组");
+        assert_eq!(analyze_data(&[0; 101]), "大数组");
+    }
+}
+
+mod calculate_shipping {
+    use super::*;
+
+    #[test]
+    fn without_priority() {
+        assert_eq!(calculate_shipping(0.5, 100.0, false), 5.0);
+        assert_eq!(calculate_shipping(8.0, 1200.0, false), 40.0);
+    }
+
+    #[test]
+    fn with_priority() {
+        assert_eq!(calculate_shipping(3.0, 600.0, true), 19.5);
+        assert_eq!(calculate_shipping(15.0, 300.0, true), 39.0);
+    }
+}
+
+mod get_display_name {
+    use super::*;
+
+    #[test]
+    fn full_name() {
+        assert_eq!(
+            get_display_name(Some("张"), Some("三"), Some("zhangsan")),
+            "张 三"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_username() {
+        assert_eq!(
+            get_display_name(Some("张"), None, Some("zhangsan")),
+            "zhangsan"
+        );
+        assert_eq!(get_display_name(None, None, None), "匿名用户");
+    }
+}
+
+mod calculate_base_cost {
+    use super::*;
+
+    #[test]
+    fn by_weight() {
+        assert_eq!(calculate_base_cost(1.0), 5.0);
+        assert_eq!(calculate_base_cost(5.0), 10.0);
+        assert_eq!(calculate_base_cost(10.0), 20.0);
+        assert_eq!(calculate_base_cost(10.5), 30.0);
+    }
+}
+
+mod apply_distance_multiplier {
+    use super::*;
+
+    #[test]
+    fn by_distance() {
+        assert_eq!(apply_distance_multiplier(10.0, 500.0), 10.0);
+        assert_eq!(apply_distance_multiplier(10.0, 501.0), 15.0);
+        assert_eq!(apply_distance_multiplier(10.0, 1001.0), 20.0);
+    }
+}
+
+mod apply_priority_fee {
+    use super::*;
+
+    #[test]
+    fn priority_adds_thirty_percent() {
+        assert_eq!(apply_priority_fee(10.0, false), 10.0);
+        assert!((apply_priority_fee(10.0, true) - 13.0).abs() < 1e-9);
+    }
+}
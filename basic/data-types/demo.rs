@@ -1,5 +1,7 @@
 // Rust 中的数据类型练习
 // 请根据 TypeScript 示例，完成以下 Rust 代码
+// 还没完成的函数体是 todo!()，整个文件始终可以编译，完成一个练习就能运行一个练习
+#![allow(dead_code, unused_variables)]
 
 fn main() {
     println!("=== Rust 数据类型演示 ===");
@@ -74,52 +76,51 @@ fn main() {
 
 // TODO: 定义 User 结构体
 // 提示：使用 #[derive(Debug)] 来自动实现 Debug trait
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct User {
     // TODO: 添加字段
     
 }
 
 // TODO: 定义 Address 结构体
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Address {
     // TODO: 添加字段
     
 }
 
 // TODO: 定义 UserWithAddress 结构体
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct UserWithAddress {
     // TODO: 添加字段
     
 }
 
 // TODO: 定义 Status 枚举
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 enum Status {
     // TODO: 添加变体
     
 }
 
 // TODO: 定义 Product 结构体
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Product {
     // TODO: 添加字段
     
 }
 
 // TODO: 定义 Category 结构体
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Category {
     // TODO: 添加字段
     
 }
 
-// TODO: 定义泛型 Container 结构体
+// 泛型 Container 结构体（字段已经给出：泛型参数必须在字段中用到，否则无法编译）
 #[derive(Debug)]
 struct Container<T> {
-    // TODO: 添加字段
-    
+    value: T,
 }
 
 // TODO: 为 Container 实现方法
@@ -135,25 +136,25 @@ impl<T> Container<T> {
 // 提示：返回格式化的用户信息字符串
 fn process_user(user: &User) -> String {
     // TODO: 实现函数体
-    
+    todo!()
 }
 
 // TODO: 实现 calculate_total 函数
 // 提示：计算所有产品的总价
 fn calculate_total(products: &[Product]) -> f64 {
     // TODO: 实现函数体
-    
+    todo!()
 }
 
 // TODO: 实现 match_status 函数
 // 提示：使用 match 表达式处理不同的状态
 fn match_status(status: &Status) -> &'static str {
     // TODO: 实现函数体
-    
+    todo!()
 }
 
-// 编译并运行：
-// rustc demo.rs && ./demo
+// 编译并运行（在项目根目录执行，编译产物放在临时目录）：
+// cargo run -- run data-types
 //
 // 或者使用 Cargo：
 // cargo init
@@ -0,0 +1,100 @@
+// 数据类型的隐藏测试
+// cargo run -- check data-types 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数或类型
+
+mod process_user {
+    use super::*;
+
+    fn user(age: Option<u32>) -> User {
+        User {
+            id: 1,
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            age,
+            is_active: true,
+        }
+    }
+
+    #[test]
+    fn with_age() {
+        let text = process_user(&user(Some(25)));
+        assert!(text.contains("Alice"), "应该包含用户名: {}", text);
+        assert!(text.contains("alice@example.com"), "应该包含邮箱: {}", text);
+        assert!(text.contains("25"), "有年龄时应该显示年龄: {}", text);
+    }
+
+    #[test]
+    fn without_age() {
+        let text = process_user(&user(None));
+        assert!(text.contains("Alice"), "应该包含用户名: {}", text);
+        assert!(!text.contains("None"), "没有年龄时不要输出 None: {}", text);
+    }
+}
+
+mod calculate_total {
+    use super::*;
+
+    fn product(id: u32, price: f64) -> Product {
+        Product {
+            id,
+            name: format!("商品 {}", id),
+            price,
+            tags: vec!["测试".to_string()],
+            category: Category {
+                id: 1,
+                name: "分类".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn sums_prices() {
+        let products = vec![product(1, 999.99), product(2, 25.5)];
+        assert!((calculate_total(&products) - 1025.49).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_list() {
+        assert_eq!(calculate_total(&[]), 0.0);
+    }
+}
+
+mod match_status {
+    use super::*;
+
+    #[test]
+    fn every_variant() {
+        assert_eq!(match_status(&Status::Pending), "等待中");
+        assert_eq!(match_status(&Status::Approved), "已批准");
+        assert_eq!(match_status(&Status::Rejected), "已拒绝");
+    }
+}
+
+mod container {
+    use super::*;
+
+    #[test]
+    fn holds_any_type() {
+        assert_eq!(*Container::new(42).get_value(), 42);
+        assert_eq!(Container::new("hello").get_value(), &"hello");
+        assert_eq!(Container::new(vec![1, 2, 3]).get_value().len(), 3);
+    }
+}
+
+mod user_with_address {
+    use super::*;
+
+    #[test]
+    fn nested_struct() {
+        let user = UserWithAddress {
+            id: 2,
+            name: "Bob".to_string(),
+            address: Address {
+                street: "中山路 1 号".to_string(),
+                city: "上海".to_string(),
+                zip_code: "200000".to_string(),
+            },
+        };
+        assert_eq!(user.address.city, "上海");
+    }
+}
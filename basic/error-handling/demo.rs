@@ -1,6 +1,8 @@
 // Rust 中的错误处理练习
 // 请根据 TypeScript 示例，完成以下 Rust 代码
 // Rust 使用 Result 和 Option 类型处理错误
+// 还没完成的函数体是 todo!()，整个文件始终可以编译，完成一个练习就能运行一个练习
+#![allow(dead_code, unused_variables)]
 
 fn main() {
     println!("=== Rust 错误处理演示 ===");
@@ -38,14 +40,14 @@ fn main() {
 // 提示：返回 Result<f64, String>
 fn divide(a: f64, b: f64) -> Result<f64, String> {
     // TODO: 检查除零情况
-    
+    todo!()
 }
 
 // TODO: 实现 parse_number 函数
 // 提示：解析字符串为数字，返回 Result
 fn parse_number(s: &str) -> Result<i32, String> {
     // TODO: 尝试解析字符串
-    
+    todo!()
 }
 
 // TODO: 定义 User 结构体
@@ -59,7 +61,7 @@ struct User {
 // 提示：返回 Option<User>
 fn find_user(id: u32) -> Option<User> {
     // TODO: 模拟查找用户
-    
+    todo!()
 }
 
 // TODO: 定义自定义错误枚举
@@ -74,65 +76,65 @@ use std::fmt;
 impl fmt::Display for MyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // TODO: 实现错误显示
-        
+        todo!()
     }
 }
 
 // TODO: 实现 validate_age 函数
-fn validate_age(age: i32) -> Result<i32, MyError> {
+fn validate_age(age: i32) -> Result<u32, MyError> {
     // TODO: 验证年龄范围
-    
+    todo!()
 }
 
 // TODO: 实现 validate_email 函数
 fn validate_email(email: &str) -> Result<String, MyError> {
     // TODO: 验证邮箱格式
-    
+    todo!()
 }
 
 // TODO: 实现 create_user_safe 函数
 // 提示：使用 ? 运算符处理多个可能的错误
 fn create_user_safe(name: &str, age: i32, email: &str) -> Result<User, MyError> {
     // TODO: 验证所有字段并创建用户
-    
+    todo!()
 }
 
 // TODO: 实现 process_file 函数
 // 提示：模拟文件处理，可能失败
 fn process_file(filename: &str) -> Result<String, MyError> {
     // TODO: 模拟文件读取和处理
-    
+    todo!()
 }
 
 // TODO: 实现 safe_divide_and_parse 函数
 // 提示：组合多个可能失败的操作
 fn safe_divide_and_parse(a: &str, b: &str) -> Result<f64, String> {
     // TODO: 解析两个字符串并执行除法
-    
+    todo!()
 }
 
 // TODO: 实现 unwrap_example 函数
 fn unwrap_example() {
     // TODO: 演示 unwrap、expect 等方法
-    
+    todo!()
 }
 
 // TODO: 实现 option_methods 函数
 fn option_methods() {
     // TODO: 演示 Option 的各种方法
     // map、and_then、unwrap_or、unwrap_or_else 等
-    
+    todo!()
 }
 
 // TODO: 实现 result_methods 函数
 fn result_methods() {
     // TODO: 演示 Result 的各种方法
     // map、map_err、and_then、unwrap_or_else 等
-    
+    todo!()
 }
 
-// 编译并运行：
-// rustc demo.rs && ./demo
+// 编译并运行（在项目根目录执行，编译产物放在临时目录）：
+// cargo run -- run error-handling
 //
 // 或者使用 Cargo：
 // cargo run --example error_handling 
\ No newline at end of file
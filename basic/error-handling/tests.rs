@@ -0,0 +1,145 @@
+// 错误处理的隐藏测试
+// cargo run -- check error-handling 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数
+
+mod divide {
+    use super::*;
+
+    #[test]
+    fn ok() {
+        assert_eq!(divide(10.0, 2.0), Ok(5.0));
+    }
+
+    #[test]
+    fn by_zero() {
+        assert!(divide(10.0, 0.0).is_err(), "除数为 0 时应该返回 Err");
+    }
+}
+
+mod parse_number {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(parse_number("123"), Ok(123));
+        assert_eq!(parse_number("-7"), Ok(-7));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse_number("abc").is_err());
+        assert!(parse_number("").is_err());
+    }
+}
+
+mod find_user {
+    use super::*;
+
+    #[test]
+    fn known_ids() {
+        assert!(find_user(1).is_some(), "id 1 应该能找到用户");
+        assert!(find_user(2).is_some(), "id 2 应该能找到用户");
+    }
+
+    #[test]
+    fn unknown_id() {
+        assert!(find_user(999).is_none(), "不存在的用户应该返回 None");
+    }
+}
+
+mod validate_age {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(validate_age(0).unwrap(), 0);
+        assert_eq!(validate_age(25).unwrap(), 25);
+        assert_eq!(validate_age(150).unwrap(), 150);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(validate_age(-5).is_err(), "负数年龄无效");
+        assert!(validate_age(151).is_err(), "超过 150 的年龄无效");
+    }
+}
+
+mod validate_email {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            validate_email("alice@example.com").unwrap(),
+            "alice@example.com"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(
+            validate_email("invalid-email").is_err(),
+            "没有 @ 的邮箱无效"
+        );
+    }
+}
+
+mod create_user_safe {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+        let user = create_user_safe("Alice", 25, "alice@example.com");
+        assert!(user.is_ok(), "合法输入应该创建成功: {:?}", user);
+    }
+
+    #[test]
+    fn propagates_errors() {
+        assert!(
+            create_user_safe("", 25, "alice@example.com").is_err(),
+            "姓名为空"
+        );
+        assert!(
+            create_user_safe("Alice", -5, "alice@example.com").is_err(),
+            "年龄无效"
+        );
+        assert!(
+            create_user_safe("Alice", 25, "invalid-email").is_err(),
+            "邮箱无效"
+        );
+    }
+}
+
+mod process_file {
+    use super::*;
+
+    #[test]
+    fn existing_file() {
+        assert!(process_file("document.txt").is_ok());
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = process_file("missing.txt").unwrap_err();
+        assert!(
+            error.to_string().contains("missing.txt"),
+            "错误信息应该包含文件名: {}",
+            error
+        );
+    }
+}
+
+mod safe_divide_and_parse {
+    use super::*;
+
+    #[test]
+    fn ok() {
+        assert_eq!(safe_divide_and_parse("10", "2"), Ok(5.0));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(safe_divide_and_parse("abc", "2").is_err(), "无法解析的输入");
+        assert!(safe_divide_and_parse("10", "0").is_err(), "除以零");
+    }
+}
@@ -1,5 +1,7 @@
 // Rust 中的函数练习
 // 请根据 TypeScript 示例，完成以下 Rust 代码
+// 还没完成的函数体是 todo!()，整个文件始终可以编译，完成一个练习就能运行一个练习
+#![allow(dead_code, unused_variables)]
 
 fn main() {
     println!("=== Rust 函数演示 ===");
@@ -45,40 +47,40 @@ fn main() {
 // 提示：接受 &str 参数，返回 String
 fn greet(name: &str) -> String {
     // TODO: 返回问候语
-    
+    todo!()
 }
 
 // TODO: 实现 multiply 函数
 // 提示：接受两个 i32 参数，返回 i32
 fn multiply(a: i32, b: i32) -> i32 {
     // TODO: 返回乘积
-    
+    todo!()
 }
 
 // TODO: 实现 create_user 函数
 // 提示：使用 Option<T> 处理可选参数
 fn create_user(name: String, age: Option<u32>) -> String {
     // TODO: 根据是否有年龄返回不同格式
-    
+    todo!()
 }
 
 // TODO: 实现 power 函数（基础版本）
 fn power(base: f64, exponent: f64) -> f64 {
     // TODO: 计算幂
-    
+    todo!()
 }
 
 // TODO: 实现带默认值的 power 函数
 fn power_default(base: f64) -> f64 {
     // TODO: 使用默认指数 2.0
-    
+    todo!()
 }
 
 // TODO: 实现 sum 函数
 // 提示：接受切片 &[i32]，返回 i32
 fn sum(numbers: &[i32]) -> i32 {
     // TODO: 计算所有数字的和
-    
+    todo!()
 }
 
 // TODO: 实现 apply_operation 函数
@@ -88,32 +90,32 @@ where
     F: Fn(i32, i32) -> i32,
 {
     // TODO: 应用操作函数
-    
+    todo!()
 }
 
 // TODO: 实现 divide 函数
 // 提示：返回 Result<f64, String> 处理除零错误
 fn divide(a: f64, b: f64) -> Result<f64, String> {
     // TODO: 安全除法，处理除零情况
-    
+    todo!()
 }
 
 // TODO: 实现 factorial 函数
 // 提示：递归计算阶乘
 fn factorial(n: u32) -> u32 {
     // TODO: 递归实现
-    
+    todo!()
 }
 
 // TODO: 实现 fibonacci 函数
 // 提示：递归计算斐波那契数列
 fn fibonacci(n: u32) -> u32 {
     // TODO: 递归实现
-    
+    todo!()
 }
 
-// 编译并运行：
-// rustc demo.rs && ./demo
+// 编译并运行（在项目根目录执行，编译产物放在临时目录）：
+// cargo run -- run functions
 //
 // 或者使用 Cargo：
 // cargo run --example functions 
\ No newline at end of file
@@ -0,0 +1,114 @@
+// 函数的隐藏测试
+// cargo run -- check functions 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数
+
+mod greet {
+    use super::*;
+
+    #[test]
+    fn includes_name() {
+        assert_eq!(greet("Alice"), "Hello, Alice!");
+    }
+}
+
+mod multiply {
+    use super::*;
+
+    #[test]
+    fn products() {
+        assert_eq!(multiply(5, 3), 15);
+        assert_eq!(multiply(-4, 3), -12);
+        assert_eq!(multiply(7, 0), 0);
+    }
+}
+
+mod create_user {
+    use super::*;
+
+    #[test]
+    fn with_age() {
+        let text = create_user("Bob".to_string(), Some(25));
+        assert!(text.contains("Bob") && text.contains("25"), "{}", text);
+    }
+
+    #[test]
+    fn without_age() {
+        let text = create_user("Charlie".to_string(), None);
+        assert!(text.contains("Charlie"), "{}", text);
+        assert!(!text.contains("None"), "没有年龄时不要输出 None: {}", text);
+    }
+}
+
+mod power {
+    use super::*;
+
+    #[test]
+    fn powers() {
+        assert_eq!(power(2.0, 3.0), 8.0);
+        assert_eq!(power(9.0, 0.5), 3.0);
+    }
+}
+
+mod power_default {
+    use super::*;
+
+    #[test]
+    fn squares() {
+        assert_eq!(power_default(5.0), 25.0);
+    }
+}
+
+mod sum {
+    use super::*;
+
+    #[test]
+    fn sums_slice() {
+        assert_eq!(sum(&[1, 2, 3, 4, 5]), 15);
+        assert_eq!(sum(&[]), 0);
+    }
+}
+
+mod apply_operation {
+    use super::*;
+
+    #[test]
+    fn closures() {
+        assert_eq!(apply_operation(10, 5, |a, b| a + b), 15);
+        assert_eq!(apply_operation(10, 5, |a, b| a - b), 5);
+    }
+}
+
+mod divide {
+    use super::*;
+
+    #[test]
+    fn ok() {
+        assert_eq!(divide(10.0, 2.0), Ok(5.0));
+    }
+
+    #[test]
+    fn by_zero() {
+        assert!(divide(10.0, 0.0).is_err(), "除数为 0 时应该返回 Err");
+    }
+}
+
+mod factorial {
+    use super::*;
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(factorial(0), 1, "0! = 1");
+        assert_eq!(factorial(1), 1);
+        assert_eq!(factorial(5), 120);
+    }
+}
+
+mod fibonacci {
+    use super::*;
+
+    #[test]
+    fn sequence() {
+        let first: Vec<u32> = (0..10).map(fibonacci).collect();
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+}
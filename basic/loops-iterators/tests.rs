@@ -0,0 +1,43 @@
+// 循环和迭代器的隐藏测试
+// cargo run -- check loops-iterators 会把这个文件和 demo.rs 一起编译运行，
+// 其他练习函数只负责打印，这里只测试自定义迭代器 Counter
+
+mod counter {
+    use super::*;
+
+    #[test]
+    fn yields_one_to_max() {
+        let values: Vec<usize> = Counter::new(5).collect();
+        assert_eq!(
+            values,
+            vec![1, 2, 3, 4, 5],
+            "Counter::new(5) 应该依次产生 1..=5"
+        );
+    }
+
+    #[test]
+    fn stops_after_max() {
+        let mut counter = Counter::new(2);
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), None, "超过 max 后应该返回 None");
+        assert_eq!(counter.next(), None, "结束后继续调用 next 仍然返回 None");
+    }
+
+    #[test]
+    fn zero_is_empty() {
+        assert_eq!(Counter::new(0).count(), 0);
+    }
+
+    #[test]
+    fn works_with_adapters() {
+        let sum: usize = Counter::new(5)
+            .zip(Counter::new(5).skip(1))
+            .map(|(a, b)| a * b)
+            .sum();
+        assert_eq!(
+            sum, 40,
+            "实现 Iterator 后可以直接使用 zip / skip / map / sum"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+// 编译失败练习：移动后继续使用 (E0382)
+// 这个文件的目标是让编译器报错！对应 demo.rs 中的练习 1。
+// 把 s1 赋值给 s2 之后，s1 的所有权已经转移，再使用 s1 就会得到 E0382。
+// 标有 //~ 的注释说明了错误应该出现的位置，check 会确认错误码和行号都符合预期。
+
+fn main() {
+    let s1 = String::from("hello");
+
+    // TODO: 把 s1 赋值给 s2（所有权转移）
+
+
+    // TODO: 在下一行同时打印 s1 和 s2
+    //~v ERROR E0382
+
+}
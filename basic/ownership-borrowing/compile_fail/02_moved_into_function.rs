@@ -0,0 +1,19 @@
+// 编译失败练习：传给函数后继续使用 (E0382)
+// 这个文件的目标是让编译器报错！对应 demo.rs 中的练习 2。
+// 把 String 传给 take_ownership 之后，所有权随参数进入函数，调用者不能再使用它。
+// 标有 //~ 的注释说明了错误应该出现的位置，check 会确认错误码和行号都符合预期。
+
+fn take_ownership(s: String) {
+    println!("取得所有权: {}", s);
+}
+
+fn main() {
+    let s = String::from("world");
+
+    // TODO: 调用 take_ownership，把 s 传进去
+
+
+    // TODO: 在下一行打印 s
+    //~v ERROR E0382
+
+}
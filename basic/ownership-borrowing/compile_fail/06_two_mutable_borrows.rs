@@ -0,0 +1,18 @@
+// 编译失败练习：同时存在两个可变引用 (E0499)
+// 这个文件的目标是让编译器报错！对应 demo.rs 中的练习 6。
+// 同一时间只能有一个可变引用：第一个可变引用还在使用时，不能再创建第二个。
+// 标有 //~ 的注释说明了错误应该出现的位置，check 会确认错误码和行号都符合预期。
+
+fn main() {
+    let mut s = String::from("hello");
+
+    // TODO: 创建第一个可变引用 r1
+
+
+    // TODO: 在下一行创建第二个可变引用 r2
+    //~v ERROR E0499
+
+
+    // TODO: 同时使用 r1 和 r2
+
+}
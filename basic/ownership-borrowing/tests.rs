@@ -0,0 +1,91 @@
+// 所有权和借用的隐藏测试
+// cargo run -- check ownership-borrowing 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数
+
+mod borrow_string {
+    use super::*;
+
+    #[test]
+    fn returns_length_without_taking_ownership() {
+        let s = String::from("hello");
+        assert_eq!(borrow_string(&s), 5);
+        assert_eq!(s, "hello", "借用之后原字符串仍然可用");
+    }
+}
+
+mod borrow_and_modify {
+    use super::*;
+
+    #[test]
+    fn appends_to_original() {
+        let mut s = String::from("hello");
+        borrow_and_modify(&mut s);
+        assert!(s.starts_with("hello"), "应该在原字符串后追加内容: {}", s);
+        assert!(s.len() > 5, "可变借用后字符串应该被修改: {}", s);
+    }
+}
+
+mod create_person {
+    use super::*;
+
+    #[test]
+    fn moves_fields_into_struct() {
+        let person = create_person("Alice".to_string(), 30);
+        assert_eq!(person.name, "Alice");
+        assert_eq!(person.age, 30);
+    }
+}
+
+mod process_person {
+    use super::*;
+
+    #[test]
+    fn describes_person() {
+        let person = Person {
+            name: "Bob".to_string(),
+            age: 25,
+        };
+        let text = process_person(&person);
+        assert!(text.contains("Bob") && text.contains("25"), "{}", text);
+        assert_eq!(person.name, "Bob", "借用之后 person 仍然可用");
+    }
+}
+
+mod update_person_age {
+    use super::*;
+
+    #[test]
+    fn updates_in_place() {
+        let mut person = Person {
+            name: "Carol".to_string(),
+            age: 25,
+        };
+        update_person_age(&mut person, 26);
+        assert_eq!(person.age, 26);
+    }
+}
+
+mod first_word {
+    use super::*;
+
+    #[test]
+    fn two_words() {
+        assert_eq!(first_word("hello world"), "hello");
+    }
+
+    #[test]
+    fn single_word() {
+        assert_eq!(first_word("hello"), "hello", "没有空格时返回整个字符串");
+        assert_eq!(first_word(""), "");
+    }
+}
+
+mod longest {
+    use super::*;
+
+    #[test]
+    fn picks_longer() {
+        assert_eq!(longest("long string", "xyz"), "long string");
+        assert_eq!(longest("ab", "abc"), "abc");
+    }
+}
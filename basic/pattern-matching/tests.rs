@@ -0,0 +1,162 @@
+// 模式匹配的隐藏测试
+// cargo run -- check pattern-matching 会把这个文件和 demo.rs 一起编译运行，
+// 每个 mod 对应 demo.rs 中的一个函数
+
+mod process_number {
+    use super::*;
+
+    #[test]
+    fn literals() {
+        assert_eq!(process_number(0), "零");
+        assert_eq!(process_number(1), "一");
+        assert_eq!(process_number(3), "三");
+    }
+
+    #[test]
+    fn other_numbers() {
+        assert_ne!(process_number(42), "零");
+        assert_ne!(process_number(-1), "一");
+    }
+}
+
+mod process_status {
+    use super::*;
+
+    #[test]
+    fn every_variant() {
+        assert_eq!(process_status(Status::Pending), "等待处理");
+        assert_eq!(process_status(Status::Approved), "已批准");
+        assert_eq!(process_status(Status::Rejected), "已拒绝");
+    }
+}
+
+mod calculate_area {
+    use super::*;
+
+    #[test]
+    fn every_shape() {
+        let circle = calculate_area(Shape::Circle { radius: 1.0 });
+        assert!(
+            (circle - std::f64::consts::PI).abs() < 1e-9,
+            "圆面积: {}",
+            circle
+        );
+        assert_eq!(
+            calculate_area(Shape::Rectangle {
+                width: 4.0,
+                height: 6.0
+            }),
+            24.0
+        );
+        assert_eq!(
+            calculate_area(Shape::Triangle {
+                base: 3.0,
+                height: 8.0
+            }),
+            12.0
+        );
+    }
+}
+
+mod process_option {
+    use super::*;
+
+    #[test]
+    fn some_and_none() {
+        assert!(process_option(Some(42)).contains("42"));
+        assert!(process_option(Some(-5)).contains("-5"));
+        assert_ne!(process_option(None), process_option(Some(0)));
+    }
+}
+
+mod process_result {
+    use super::*;
+
+    #[test]
+    fn ok_and_err() {
+        assert!(process_result(Ok(5)).contains('5'));
+        let error = process_result(Err("网络错误".to_string()));
+        assert!(error.contains("网络错误"), "应该包含错误信息: {}", error);
+    }
+}
+
+mod process_point {
+    use super::*;
+
+    #[test]
+    fn origin_and_axes() {
+        let origin = process_point(Point { x: 0, y: 0 });
+        let on_y = process_point(Point { x: 0, y: 5 });
+        let on_x = process_point(Point { x: 3, y: 0 });
+        assert!(origin.contains("原点"), "{}", origin);
+        assert!(on_y.contains('5'), "{}", on_y);
+        assert!(on_x.contains('3'), "{}", on_x);
+        assert_ne!(on_x, on_y);
+    }
+
+    #[test]
+    fn quadrants_differ() {
+        let first = process_point(Point { x: 2, y: 3 });
+        let second = process_point(Point { x: -2, y: 3 });
+        assert_ne!(
+            first.replace('-', ""),
+            second.replace('-', ""),
+            "第一象限和第二象限应该区分"
+        );
+    }
+}
+
+mod process_tuple {
+    use super::*;
+
+    #[test]
+    fn destructures() {
+        assert!(process_tuple((0, 0)).contains("原点"));
+        assert!(process_tuple((0, 5)).contains('5'));
+        assert!(process_tuple((3, 4)).contains('3'));
+    }
+}
+
+mod categorize_number {
+    use super::*;
+
+    #[test]
+    fn guards() {
+        assert_eq!(categorize_number(-5), "负数");
+        assert_eq!(categorize_number(0), "零");
+        assert_eq!(categorize_number(1), "小正数");
+        assert_eq!(categorize_number(10), "小正数");
+        assert_eq!(categorize_number(11), "中等数");
+        assert_eq!(categorize_number(50), "中等数");
+        assert_eq!(categorize_number(51), "大数");
+    }
+}
+
+mod match_range {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        assert_eq!(match_range(1), "1-10");
+        assert_eq!(match_range(10), "1-10");
+        assert_eq!(match_range(11), "11-20");
+        assert_eq!(match_range(100), "51-100");
+        assert_eq!(match_range(0), "其他范围");
+        assert_eq!(match_range(101), "其他范围");
+    }
+}
+
+mod process_message {
+    use super::*;
+
+    #[test]
+    fn every_variant() {
+        let moved = process_message(Message::Move { x: 10, y: 20 });
+        assert!(moved.contains("10") && moved.contains("20"), "{}", moved);
+        let written = process_message(Message::Write("Hello".to_string()));
+        assert!(written.contains("Hello"), "{}", written);
+        let color = process_message(Message::ChangeColor(255, 0, 128));
+        assert!(color.contains("255") && color.contains("128"), "{}", color);
+        assert!(!process_message(Message::Quit).is_empty());
+    }
+}
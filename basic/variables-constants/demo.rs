@@ -1,5 +1,7 @@
 // Rust 中的变量和常量练习
 // 请根据 TypeScript 示例，完成以下 Rust 代码
+// 还没完成的函数体是 todo!()，整个文件始终可以编译，完成一个练习就能运行一个练习
+#![allow(dead_code, unused_variables)]
 
 fn main() {
     println!("=== Rust 中的变量和常量 ===");
@@ -79,18 +81,18 @@ fn main() {
 // 提示：展示变量作用域和遮蔽概念
 fn demonstrate_scope() {
     // TODO: 在这里实现作用域演示
-    
+    todo!()
 }
 
 // TODO: 实现 calculate_area 函数
 // 提示：接受两个 f64 参数，返回 f64
 fn calculate_area(width: f64, height: f64) -> f64 {
     // TODO: 在这里实现面积计算
-    
+    todo!()
 }
 
-// 编译并运行：
-// rustc demo.rs && ./demo
+// 编译并运行（在项目根目录执行，编译产物放在临时目录）：
+// cargo run -- run variables-constants
 //
 // 或者使用 Cargo：
 // cargo init
@@ -0,0 +1,13 @@
+// 变量和常量的隐藏测试
+// cargo run -- check variables-constants 会把这个文件和 demo.rs 一起编译运行
+
+mod calculate_area {
+    use super::*;
+
+    #[test]
+    fn width_times_height() {
+        assert_eq!(calculate_area(10.0, 20.0), 200.0);
+        assert_eq!(calculate_area(2.5, 4.0), 10.0);
+        assert_eq!(calculate_area(0.0, 5.0), 0.0);
+    }
+}
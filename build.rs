@@ -0,0 +1,39 @@
+// 构建脚本：把 templates/*/demo.rs 中的原始练习模板嵌入二进制，供 reset 命令恢复
+// 模板是单独提交的副本，不读取 basic/*/demo.rs：学习者修改过的 demo.rs 不会被当成原始模板；
+// 每个模板文件都登记 rerun-if-changed，课程更新模板后重新编译就会嵌入新的内容
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let root =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("cargo 会设置 CARGO_MANIFEST_DIR"));
+    let dir = root.join("templates");
+    // 目录本身也要登记，新增模块时才会重新运行
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut templates = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            let demo = entry.path().join("demo.rs");
+            if let Ok(source) = fs::read_to_string(&demo) {
+                println!("cargo:rerun-if-changed={}", demo.display());
+                templates.push((entry.file_name().to_string_lossy().to_string(), source));
+            }
+        }
+    }
+    templates.sort();
+
+    let mut code = String::from("// 由 build.rs 生成：templates/*/demo.rs 的内容\n");
+    code.push_str("pub const TEMPLATES: &[(&str, &str)] = &[\n");
+    for (id, source) in &templates {
+        code.push_str(&format!("    ({:?}, {:?}),\n", id, source));
+    }
+    code.push_str("];\n");
+
+    let out = PathBuf::from(env::var("OUT_DIR").expect("cargo 会设置 OUT_DIR"));
+    fs::write(out.join("templates.rs"), code).expect("无法写入 templates.rs");
+}
@@ -0,0 +1,236 @@
+// answer.md 解析
+// 把答案文档拆成标题小节和代码块，供提示、参考答案等命令使用
+
+use std::fs;
+use std::path::Path;
+
+use syn::spanned::Spanned;
+use syn::{ImplItem, Item};
+
+use crate::checker;
+use crate::course::Lesson;
+use crate::i18n::t;
+
+// 以 ## 或 ### 开头的小节，body 不包含标题行
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub title: String,
+    pub line: usize,
+    pub body: String,
+}
+
+// ```lang 围起来的代码块
+#[derive(Debug, Clone)]
+pub struct CodeBlock {
+    pub lang: String,
+    pub code: String,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub content: String,
+}
+
+impl Answer {
+    pub fn load(lesson: &Lesson) -> Result<Answer, String> {
+        Answer::read(&lesson.answer_path())
+    }
+
+    // 只用讲解文字时读取当前界面语言的版本
+    pub fn load_localized(lesson: &Lesson) -> Result<Answer, String> {
+        Answer::read(&lesson.localized_answer_path())
+    }
+
+    fn read(path: &Path) -> Result<Answer, String> {
+        let content =
+            fs::read_to_string(path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+        Ok(Answer { content })
+    }
+
+    pub fn sections(&self) -> Vec<Section> {
+        let mut sections: Vec<Section> = Vec::new();
+        let mut in_code = false;
+
+        for (index, line) in self.content.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+            }
+
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if !in_code && (2..=3).contains(&level) && line[level..].starts_with(' ') {
+                sections.push(Section {
+                    title: line[level..].trim().to_string(),
+                    line: index + 1,
+                    body: String::new(),
+                });
+                continue;
+            }
+
+            if let Some(section) = sections.last_mut() {
+                section.body.push_str(line);
+                section.body.push('\n');
+            }
+        }
+
+        sections
+    }
+
+    pub fn code_blocks(&self) -> Vec<CodeBlock> {
+        let mut blocks = Vec::new();
+        let mut current: Option<CodeBlock> = None;
+
+        for line in self.content.lines() {
+            let trimmed = line.trim_start();
+            match current.take() {
+                None => {
+                    if let Some(lang) = trimmed.strip_prefix("```") {
+                        current = Some(CodeBlock {
+                            lang: lang.trim().to_string(),
+                            code: String::new(),
+                        });
+                    }
+                }
+                Some(mut block) => {
+                    if trimmed.starts_with("```") {
+                        blocks.push(block);
+                    } else {
+                        block.code.push_str(line);
+                        block.code.push('\n');
+                        current = Some(block);
+                    }
+                }
+            }
+        }
+
+        blocks
+    }
+}
+
+// 参考答案中的一段代码：顶层函数/类型，或 main 中以注释开头的一段
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    // 与 checker 中任务名称一致，例如 "divide"、"Counter::next"、"struct User"
+    pub name: Option<String>,
+    // 紧挨在代码上方的注释
+    pub comment: String,
+    pub source: String,
+}
+
+pub fn snippets(code: &str) -> Result<Vec<Snippet>, String> {
+    let file = syn::parse_file(code).map_err(|e| t!("参考答案无法解析: {}", e))?;
+    let lines: Vec<&str> = code.lines().collect();
+    let mut snippets = Vec::new();
+
+    for item in &file.items {
+        let start = item.span().start().line;
+        let end = item.span().end().line;
+
+        match item {
+            Item::Fn(function) if function.sig.ident == "main" => {
+                snippets.extend(main_chunks(&lines, start, end));
+            }
+            Item::Fn(function) => snippets.push(Snippet {
+                name: Some(function.sig.ident.to_string()),
+                comment: comment_above(&lines, start),
+                source: slice(&lines, start, end),
+            }),
+            Item::Impl(block) => {
+                let owner = checker::type_name(&block.self_ty);
+                for impl_item in &block.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        let method_start = method.span().start().line;
+                        snippets.push(Snippet {
+                            name: Some(format!("{}::{}", owner, method.sig.ident)),
+                            comment: comment_above(&lines, method_start),
+                            source: slice(&lines, method_start, method.span().end().line),
+                        });
+                    }
+                }
+            }
+            Item::Struct(definition) => snippets.push(Snippet {
+                name: Some(format!("struct {}", definition.ident)),
+                comment: comment_above(&lines, start),
+                source: slice(&lines, start, end),
+            }),
+            Item::Enum(definition) => snippets.push(Snippet {
+                name: Some(format!("enum {}", definition.ident)),
+                comment: comment_above(&lines, start),
+                source: slice(&lines, start, end),
+            }),
+            _ => {}
+        }
+    }
+
+    Ok(snippets)
+}
+
+// main 中每段以注释开头、空行分隔的代码
+fn main_chunks(lines: &[&str], start: usize, end: usize) -> Vec<Snippet> {
+    let mut chunks: Vec<Snippet> = Vec::new();
+    let mut previous_blank = true;
+
+    for line_number in start + 1..end {
+        let line = lines[line_number - 1];
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("//") && previous_blank {
+            chunks.push(Snippet {
+                name: checker::parse_exercise_marker(trimmed),
+                comment: String::new(),
+                source: String::new(),
+            });
+        }
+
+        if let Some(chunk) = chunks.last_mut() {
+            if trimmed.starts_with("//") && !has_code(&chunk.source) {
+                chunk
+                    .comment
+                    .push_str(trimmed.trim_start_matches('/').trim());
+                chunk.comment.push('\n');
+            }
+            chunk.source.push_str(line);
+            chunk.source.push('\n');
+        }
+
+        previous_blank = trimmed.is_empty();
+    }
+
+    chunks
+        .into_iter()
+        .filter(|chunk| has_code(&chunk.source))
+        .collect()
+}
+
+fn has_code(text: &str) -> bool {
+    text.lines()
+        .map(str::trim)
+        .any(|line| !line.is_empty() && !line.starts_with("//"))
+}
+
+// 第 start 行（从 1 开始）上方连续的 // 注释
+fn comment_above(lines: &[&str], start: usize) -> String {
+    let mut comments = Vec::new();
+    let mut index = start.saturating_sub(1);
+
+    while index > 0 {
+        let trimmed = lines[index - 1].trim();
+        if trimmed.starts_with("#[") {
+            index -= 1;
+            continue;
+        }
+        match trimmed.strip_prefix("//") {
+            Some(comment) => comments.push(comment.trim().to_string()),
+            None => break,
+        }
+        index -= 1;
+    }
+
+    comments.reverse();
+    comments.join("\n")
+}
+
+fn slice(lines: &[&str], start: usize, end: usize) -> String {
+    let mut text = lines[start - 1..end.min(lines.len())].join("\n");
+    text.push('\n');
+    text
+}
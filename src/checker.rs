@@ -0,0 +1,573 @@
+// 练习完成度检查
+// 用 syn 解析 demo.rs 找出每个函数和 main 中的练习块，再结合 rustc 的诊断信息判断状态：
+// 未开始的练习产生的 E0308 "expected X, found ()" 会被归到对应练习下，不再刷屏；
+// 能编译时再逐个运行 main 中的练习，只有运行通过的练习才算完成
+
+use std::fs;
+use std::path::Path;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use syn::spanned::Spanned;
+use syn::{Expr, ImplItem, Item, Pat, Stmt, Type};
+
+use crate::compile_fail;
+use crate::course::Lesson;
+use crate::diagnostics::Diagnostic;
+use crate::harness::{self, Outcome};
+use crate::i18n::{t, tr};
+use crate::runner::{self, BuildDir, CompileOutput};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Empty,
+    Broken,
+    Compiles,
+    Done,
+}
+
+impl Status {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Status::Empty => "⬜",
+            Status::Broken => "❌",
+            Status::Compiles => "🟡",
+            Status::Done => "✅",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::Empty => tr("未开始"),
+            Status::Broken => tr("有编译错误"),
+            Status::Compiles => tr("可编译"),
+            Status::Done => tr("完成"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskKind {
+    // main 中 "// 练习 N: ..." 注释标出的代码块
+    Exercise,
+    Function,
+    Type,
+    // compile_fail/*.rs 中预期编译失败的练习
+    CompileFail,
+}
+
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub kind: TaskKind,
+    pub name: String,
+    // 显示用的名称：英文界面下是 course.toml 中 exercises_en 的标题，其他情况与 name 相同
+    pub title: String,
+    // 不在 demo.rs 中的任务所在的文件（相对模块目录）
+    pub file: Option<String>,
+    // course.toml 中列出但 demo.rs 里找不到的练习为 0
+    pub start_line: usize,
+    pub end_line: usize,
+    pub status: Status,
+    pub errors: Vec<Diagnostic>,
+    // main 中的练习逐个运行的结果，没有运行时为 None
+    pub outcome: Option<Outcome>,
+}
+
+impl Task {
+    fn contains(&self, line: usize) -> bool {
+        self.file.is_none() && self.start_line <= line && line <= self.end_line
+    }
+
+    // "第 12-20 行" 或 "compile_fail/01_use_after_move.rs"
+    pub fn location(&self) -> String {
+        match &self.file {
+            Some(file) => file.clone(),
+            None if self.start_line == 0 => tr("demo.rs 中缺失").to_string(),
+            None => t!("第 {}-{} 行", self.start_line, self.end_line),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckReport {
+    pub compile: CompileOutput,
+    pub tasks: Vec<Task>,
+    // demo.rs 本身有语法错误时无法拆分练习
+    pub parse_error: Option<String>,
+    // 不属于任何练习的编译错误
+    pub other_errors: Vec<Diagnostic>,
+}
+
+impl CheckReport {
+    pub fn count(&self, status: Status) -> usize {
+        self.tasks
+            .iter()
+            .filter(|task| task.status == status)
+            .count()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.parse_error.is_none()
+            && self.compile.success
+            && self.tasks.iter().all(|task| task.status == Status::Done)
+    }
+}
+
+// 还没有开始或尚未分析的任务
+struct Candidate {
+    kind: TaskKind,
+    name: String,
+    start_line: usize,
+    end_line: usize,
+    empty: bool,
+}
+
+pub fn check(lesson: &Lesson) -> Result<CheckReport, String> {
+    let path = lesson.demo_path();
+    let source = fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+
+    let build_dir = BuildDir::new(&lesson.id)?;
+    let compile = runner::compile(&path, build_dir.path())?;
+
+    let mut report = analyze(lesson, &source, compile);
+    if report.parse_error.is_none() {
+        report
+            .tasks
+            .extend(missing_exercises(lesson, &report.tasks));
+    }
+    if report.compile.success {
+        let runs = match harness::build(&source, build_dir.path())? {
+            Some(binary) => harness::run(&binary, build_dir.path(), false)?.0,
+            None => whole_program(&report.tasks, &report.compile.binary)?,
+        };
+        apply_outcomes(&mut report.tasks, runs);
+    }
+    report.tasks.extend(compile_fail::tasks(lesson)?);
+    Ok(report)
+}
+
+// 练习之间共用变量、无法逐个运行时，整个程序正常结束才算所有练习都运行通过
+fn whole_program(tasks: &[Task], binary: &Path) -> Result<Vec<harness::ExerciseRun>, String> {
+    if !runner::execute(binary, &[], false)?.success {
+        return Ok(Vec::new());
+    }
+    Ok(tasks
+        .iter()
+        .filter(|task| task.kind == TaskKind::Exercise)
+        .map(|task| harness::ExerciseRun {
+            name: task.name.clone(),
+            outcome: Outcome::Passed,
+        })
+        .collect())
+}
+
+// 能编译只说明写了代码，main 中的练习要运行通过才算完成，没有运行到的也降为"可编译"；
+// panic 或 todo!() 发生在某个函数里时，这个函数同样不算完成
+fn apply_outcomes(tasks: &mut [Task], runs: Vec<harness::ExerciseRun>) {
+    for run in runs {
+        let line = match run.outcome {
+            Outcome::Unimplemented { line } | Outcome::Panicked { line, .. } => Some(line),
+            _ => None,
+        };
+        if let Some(function) = line.and_then(|line| {
+            tasks
+                .iter_mut()
+                .filter(|task| task.kind == TaskKind::Function && task.contains(line))
+                .min_by_key(|task| task.end_line - task.start_line)
+        }) {
+            if function.status == Status::Done {
+                function.status = Status::Compiles;
+                function.outcome = Some(run.outcome.clone());
+            }
+        }
+
+        if let Some(task) = tasks
+            .iter_mut()
+            .find(|task| task.kind == TaskKind::Exercise && task.name == run.name)
+        {
+            task.outcome = Some(run.outcome);
+        }
+    }
+
+    for task in tasks {
+        if task.kind == TaskKind::Exercise
+            && task.status == Status::Done
+            && task.outcome != Some(Outcome::Passed)
+        {
+            task.status = Status::Compiles;
+        }
+    }
+}
+
+// course.toml 中列出、但 demo.rs 里找不到的练习（被删除或改名），算作有错误
+fn missing_exercises(lesson: &Lesson, tasks: &[Task]) -> Vec<Task> {
+    lesson
+        .exercises
+        .iter()
+        .filter(|name| !tasks.iter().any(|task| &task.name == *name))
+        .map(|name| {
+            let message = t!(
+                "demo.rs 中找不到练习 {}，可能被删除或改名了（可以用 reset 恢复模板）",
+                name
+            );
+            Task {
+                kind: kind_of(name),
+                name: name.clone(),
+                title: lesson.exercise_title(name),
+                file: None,
+                start_line: 0,
+                end_line: 0,
+                status: Status::Broken,
+                errors: vec![Diagnostic {
+                    level: "error".to_string(),
+                    code: None,
+                    rendered: format!("error: {}\n", message),
+                    message,
+                    line: None,
+                }],
+                outcome: None,
+            }
+        })
+        .collect()
+}
+
+// 根据 check 显示的名称判断练习种类
+fn kind_of(name: &str) -> TaskKind {
+    if parse_exercise_marker(&format!("// {}", name)).is_some() {
+        TaskKind::Exercise
+    } else if name.starts_with("struct ") || name.starts_with("enum ") {
+        TaskKind::Type
+    } else {
+        TaskKind::Function
+    }
+}
+
+// demo.rs 中所有练习的名称，与 check 显示的一致，用于和 course.toml 对比
+pub fn task_names(source: &str) -> Result<Vec<String>, String> {
+    let file = syn::parse_file(source).map_err(|e| t!("第 {} 行: {}", e.span().start().line, e))?;
+    Ok(collect_tasks(&file, source)
+        .into_iter()
+        .map(|candidate| candidate.name)
+        .collect())
+}
+
+pub fn analyze(lesson: &Lesson, source: &str, compile: CompileOutput) -> CheckReport {
+    let errors: Vec<Diagnostic> = compile
+        .diagnostics
+        .iter()
+        .filter(|d| d.is_error())
+        .cloned()
+        .collect();
+
+    let file = match syn::parse_file(source) {
+        Ok(file) => file,
+        Err(e) => {
+            return CheckReport {
+                compile,
+                tasks: Vec::new(),
+                parse_error: Some(t!("第 {} 行: {}", e.span().start().line, e)),
+                other_errors: errors,
+            };
+        }
+    };
+
+    let mut tasks: Vec<Task> = collect_tasks(&file, source)
+        .into_iter()
+        .map(|candidate| Task {
+            kind: candidate.kind,
+            title: lesson.exercise_title(&candidate.name),
+            name: candidate.name,
+            file: None,
+            start_line: candidate.start_line,
+            end_line: candidate.end_line,
+            status: if candidate.empty {
+                Status::Empty
+            } else {
+                Status::Done
+            },
+            errors: Vec::new(),
+            outcome: None,
+        })
+        .collect();
+
+    // 每条错误归到范围最小的任务：main 中的练习块比 main 本身更具体
+    let mut other_errors = Vec::new();
+    for error in errors {
+        let owner = error.line.and_then(|line| {
+            tasks
+                .iter_mut()
+                .filter(|task| task.contains(line))
+                .min_by_key(|task| task.end_line - task.start_line)
+        });
+        match owner {
+            Some(task) => task.errors.push(error),
+            None => other_errors.push(error),
+        }
+    }
+
+    for task in &mut tasks {
+        if task.status == Status::Empty {
+            continue;
+        }
+        task.status = if !task.errors.is_empty() {
+            Status::Broken
+        } else if compile.success {
+            Status::Done
+        } else {
+            Status::Compiles
+        };
+    }
+
+    CheckReport {
+        compile,
+        tasks,
+        parse_error: None,
+        other_errors,
+    }
+}
+
+fn collect_tasks(file: &syn::File, source: &str) -> Vec<Candidate> {
+    let mut exercises = Vec::new();
+    let mut items = Vec::new();
+
+    for item in &file.items {
+        let start_line = item.span().start().line;
+        let end_line = item.span().end().line;
+
+        match item {
+            Item::Fn(function) if function.sig.ident == "main" => {
+                exercises = collect_exercises(source, start_line, end_line);
+            }
+            Item::Fn(function) => items.push(Candidate {
+                kind: TaskKind::Function,
+                name: function.sig.ident.to_string(),
+                start_line,
+                end_line,
+                empty: is_scaffold(&function.block.stmts),
+            }),
+            Item::Impl(block) => {
+                let owner = type_name(&block.self_ty);
+                for impl_item in &block.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        items.push(Candidate {
+                            kind: TaskKind::Function,
+                            name: format!("{}::{}", owner, method.sig.ident),
+                            start_line: method.span().start().line,
+                            end_line: method.span().end().line,
+                            empty: is_scaffold(&method.block.stmts),
+                        });
+                    }
+                }
+            }
+            Item::Struct(definition) => items.push(Candidate {
+                kind: TaskKind::Type,
+                name: format!("struct {}", definition.ident),
+                start_line,
+                end_line,
+                empty: definition.fields.is_empty(),
+            }),
+            Item::Enum(definition) => items.push(Candidate {
+                kind: TaskKind::Type,
+                name: format!("enum {}", definition.ident),
+                start_line,
+                end_line,
+                empty: definition.variants.is_empty(),
+            }),
+            _ => {}
+        }
+    }
+
+    exercises.extend(items);
+    exercises
+}
+
+// 在 main 的源码范围内查找 "// 练习 N: 标题" 注释，每个练习持续到下一个练习或 main 结束
+fn collect_exercises(source: &str, main_start: usize, main_end: usize) -> Vec<Candidate> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut markers = Vec::new();
+
+    for line_number in main_start..main_end {
+        let Some(line) = lines.get(line_number - 1) else {
+            break;
+        };
+        if let Some(title) = parse_exercise_marker(line) {
+            markers.push((line_number, title));
+        }
+    }
+
+    let mut exercises = Vec::new();
+    for (index, (start_line, title)) in markers.iter().enumerate() {
+        let end_line = markers
+            .get(index + 1)
+            .map(|(next, _)| next - 1)
+            .unwrap_or(main_end - 1);
+
+        let empty = lines[*start_line..end_line.min(lines.len())]
+            .iter()
+            .map(|line| line.trim())
+            .all(|line| line.is_empty() || line.starts_with("//"));
+
+        exercises.push(Candidate {
+            kind: TaskKind::Exercise,
+            name: title.clone(),
+            start_line: *start_line,
+            end_line,
+            empty,
+        });
+    }
+
+    exercises
+}
+
+// "// 练习 3: ? 运算符" → "练习 3: ? 运算符"
+pub fn parse_exercise_marker(line: &str) -> Option<String> {
+    let comment = line.trim().strip_prefix("//")?.trim();
+    let rest = comment.strip_prefix("练习")?.trim_start();
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        return None;
+    }
+    Some(comment.to_string())
+}
+
+// 函数体里只剩模板自带的内容（标题 println!、尚未使用的 let、todo!()）就算未开始
+pub fn is_scaffold(stmts: &[Stmt]) -> bool {
+    stmts.iter().enumerate().all(|(index, stmt)| match stmt {
+        Stmt::Macro(statement) => is_placeholder_macro(&statement.mac),
+        Stmt::Expr(Expr::Macro(expression), _) => is_placeholder_macro(&expression.mac),
+        Stmt::Local(local) => match binding_name(&local.pat) {
+            Some(name) => !stmts[index + 1..]
+                .iter()
+                .any(|later| mentions(later.to_token_stream(), &name)),
+            None => false,
+        },
+        _ => false,
+    })
+}
+
+fn is_placeholder_macro(mac: &syn::Macro) -> bool {
+    let Some(name) = mac.path.get_ident() else {
+        return false;
+    };
+
+    match name.to_string().as_str() {
+        "todo" | "unimplemented" => true,
+        "println" | "print" => {
+            let tokens: Vec<TokenTree> = mac.tokens.clone().into_iter().collect();
+            matches!(tokens.as_slice(), [] | [TokenTree::Literal(_)])
+        }
+        _ => false,
+    }
+}
+
+fn binding_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(binding) => Some(binding.ident.to_string()),
+        Pat::Type(typed) => binding_name(&typed.pat),
+        _ => None,
+    }
+}
+
+// 包括 println!("{numbers:?}") 这种写在格式字符串里的变量
+fn mentions(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions(group.stream(), name),
+        TokenTree::Literal(literal) => literal.to_string().contains(&format!("{{{}", name)),
+        TokenTree::Punct(_) => false,
+    })
+}
+
+pub fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        other => other.to_token_stream().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognises_exercise_markers() {
+        assert_eq!(
+            parse_exercise_marker("    // 练习 3: 克隆 (Clone)").as_deref(),
+            Some("练习 3: 克隆 (Clone)")
+        );
+        assert_eq!(
+            parse_exercise_marker("//练习12：调用处理函数").as_deref(),
+            Some("练习12：调用处理函数")
+        );
+        assert_eq!(parse_exercise_marker("// 练习: 没有序号"), None);
+        assert_eq!(parse_exercise_marker("let x = 1; // 练习 1"), None);
+    }
+
+    #[test]
+    fn lists_exercises_functions_and_types() {
+        let source = r#"
+fn main() {
+    // 练习 1: 基本变量
+    let x = 1;
+
+    // 练习 2: 常量
+    println!("{}", x);
+}
+
+struct User {
+    name: String,
+}
+
+impl User {
+    fn greet(&self) -> String {
+        todo!()
+    }
+}
+
+fn area(width: f64, height: f64) -> f64 {
+    width * height
+}
+"#;
+        assert_eq!(
+            task_names(source).unwrap(),
+            [
+                "练习 1: 基本变量",
+                "练习 2: 常量",
+                "struct User",
+                "User::greet",
+                "area"
+            ]
+        );
+        assert!(task_names("fn main() {").is_err());
+    }
+
+    #[test]
+    fn template_leftovers_are_scaffold() {
+        let block: syn::Block = syn::parse_str(
+            r#"{
+                println!("=== 练习 ===");
+                let unused = 5;
+                todo!()
+            }"#,
+        )
+        .unwrap();
+        assert!(is_scaffold(&block.stmts));
+
+        let block: syn::Block = syn::parse_str(
+            r#"{
+                let total = 5;
+                println!("{}", total);
+            }"#,
+        )
+        .unwrap();
+        assert!(!is_scaffold(&block.stmts));
+    }
+}
@@ -0,0 +1,637 @@
+// 命令行参数解析
+// 保持零依赖：子命令数量不多，手写解析比引入 clap 更直观
+
+use crate::i18n::{t, tr, Lang};
+use crate::report::Format;
+use crate::serve;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LessonFile {
+    Example,
+    Demo,
+    Answer,
+}
+
+impl LessonFile {
+    fn parse(value: &str) -> Result<LessonFile, String> {
+        match value {
+            "ts" | "example" | "example.ts" => Ok(LessonFile::Example),
+            "rs" | "demo" | "demo.rs" => Ok(LessonFile::Demo),
+            "md" | "answer" | "answer.md" => Ok(LessonFile::Answer),
+            other => Err(t!("未知的文件类型 '{}'，可选: ts | rs | md", other)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Intro,
+    Help,
+    List,
+    Show {
+        module: String,
+        file: LessonFile,
+        // 只显示 answer.md 的第 N 个小节（从 1 开始）
+        section: Option<usize>,
+    },
+    Run {
+        module: String,
+    },
+    Compare {
+        module: String,
+        name: Option<String>,
+    },
+    BuildSite {
+        // 默认是课程根目录下的 site/
+        out: Option<String>,
+    },
+    Serve {
+        port: u16,
+    },
+    Check {
+        // None 表示 --all
+        module: Option<String>,
+        report: Option<Format>,
+    },
+    Progress {
+        module: Option<String>,
+    },
+    Watch {
+        module: Option<String>,
+    },
+    Hint {
+        module: String,
+        exercise: Option<String>,
+    },
+    Solution {
+        module: String,
+        diff: bool,
+        name: Option<String>,
+    },
+    Explain {
+        code: Option<String>,
+    },
+    Reset {
+        module: String,
+    },
+    Restore {
+        module: String,
+        backup: Option<String>,
+    },
+    Validate {
+        fix: bool,
+    },
+    Coverage {
+        module: Option<String>,
+        table: bool,
+    },
+    Verify {
+        module: String,
+        update: bool,
+        strict_floats: bool,
+        strict_whitespace: bool,
+    },
+    Lang {
+        lang: Option<String>,
+    },
+}
+
+// --lang zh|en 可以写在任何位置，对所有命令生效，解析子命令之前先取出来
+pub fn take_lang(args: &mut Vec<String>) -> Result<Option<Lang>, String> {
+    let mut lang = None;
+    let mut index = 0;
+    while index < args.len() {
+        let value = if let Some(value) = args[index].strip_prefix("--lang=") {
+            let value = value.to_string();
+            args.remove(index);
+            value
+        } else if args[index] == "--lang" {
+            args.remove(index);
+            if index >= args.len() {
+                return Err(tr("--lang 缺少语言，可选: zh | en").to_string());
+            }
+            args.remove(index)
+        } else {
+            index += 1;
+            continue;
+        };
+        lang = Some(Lang::parse(&value)?);
+    }
+    Ok(lang)
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let Some(name) = args.first() else {
+        return Ok(Command::Intro);
+    };
+    let rest = &args[1..];
+
+    match name.as_str() {
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        "list" | "ls" => {
+            expect_no_more(rest, 0)?;
+            Ok(Command::List)
+        }
+        "show" => {
+            let module = required(rest, 0, "show <module> [ts|rs|md] [--section N]")?;
+            let mut file = None;
+            let mut section = None;
+            let mut args = rest[1..].iter();
+            while let Some(arg) = args.next() {
+                let value = match arg.as_str() {
+                    "--section" => Some(
+                        args.next()
+                            .map(String::as_str)
+                            .ok_or_else(|| tr("--section 缺少小节编号"))?,
+                    ),
+                    flag => flag.strip_prefix("--section="),
+                };
+                match (value, arg.as_str()) {
+                    (Some(value), _) => section = Some(parse_section(value)?),
+                    (None, flag) if flag.starts_with("--") => {
+                        return Err(t!("show 不支持选项 '{}'", flag))
+                    }
+                    (None, value) if file.is_none() => file = Some(LessonFile::parse(value)?),
+                    (None, extra) => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            let file = file.unwrap_or(LessonFile::Answer);
+            if section.is_some() && file != LessonFile::Answer {
+                return Err(tr("--section 只能用于 answer.md").to_string());
+            }
+            Ok(Command::Show {
+                module,
+                file,
+                section,
+            })
+        }
+        "compare" => {
+            let module = required(rest, 0, "compare <module> [name]")?;
+            expect_no_more(rest, 2)?;
+            Ok(Command::Compare {
+                module,
+                name: rest.get(1).cloned(),
+            })
+        }
+        "build-site" => {
+            let mut out = None;
+            let mut args = rest.iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--out" => {
+                        let dir = args.next().ok_or_else(|| {
+                            tr("--out 缺少目录，用法: build-site [--out DIR]").to_string()
+                        })?;
+                        out = Some(dir.clone());
+                    }
+                    flag if flag.starts_with("--out=") => {
+                        out = Some(flag["--out=".len()..].to_string())
+                    }
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("build-site 不支持选项 '{}'", flag))
+                    }
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            Ok(Command::BuildSite { out })
+        }
+        "serve" => {
+            let mut port = serve::DEFAULT_PORT;
+            let mut args = rest.iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--port" => {
+                        let value = args.next().ok_or_else(|| {
+                            tr("--port 缺少端口号，用法: serve [--port N]").to_string()
+                        })?;
+                        port = parse_port(value)?;
+                    }
+                    flag if flag.starts_with("--port=") => {
+                        port = parse_port(&flag["--port=".len()..])?
+                    }
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("serve 不支持选项 '{}'", flag))
+                    }
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            Ok(Command::Serve { port })
+        }
+        "run" => {
+            let module = required(rest, 0, "run <module>")?;
+            expect_no_more(rest, 1)?;
+            Ok(Command::Run { module })
+        }
+        "check" => {
+            let usage = "check <module> | check --all [--report json|junit]";
+            let mut module = None;
+            let mut all = false;
+            let mut report = None;
+            let mut args = rest.iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--all" => all = true,
+                    "--report" => {
+                        let format = args
+                            .next()
+                            .ok_or_else(|| t!("--report 缺少格式，用法: {}", usage))?;
+                        report = Some(Format::parse(format)?);
+                    }
+                    flag if flag.starts_with("--report=") => {
+                        report = Some(Format::parse(&flag["--report=".len()..])?);
+                    }
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("check 不支持选项 '{}'", flag))
+                    }
+                    value if module.is_none() => module = Some(value.to_string()),
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            match (module, all) {
+                (Some(_), true) => Err(t!("指定模块时不能同时使用 --all，用法: {}", usage)),
+                (None, false) => Err(t!("缺少参数，用法: {}", usage)),
+                (module, _) => Ok(Command::Check { module, report }),
+            }
+        }
+        "progress" => {
+            expect_no_more(rest, 1)?;
+            Ok(Command::Progress {
+                module: rest.first().cloned(),
+            })
+        }
+        "watch" => {
+            expect_no_more(rest, 1)?;
+            Ok(Command::Watch {
+                module: rest.first().cloned(),
+            })
+        }
+        "hint" => {
+            let module = required(rest, 0, "hint <module> [exercise]")?;
+            expect_no_more(rest, 2)?;
+            Ok(Command::Hint {
+                module,
+                exercise: rest.get(1).cloned(),
+            })
+        }
+        "solution" => {
+            let module = required(rest, 0, "solution <module> [--diff] [name]")?;
+            let mut diff = false;
+            let mut name = None;
+            for arg in &rest[1..] {
+                match arg.as_str() {
+                    "--diff" => diff = true,
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("solution 不支持选项 '{}'", flag))
+                    }
+                    value if name.is_none() => name = Some(value.to_string()),
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            Ok(Command::Solution { module, diff, name })
+        }
+        "explain" => {
+            expect_no_more(rest, 1)?;
+            Ok(Command::Explain {
+                code: rest.first().cloned(),
+            })
+        }
+        "reset" => {
+            let module = required(rest, 0, "reset <module>")?;
+            expect_no_more(rest, 1)?;
+            Ok(Command::Reset { module })
+        }
+        "restore" => {
+            let module = required(rest, 0, "restore <module> [backup]")?;
+            expect_no_more(rest, 2)?;
+            Ok(Command::Restore {
+                module,
+                backup: rest.get(1).cloned(),
+            })
+        }
+        "coverage" => {
+            let mut module = None;
+            let mut table = false;
+            for arg in rest {
+                match arg.as_str() {
+                    "--table" => table = true,
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("coverage 不支持选项 '{}'", flag))
+                    }
+                    value if module.is_none() => module = Some(value.to_string()),
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            Ok(Command::Coverage { module, table })
+        }
+        "validate" => {
+            let mut fix = false;
+            for arg in rest {
+                match arg.as_str() {
+                    "--fix" => fix = true,
+                    other => return Err(t!("validate 不支持参数 '{}'", other)),
+                }
+            }
+            Ok(Command::Validate { fix })
+        }
+        "verify" => {
+            let module = required(rest, 0, "verify <module> [--update] [--strict]")?;
+            let mut update = false;
+            let mut strict_floats = false;
+            let mut strict_whitespace = false;
+            for arg in &rest[1..] {
+                match arg.as_str() {
+                    "--update" => update = true,
+                    "--strict" => {
+                        strict_floats = true;
+                        strict_whitespace = true;
+                    }
+                    "--strict-floats" => strict_floats = true,
+                    "--strict-whitespace" => strict_whitespace = true,
+                    flag if flag.starts_with("--") => {
+                        return Err(t!("verify 不支持选项 '{}'", flag))
+                    }
+                    extra => return Err(t!("多余的参数 '{}'", extra)),
+                }
+            }
+            Ok(Command::Verify {
+                module,
+                update,
+                strict_floats,
+                strict_whitespace,
+            })
+        }
+        "lang" => {
+            expect_no_more(rest, 1)?;
+            Ok(Command::Lang {
+                lang: rest.first().cloned(),
+            })
+        }
+        other => Err(t!("未知命令 '{}'", other)),
+    }
+}
+
+fn required(args: &[String], index: usize, usage: &str) -> Result<String, String> {
+    args.get(index)
+        .cloned()
+        .ok_or_else(|| t!("缺少参数，用法: {}", usage))
+}
+
+fn expect_no_more(args: &[String], count: usize) -> Result<(), String> {
+    match args.get(count) {
+        Some(extra) => Err(t!("多余的参数 '{}'", extra)),
+        None => Ok(()),
+    }
+}
+
+// 小节编号从 1 开始
+fn parse_section(value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|section| *section > 0)
+        .ok_or_else(|| t!("小节编号应该是正整数，而不是 '{}'", value))
+}
+
+fn parse_port(value: &str) -> Result<u16, String> {
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|port| *port > 0)
+        .ok_or_else(|| t!("端口号应该是 1-65535 之间的整数，而不是 '{}'", value))
+}
+
+pub fn print_usage() {
+    println!("{}", tr("用法: rust-tutorial-for-typescript <命令> [参数]"));
+    println!();
+    println!("{}", tr("命令:"));
+    println!("{}", tr("  list                    列出所有课程模块"));
+    println!("  show <module> [ts|rs|md] [--section N]");
+    println!(
+        "{}",
+        tr("                          查看模块文件（默认 answer.md），渲染标题、表格并高亮代码；")
+    );
+    println!(
+        "{}",
+        tr("                          --section N 只显示第 N 个小节，超过一屏时用 $PAGER（默认 less）分页")
+    );
+    println!(
+        "{}",
+        tr("  run <module>            编译并运行模块的 demo.rs")
+    );
+    println!(
+        "{}",
+        tr("  compare <module> [name] 并排对照 example.ts 和参考答案中对应的 Rust 代码")
+    );
+    println!("{}", tr("  check <module>          检查每个练习的完成情况"));
+    println!("  check --all [--report json|junit]");
+    println!(
+        "{}",
+        tr("                          检查所有模块，--report 输出 JSON 或 JUnit XML 报告")
+    );
+    println!(
+        "{}",
+        tr("  watch [module]          保存后自动检查，完成后进入下一个模块")
+    );
+    println!("{}", tr("  hint <module> [exercise] 逐级获取练习提示"));
+    println!("  solution <module> [--diff] [name]");
+    println!(
+        "{}",
+        tr("                          查看参考答案，--diff 与 demo.rs 逐个函数对比")
+    );
+    println!("  verify <module> [--update] [--strict]");
+    println!(
+        "{}",
+        tr("                          对比 demo.rs 与参考答案的运行输出，--update 重新生成")
+    );
+    println!(
+        "{}", tr("                          expected_output.txt；默认忽略浮点格式 (5 / 5.0) 和空白差异，")
+    );
+    println!(
+        "{}",
+        tr("                          --strict-floats / --strict-whitespace 单独关闭")
+    );
+    println!(
+        "{}",
+        tr("  explain [code|lint]     用 TypeScript 对比讲解常见编译错误和 clippy lint")
+    );
+    println!(
+        "{}",
+        tr("  reset <module>          把 demo.rs 恢复为原始模板（自动备份当前代码）")
+    );
+    println!("  restore <module> [backup]");
+    println!(
+        "{}",
+        tr("                          列出备份，或从备份恢复 demo.rs（latest 为最近一次）")
+    );
+    println!("{}", tr("  progress [module]       查看学习进度"));
+    println!("  serve [--port N]");
+    println!(
+        "{}",
+        tr("                          在浏览器中做练习：启动本机 HTTP 服务（默认端口 7878），含编辑器、输出和 TS 参考")
+    );
+    println!("  build-site [--out DIR]");
+    println!(
+        "{}",
+        tr("                          生成可离线浏览的课程网站（默认输出到 site/），含搜索和 TS / Rust 标签页")
+    );
+    println!("  coverage [module] [--table]");
+    println!(
+        "{}", tr("                          example.ts 与 demo.rs 的对应关系，--table 输出 Markdown 表格")
+    );
+    println!(
+        "{}",
+        tr("  validate [--fix]        校验 course.toml 与 basic/ 目录一致，--fix 重新生成")
+    );
+    println!(
+        "{}",
+        tr("                          Cargo.toml 中的 [[example]]")
+    );
+    println!(
+        "{}",
+        tr("  lang [zh|en]            查看或设置界面语言（保存在 .tutorial/settings.json）")
+    );
+    println!("{}", tr("  help                    显示本帮助"));
+    println!();
+    println!("{}", tr("所有命令都可以加 --lang zh|en，只对这一次生效"));
+    println!(
+        "{}",
+        tr("<module> 可以是目录名 (error-handling)、example 名 (error_handling) 或序号 (6)")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn parse_line(line: &str) -> Result<Command, String> {
+        parse(&args(line))
+    }
+
+    #[test]
+    fn no_arguments_shows_intro() {
+        assert!(matches!(parse_line(""), Ok(Command::Intro)));
+    }
+
+    #[test]
+    fn show_defaults_to_answer() {
+        assert!(matches!(
+            parse_line("show functions"),
+            Ok(Command::Show { module, file: LessonFile::Answer, section: None }) if module == "functions"
+        ));
+        assert!(matches!(
+            parse_line("show functions ts"),
+            Ok(Command::Show {
+                file: LessonFile::Example,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn show_section_only_for_answer() {
+        assert!(matches!(
+            parse_line("show functions --section 2"),
+            Ok(Command::Show {
+                section: Some(2),
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_line("show functions --section=3"),
+            Ok(Command::Show {
+                section: Some(3),
+                ..
+            })
+        ));
+        assert!(parse_line("show functions rs --section 1").is_err());
+        assert!(parse_line("show functions --section 0").is_err());
+        assert!(parse_line("show functions --section").is_err());
+    }
+
+    #[test]
+    fn check_requires_module_or_all() {
+        assert!(matches!(
+            parse_line("check functions"),
+            Ok(Command::Check { module: Some(module), report: None }) if module == "functions"
+        ));
+        assert!(matches!(
+            parse_line("check --all --report junit"),
+            Ok(Command::Check {
+                module: None,
+                report: Some(Format::Junit)
+            })
+        ));
+        assert!(matches!(
+            parse_line("check --all --report=json"),
+            Ok(Command::Check {
+                report: Some(Format::Json),
+                ..
+            })
+        ));
+        assert!(parse_line("check").is_err());
+        assert!(parse_line("check functions --all").is_err());
+        assert!(parse_line("check --all --report html").is_err());
+    }
+
+    #[test]
+    fn verify_strict_sets_both_flags() {
+        assert!(matches!(
+            parse_line("verify functions --strict"),
+            Ok(Command::Verify {
+                strict_floats: true,
+                strict_whitespace: true,
+                update: false,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_line("verify functions --strict-floats --update"),
+            Ok(Command::Verify {
+                strict_floats: true,
+                strict_whitespace: false,
+                update: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn serve_port() {
+        assert!(
+            matches!(parse_line("serve"), Ok(Command::Serve { port }) if port == serve::DEFAULT_PORT)
+        );
+        assert!(matches!(
+            parse_line("serve --port=8080"),
+            Ok(Command::Serve { port: 8080 })
+        ));
+        assert!(parse_line("serve --port 0").is_err());
+        assert!(parse_line("serve --port 70000").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_and_extra_arguments() {
+        assert!(parse_line("frobnicate").is_err());
+        assert!(parse_line("run").is_err());
+        assert!(parse_line("run functions extra").is_err());
+        assert!(parse_line("list extra").is_err());
+        assert!(parse_line("solution functions --color").is_err());
+    }
+
+    #[test]
+    fn lang_is_taken_from_any_position() {
+        let mut line = args("check --lang en functions");
+        assert_eq!(take_lang(&mut line), Ok(Some(Lang::En)));
+        assert_eq!(line, args("check functions"));
+
+        let mut line = args("run functions --lang=zh");
+        assert_eq!(take_lang(&mut line), Ok(Some(Lang::Zh)));
+        assert_eq!(line, args("run functions"));
+
+        assert!(take_lang(&mut args("run --lang")).is_err());
+        assert_eq!(take_lang(&mut args("run functions")), Ok(None));
+    }
+}
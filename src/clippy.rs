@@ -0,0 +1,290 @@
+// Clippy 检查
+// 在练习能编译之后，用 clippy-driver 检查 demo.rs 的写法，按课程阶段（course.toml 中的 difficulty）
+// 选择不同的 lint 配置：入门阶段只用默认规则，中级加上少量 pedantic 规则，进阶开启全部 pedantic；
+// 对 TypeScript 开发者最常遇到的 lint 附上中英双语说明和讲解它的课程模块
+
+use std::env;
+use std::process::Command;
+
+use crate::course::{Difficulty, Lesson};
+use crate::diagnostics::{self, Diagnostic};
+use crate::i18n::{t, tr};
+use crate::lint::Finding;
+use crate::runner::BuildDir;
+
+pub struct LintExplanation {
+    pub lint: &'static str,
+    pub title: &'static str,
+    pub title_en: &'static str,
+    pub detail: &'static str,
+    pub detail_en: &'static str,
+    // 讲解这个概念的课程模块
+    pub lesson: &'static str,
+}
+
+pub const EXPLANATIONS: &[LintExplanation] = &[
+    LintExplanation {
+        lint: "needless_range_loop",
+        title: "用下标遍历集合",
+        title_en: "indexing in a range loop",
+        detail: "for i in 0..v.len() 是 TypeScript 的 for (let i = 0; ...) 写法。\
+                 Rust 中直接写 for item in &v，需要下标时用 v.iter().enumerate()，省掉边界检查也不会越界。",
+        detail_en: "for i in 0..v.len() mirrors TypeScript's for (let i = 0; ...). In Rust, iterate with \
+                    for item in &v, or v.iter().enumerate() when you need the index: no bounds checks and no \
+                    off-by-one mistakes.",
+        lesson: "loops-iterators",
+    },
+    LintExplanation {
+        lint: "redundant_clone",
+        title: "多余的 clone",
+        title_en: "redundant clone",
+        detail: "TypeScript 中赋值只是复制引用，所以很容易用 .clone() 让借用检查器闭嘴。\
+                 这里的原值之后不再使用，clone 只是白白复制了一份数据，直接移动或借用即可。",
+        detail_en: "Assignment in TypeScript copies a reference, so .clone() is a tempting way to quiet the \
+                    borrow checker. Here the original is never used again, so the clone only copies data for \
+                    nothing: move or borrow it instead.",
+        lesson: "ownership-borrowing",
+    },
+    LintExplanation {
+        lint: "ptr_arg",
+        title: "参数用 &String / &Vec 代替 &str / &[T]",
+        title_en: "&String or &Vec parameter instead of &str or &[T]",
+        detail: "TypeScript 只有 string 和 T[]；Rust 中 &str 和 &[T] 能接收更多调用方式（字面量、切片、数组），\
+                 而 &String / &Vec<T> 不提供任何额外能力。",
+        detail_en: "TypeScript has just string and T[]. In Rust, &str and &[T] accept more callers (literals, \
+                    slices, arrays), while &String / &Vec<T> add no extra capability.",
+        lesson: "ownership-borrowing",
+    },
+    LintExplanation {
+        lint: "match_like_matches_macro",
+        title: "返回 bool 的 match 可以用 matches!",
+        title_en: "match that returns bool can use matches!",
+        detail: "类似 TypeScript 中 switch 里每个 case 都 return true / false。\
+                 Rust 的 matches!(value, Pattern) 一行就能表达「是否匹配某个模式」。",
+        detail_en: "Like a TypeScript switch where every case returns true or false. Rust's \
+                    matches!(value, Pattern) says \"does this match the pattern\" in one line.",
+        lesson: "pattern-matching",
+    },
+    LintExplanation {
+        lint: "manual_map",
+        title: "手写的 Option::map",
+        title_en: "manual implementation of Option::map",
+        detail: "match x { Some(v) => Some(f(v)), None => None } 相当于 TypeScript 的 x === undefined ? undefined : f(x)。\
+                 Rust 中直接写 x.map(f)，和可选链 ?. 的思路一样。",
+        detail_en: "match x { Some(v) => Some(f(v)), None => None } is TypeScript's \
+                    x === undefined ? undefined : f(x). In Rust, write x.map(f), much like optional chaining (?.).",
+        lesson: "pattern-matching",
+    },
+    LintExplanation {
+        lint: "cast_possible_truncation",
+        title: "as 转换可能截断数值",
+        title_en: "as cast may truncate the value",
+        detail: "TypeScript 只有 number，数字之间不需要转换；Rust 中 as 会静默截断超出范围的值。\
+                 需要检查范围时用 u32::try_from(x)，转换失败会得到 Err 而不是错误的数字。",
+        detail_en: "TypeScript has a single number type, so numbers never need converting; in Rust, as \
+                    silently truncates values that do not fit. Use u32::try_from(x) to get an Err instead of a \
+                    wrong number.",
+        lesson: "data-types",
+    },
+    LintExplanation {
+        lint: "needless_return",
+        title: "函数末尾的 return",
+        title_en: "needless return at the end of a function",
+        detail: "TypeScript 函数必须写 return；Rust 中块的最后一个表达式（不加分号）就是返回值。",
+        detail_en: "TypeScript functions need return; in Rust the final expression of a block (without a \
+                    semicolon) is the return value.",
+        lesson: "functions",
+    },
+    LintExplanation {
+        lint: "bool_comparison",
+        title: "和 true / false 比较",
+        title_en: "comparison with a bool literal",
+        detail: "TypeScript 中 == true 可以排除 truthy 值；Rust 的 bool 不会隐式转换，直接写 if flag / if !flag。",
+        detail_en: "In TypeScript, == true can rule out truthy values; Rust's bool never coerces, so write \
+                    if flag / if !flag.",
+        lesson: "conditionals",
+    },
+];
+
+// 各阶段在 clippy 默认规则之外额外开启的 lint
+const COMMON: &[&str] = &["clippy::redundant_clone"];
+// 中级模块（所有权之后）开启与 TypeScript 习惯相关的 pedantic 规则：
+// TS 只有 number，数字之间随手 as 转换；forEach、switch 中重复的分支也是常见写法
+const INTERMEDIATE: &[&str] = &[
+    "clippy::explicit_iter_loop",
+    "clippy::redundant_closure_for_method_calls",
+    "clippy::cast_possible_truncation",
+    "clippy::cast_sign_loss",
+    "clippy::cast_lossless",
+    "clippy::needless_for_each",
+    "clippy::match_same_arms",
+    "clippy::cloned_instead_of_copied",
+    "clippy::manual_string_new",
+];
+const ADVANCED: &[&str] = &["clippy::pedantic"];
+// 始终关闭的 lint：模板中的函数签名按题目要求接收 String 等所有权参数，
+// needless_pass_by_value 会对原始模板报告，学习者改了签名反而通不过隐藏测试
+const ALLOWED: &[&str] = &["clippy::needless_pass_by_value"];
+
+pub fn profile(difficulty: Option<Difficulty>) -> Vec<&'static str> {
+    let mut lints = COMMON.to_vec();
+    match difficulty {
+        Some(Difficulty::Intermediate) => lints.extend(INTERMEDIATE),
+        Some(Difficulty::Advanced) => lints.extend(ADVANCED),
+        Some(Difficulty::Beginner) | None => {}
+    }
+    lints
+}
+
+pub fn find(lint: &str) -> Option<&'static LintExplanation> {
+    let lint = lint.trim_start_matches("clippy::");
+    EXPLANATIONS
+        .iter()
+        .find(|explanation| explanation.lint.eq_ignore_ascii_case(lint))
+}
+
+// 只返回 clippy 自己的诊断，rustc 的警告已经在 check 的报告里了；clippy 不可用时返回 None
+pub fn run(lesson: &Lesson) -> Result<Option<Vec<Diagnostic>>, String> {
+    let build_dir = BuildDir::new(&format!("clippy-{}", lesson.id))?;
+    let metadata = build_dir.path().join("demo.rmeta");
+
+    let mut command = Command::new(driver());
+    command
+        .arg("--edition=2021")
+        .arg("--crate-name=demo")
+        .arg("--error-format=json")
+        .arg("--emit=metadata")
+        .arg("-o")
+        .arg(&metadata);
+    for lint in profile(lesson.difficulty) {
+        command.arg("-W").arg(lint);
+    }
+    for lint in ALLOWED {
+        command.arg("-A").arg(lint);
+    }
+    command.arg(lesson.demo_path());
+
+    let Ok(output) = command.output() else {
+        return Ok(None);
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut lints: Vec<Diagnostic> = diagnostics::parse(&stderr)
+        .into_iter()
+        .filter(|d| {
+            d.code
+                .as_deref()
+                .is_some_and(|code| code.starts_with("clippy::"))
+        })
+        .collect();
+    lints.sort_by_key(|lint| lint.line);
+
+    Ok(Some(lints))
+}
+
+// 和 rustc 一样允许用环境变量指定
+fn driver() -> String {
+    env::var("CLIPPY_DRIVER").unwrap_or_else(|_| "clippy-driver".to_string())
+}
+
+// lint.rs 已经在同一行给出过建议的 lint 不再重复显示
+pub fn print(lesson: &Lesson, lints: Option<&[Diagnostic]>, findings: &[Finding]) {
+    let stage = lesson
+        .difficulty
+        .map(|difficulty| difficulty.label())
+        .unwrap_or(tr("默认"));
+    let Some(lints) = lints else {
+        println!();
+        println!(
+            "{}",
+            tr("📎 没有找到 clippy-driver，跳过 clippy 检查（rustup component add clippy）")
+        );
+        return;
+    };
+    let lints: Vec<&Diagnostic> = lints
+        .iter()
+        .filter(|lint| {
+            !findings.iter().any(|finding| {
+                Some(finding.line) == lint.line
+                    && finding.rule.clippy_lint() == lint.code.as_deref()
+            })
+        })
+        .collect();
+    if lints.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{}",
+        t!(
+            "📎 clippy（{}阶段规则，{} 条建议，不影响完成度）:",
+            stage,
+            lints.len()
+        )
+    );
+    for lint in &lints {
+        println!("  ⚠️  {}", lint.summary());
+    }
+
+    let mut explained: Vec<&str> = Vec::new();
+    for lint in &lints {
+        let Some(explanation) = lint.code.as_deref().and_then(find) else {
+            continue;
+        };
+        if !explained.contains(&explanation.lint) {
+            explained.push(explanation.lint);
+            print_explanation(explanation);
+        }
+    }
+}
+
+pub fn print_explanation(explanation: &LintExplanation) {
+    println!();
+    println!(
+        "📘 clippy::{} {} / {}",
+        explanation.lint, explanation.title, explanation.title_en
+    );
+    println!("   🇨🇳 {}", explanation.detail);
+    println!("   🇬🇧 {}", explanation.detail_en);
+    println!(
+        "{}",
+        t!(
+            "   📚 相关课程 / Lesson: {}（cargo run -- show {}）",
+            explanation.lesson,
+            explanation.lesson
+        )
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_stages_enable_more_lints() {
+        assert_eq!(profile(None), ["clippy::redundant_clone"]);
+        assert_eq!(
+            profile(Some(Difficulty::Beginner)),
+            ["clippy::redundant_clone"]
+        );
+        assert_eq!(
+            profile(Some(Difficulty::Intermediate)),
+            [
+                "clippy::redundant_clone",
+                "clippy::explicit_iter_loop",
+                "clippy::redundant_closure_for_method_calls",
+                "clippy::cast_possible_truncation",
+                "clippy::cast_sign_loss",
+                "clippy::cast_lossless",
+                "clippy::needless_for_each",
+                "clippy::match_same_arms",
+                "clippy::cloned_instead_of_copied",
+                "clippy::manual_string_new",
+            ]
+        );
+        assert_eq!(
+            profile(Some(Difficulty::Advanced)),
+            ["clippy::redundant_clone", "clippy::pedantic"]
+        );
+    }
+}
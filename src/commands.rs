@@ -0,0 +1,755 @@
+// 子命令实现
+
+use std::fs;
+use std::io::{self, IsTerminal};
+
+use crate::checker::{self, CheckReport, Status, TaskKind};
+use crate::cli::LessonFile;
+use crate::clippy;
+use crate::course::{Course, Lesson};
+use crate::explain;
+use crate::harness::{self, Outcome};
+use crate::highlight::Language;
+use crate::i18n::{self, t, tr};
+use crate::lint;
+use crate::markdown;
+use crate::progress::{self, Progress};
+use crate::report::{self, Format};
+use crate::runner::{self, BuildDir, CompileOutput, RunOutput};
+use crate::testsuite::{self, Verdict};
+use crate::ui;
+
+pub fn list(course: &Course) -> Result<(), String> {
+    println!("{}", t!("📚 基础课程模块 ({} 个):", course.lessons.len()));
+    for (index, lesson) in course.lessons.iter().enumerate() {
+        // 另一种语言的标题
+        let other_title = match (i18n::is_en(), lesson.title_en.is_empty()) {
+            (_, true) => String::new(),
+            (true, false) => format!("({})", lesson.title),
+            (false, false) => format!("({})", lesson.title_en),
+        };
+        println!(
+            "  {}. {:<20} - {} {} {}",
+            index + 1,
+            lesson.id,
+            ui::pad(&lesson.label(), 26),
+            ui::pad(&other_title, 26),
+            lesson.difficulty.map_or("", |d| d.label())
+        );
+        if !lesson.prerequisites.is_empty() {
+            println!(
+                "{}",
+                t!(
+                    "     {}   先修: {}",
+                    " ".repeat(20),
+                    lesson.prerequisites.join(", ")
+                )
+            );
+        }
+    }
+
+    for lesson in &course.incomplete {
+        println!(
+            "{}",
+            t!(
+                "  ⚠️  {} 缺少文件: {}（未加入课程）",
+                lesson.id,
+                lesson.missing.join(", ")
+            )
+        );
+    }
+
+    println!();
+    println!(
+        "{}",
+        t!("📂 课程目录: {}", course.root.join("basic").display())
+    );
+
+    Ok(())
+}
+
+pub fn show(
+    course: &Course,
+    module: &str,
+    file: LessonFile,
+    section: Option<usize>,
+) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let path = match file {
+        LessonFile::Example => lesson.example_path(),
+        LessonFile::Demo => lesson.demo_path(),
+        LessonFile::Answer => lesson.localized_answer_path(),
+    };
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+    let color = io::stdout().is_terminal();
+    let (width, _) = ui::terminal_size();
+
+    let mut output = format!(
+        "📖 {} - {} ({})\n\n",
+        lesson.id,
+        lesson.display_title(),
+        path.display()
+    );
+    match file {
+        LessonFile::Example => {
+            output += &markdown::highlight_code(&content, Language::TypeScript, color)
+        }
+        LessonFile::Demo => output += &markdown::highlight_code(&content, Language::Rust, color),
+        LessonFile::Answer => output += &show_answer(lesson, &content, section, width, color)?,
+    }
+    if !output.ends_with('\n') {
+        output.push('\n');
+    }
+    ui::page(&output);
+
+    Ok(())
+}
+
+// 完整显示时在开头列出目录；只显示一个小节时在末尾给出前后小节的命令
+fn show_answer(
+    lesson: &Lesson,
+    content: &str,
+    section: Option<usize>,
+    width: usize,
+    color: bool,
+) -> Result<String, String> {
+    let titles = markdown::sections(content);
+    let rendered = markdown::render(content, width, color);
+    let preamble_end = rendered
+        .sections
+        .first()
+        .copied()
+        .unwrap_or(rendered.lines.len());
+    let mut output = String::new();
+    let mut push = |line: &str| {
+        output.push_str(line);
+        output.push('\n');
+    };
+
+    let Some(number) = section else {
+        if !titles.is_empty() {
+            push(&t!(
+                "📑 目录（cargo run -- show {} --section N 只看某一节）",
+                lesson.id
+            ));
+            for (index, title) in titles.iter().enumerate() {
+                push(&format!("   § {}  {}", index + 1, title));
+            }
+            push(tr(
+                "💡 分页器中输入 /§ 后回车，再按 n / N 跳到下一节 / 上一节",
+            ));
+            push("");
+        }
+        for line in &rendered.lines {
+            push(line);
+        }
+        return Ok(output);
+    };
+
+    if number > titles.len() {
+        return Err(t!("{} 的讲解只有 {} 个小节", lesson.id, titles.len()));
+    }
+    let start = rendered.sections[number - 1];
+    let end = rendered
+        .sections
+        .get(number)
+        .copied()
+        .unwrap_or(rendered.lines.len());
+    for line in rendered.lines[..preamble_end]
+        .iter()
+        .chain(&rendered.lines[start..end])
+    {
+        push(line);
+    }
+    while output.ends_with("\n\n") {
+        output.pop();
+    }
+    output.push('\n');
+    if number > 1 {
+        output += &t!(
+            "⬅️  上一节 § {} {}: cargo run -- show {} --section {}",
+            number - 1,
+            titles[number - 2],
+            lesson.id,
+            number - 1
+        );
+        output.push('\n');
+    }
+    if number < titles.len() {
+        output += &t!(
+            "➡️  下一节 § {} {}: cargo run -- show {} --section {}",
+            number + 1,
+            titles[number],
+            lesson.id,
+            number + 1
+        );
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+pub fn run(course: &Course, module: &str) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    print_prerequisites(course, lesson);
+    println!("{}", t!("🔨 编译 {} ...", lesson.demo_path().display()));
+
+    let result = run_demo(course, lesson, true, |compiled| {
+        eprint!("{}", compiled.rendered());
+        if compiled.success {
+            println!(
+                "{}",
+                t!(
+                    "✅ 编译成功: {} 个警告 ({})",
+                    compiled.warnings(),
+                    runner::format_duration(compiled.duration)
+                )
+            );
+            println!("{}", tr("🏃 运行输出:"));
+            println!("{}", "-".repeat(40));
+        }
+    })?;
+
+    let compiled = &result.report.compile;
+    let Some(output) = &result.output else {
+        explain::print_for(&compiled.diagnostics);
+        println!();
+        println!(
+            "{}",
+            t!(
+                "❌ 编译失败: rustc {}，{} 个错误，{} 个警告 ({})",
+                runner::describe_status(compiled.success, compiled.exit_code),
+                compiled.errors(),
+                compiled.warnings(),
+                runner::format_duration(compiled.duration)
+            )
+        );
+        return Err(t!("{} 未能通过编译", lesson.id));
+    };
+
+    println!("{}", "-".repeat(40));
+    let icon = if output.success { "✅" } else { "❌" };
+    println!(
+        "{}",
+        t!(
+            "{} 程序{}: stdout {} 行，stderr {} 行 ({})",
+            icon,
+            runner::describe_status(output.success, output.exit_code),
+            output.stdout.lines().count(),
+            output.stderr.lines().count(),
+            runner::format_duration(output.duration)
+        )
+    );
+    if let Some(limit) = output.limit {
+        println!("⛔ {}", limit.describe());
+    }
+
+    let mut panicked = 0;
+    if result.whole_program {
+        println!(
+            "{}",
+            tr("💡 main 中的练习之间共用了变量，无法逐个运行，已按整个程序运行")
+        );
+    } else {
+        let (text, count) = exercise_runs(&result.report, &result.runs);
+        print!("{}", text);
+        panicked = count;
+    }
+
+    match (output.success, output.limit) {
+        (true, _) if panicked == 0 => Ok(()),
+        (true, _) => Err(t!("{} 有 {} 个练习运行时 panic", lesson.id, panicked)),
+        (false, Some(_)) => Err(t!("{} 超出运行限制，已被终止", lesson.id)),
+        (false, None) => Err(t!("{} 运行失败", lesson.id)),
+    }
+}
+
+// 一次 run 的结果：run 命令据此打印，serve 序列化成 JSON
+pub struct DemoRun {
+    // 编译结果和 demo.rs 拆分出的练习
+    pub report: CheckReport,
+    // 逐个练习的运行结果，按整个程序运行时为空
+    pub runs: Vec<harness::ExerciseRun>,
+    // 编译失败时没有运行
+    pub output: Option<RunOutput>,
+    // main 中的练习之间共用了变量，只能按整个程序运行
+    pub whole_program: bool,
+}
+
+// 编译 demo.rs、记录进度，能拆分练习时逐个运行，否则运行整个程序（run 和 serve 共用）；
+// on_compiled 在编译结束、开始运行之前调用，stream 为 true 时程序输出直接显示在终端
+pub fn run_demo(
+    course: &Course,
+    lesson: &Lesson,
+    stream: bool,
+    on_compiled: impl FnOnce(&CompileOutput),
+) -> Result<DemoRun, String> {
+    require_features(lesson)?;
+    let source = lesson.demo_path();
+    let build_dir = BuildDir::new(&lesson.id)?;
+    let compiled = runner::compile(&source, build_dir.path())?;
+    progress::update(course, |progress| {
+        progress.record_run(&lesson.id, compiled.success)
+    });
+    on_compiled(&compiled);
+
+    let text =
+        fs::read_to_string(&source).map_err(|e| t!("无法读取 {}: {}", source.display(), e))?;
+    if !compiled.success {
+        return Ok(DemoRun {
+            report: checker::analyze(lesson, &text, compiled),
+            runs: Vec::new(),
+            output: None,
+            whole_program: false,
+        });
+    }
+
+    let harness_binary = harness::build(&text, build_dir.path())?;
+    let (runs, output) = match &harness_binary {
+        Some(binary) => harness::run(binary, build_dir.path(), stream)?,
+        None => (Vec::new(), runner::execute(&compiled.binary, &[], stream)?),
+    };
+    Ok(DemoRun {
+        report: checker::analyze(lesson, &text, compiled),
+        runs,
+        output: Some(output),
+        whole_program: harness_binary.is_none(),
+    })
+}
+
+// 逐个练习的运行结果和 panic 的练习数；还没有写代码的练习显示为未开始（run 和 serve 共用）
+pub fn exercise_runs(report: &CheckReport, runs: &[harness::ExerciseRun]) -> (String, usize) {
+    let mut text = format!("\n{}\n", tr("📝 练习运行结果:"));
+    let mut passed = 0;
+    let mut unimplemented = 0;
+    let mut panicked = 0;
+    for task in report
+        .tasks
+        .iter()
+        .filter(|task| task.kind == TaskKind::Exercise)
+    {
+        let run = runs.iter().find(|run| run.name == task.name);
+        match (task.status, run.map(|run| &run.outcome)) {
+            (Status::Empty, _) => {
+                text += &format!(
+                    "  {} {}: {}\n",
+                    Status::Empty.icon(),
+                    task.title,
+                    Status::Empty.label()
+                );
+            }
+            (_, None) => {
+                text += &t!("  ⏸️  {}: 没有运行到", task.title);
+                text.push('\n');
+            }
+            (_, Some(outcome)) => {
+                match outcome {
+                    Outcome::Passed => passed += 1,
+                    Outcome::Unimplemented { .. } => unimplemented += 1,
+                    Outcome::Panicked { .. } | Outcome::Killed { .. } => panicked += 1,
+                }
+                text += &format!(
+                    "  {} {}: {}\n",
+                    outcome.icon(),
+                    task.title,
+                    outcome.describe()
+                );
+            }
+        }
+    }
+    text += &t!(
+        "📊 运行通过 {}，还没有实现 {}，panic 或被终止 {}",
+        passed,
+        unimplemented,
+        panicked
+    );
+    text.push('\n');
+    (text, panicked)
+}
+
+// check <module> / check --all，指定 --report 时只输出机器可读的报告
+pub fn check_modules(
+    course: &Course,
+    module: Option<&str>,
+    report: Option<Format>,
+) -> Result<(), String> {
+    let lessons: Vec<&Lesson> = match module {
+        Some(module) => vec![course.find(module)?],
+        None => course.lessons.iter().collect(),
+    };
+    if let Some(format) = report {
+        return report::report(course, &lessons, format);
+    }
+    if let Some(module) = module {
+        return check(course, module);
+    }
+
+    // 某个模块没完成不影响检查后面的模块
+    let mut unfinished = Vec::new();
+    for (index, lesson) in lessons.iter().enumerate() {
+        if index > 0 {
+            println!();
+            println!("{}", "─".repeat(60));
+        }
+        if let Err(e) = check(course, &lesson.id) {
+            println!("❌ {}", e);
+            unfinished.push(lesson.id.as_str());
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        t!(
+            "📊 {}/{} 个模块已完成",
+            lessons.len() - unfinished.len(),
+            lessons.len()
+        )
+    );
+    if unfinished.is_empty() {
+        Ok(())
+    } else {
+        Err(t!("未完成的模块: {}", unfinished.join(", ")))
+    }
+}
+
+pub fn check(course: &Course, module: &str) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    require_features(lesson)?;
+    println!("{}", t!("🔍 检查 {} - {}", lesson.id, lesson.label()));
+    print_prerequisites(course, lesson);
+
+    let report = checker::check(lesson)?;
+    print_check_report(&report);
+
+    // 语法错误时测试也无法编译，上面的报告已经说明了问题
+    let suite = match report.parse_error {
+        Some(_) => None,
+        None => testsuite::run(lesson)?,
+    };
+    if let Some(suite) = &suite {
+        println!();
+        testsuite::print(suite);
+    }
+    let tests_passing = suite.as_ref().is_none_or(|suite| suite.is_passing());
+    progress::update(course, |progress| {
+        progress.record_check(&lesson.id, &report, tests_passing)
+    });
+    if report.parse_error.is_none() {
+        let source = fs::read_to_string(lesson.demo_path())
+            .map_err(|e| t!("无法读取 {}: {}", lesson.demo_path().display(), e))?;
+        let findings = lint::lint(&source)?;
+        lint::print(&findings, &report.tasks);
+        // clippy 需要完整的类型检查，demo.rs 能编译之后才有结果
+        if report.compile.success {
+            let lints = clippy::run(lesson)?;
+            clippy::print(lesson, lints.as_deref(), &findings);
+        }
+    }
+
+    if !report.is_complete() {
+        let remaining = report.tasks.len() - report.count(Status::Done);
+        return Err(t!("{} 还有 {} 项未完成", lesson.id, remaining));
+    }
+    if let Some(suite) = suite.filter(|suite| !suite.is_passing()) {
+        let failing = suite.groups.len() - suite.count(Verdict::Passed);
+        return Err(match failing {
+            0 => t!("{} 的隐藏测试无法运行", lesson.id),
+            _ => t!("{} 还有 {} 个函数没有通过测试", lesson.id, failing),
+        });
+    }
+
+    println!("{}", t!("🎉 {} 的所有练习都已完成！", lesson.id));
+    Ok(())
+}
+
+// course.toml 中声明的 features 没有开启时，模块依赖的 crate 不可用
+pub fn require_features(lesson: &Lesson) -> Result<(), String> {
+    let missing = lesson.missing_features();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(t!(
+        "{} 需要开启 cargo features: {}，请使用 cargo run --features {} -- ...",
+        lesson.id,
+        missing.join(", "),
+        missing.join(",")
+    ))
+}
+
+// 先修模块还没完成时提醒一下，但不阻止继续
+fn print_prerequisites(course: &Course, lesson: &Lesson) {
+    let Ok(progress) = Progress::load(&Progress::path(course)) else {
+        return;
+    };
+    let pending: Vec<&str> = lesson
+        .prerequisites
+        .iter()
+        .filter(|id| {
+            !progress
+                .module(id)
+                .is_some_and(|module| module.is_complete())
+        })
+        .map(String::as_str)
+        .collect();
+    if !pending.is_empty() {
+        println!("{}", t!("💡 建议先完成: {}", pending.join(", ")));
+    }
+}
+
+fn print_check_report(report: &CheckReport) {
+    let compile = &report.compile;
+    let result = if compile.success {
+        tr("编译成功")
+    } else {
+        tr("编译失败")
+    };
+    println!(
+        "{}",
+        t!(
+            "   rustc: {}，{} 个错误，{} 个警告 ({})",
+            result,
+            compile.errors(),
+            compile.warnings(),
+            runner::format_duration(compile.duration)
+        )
+    );
+
+    if let Some(error) = &report.parse_error {
+        println!();
+        println!("{}", t!("❌ demo.rs 有语法错误，无法拆分练习: {}", error));
+        eprint!("{}", compile.rendered());
+        explain::print_for(&compile.diagnostics);
+        return;
+    }
+
+    let sections = [
+        (TaskKind::Exercise, tr("📝 main 中的练习:")),
+        (TaskKind::Function, tr("🔧 函数:")),
+        (TaskKind::Type, tr("🧱 类型定义:")),
+        (
+            TaskKind::CompileFail,
+            tr("💥 编译失败练习（要让 rustc 报出指定的错误）:"),
+        ),
+    ];
+    for (kind, heading) in sections {
+        let tasks: Vec<_> = report
+            .tasks
+            .iter()
+            .filter(|task| task.kind == kind)
+            .collect();
+        if tasks.is_empty() {
+            continue;
+        }
+
+        println!();
+        println!("{}", heading);
+        for task in tasks {
+            println!(
+                "{}",
+                t!(
+                    "  {} {} ({}，{})",
+                    task.status.icon(),
+                    task.title,
+                    task.status.label(),
+                    task.location()
+                )
+            );
+            if matches!(task.status, Status::Broken | Status::Compiles) {
+                for error in task.errors.iter().take(2) {
+                    println!("      ↳ {}", error.summary());
+                }
+                if task.errors.len() > 2 {
+                    println!("{}", t!("      ↳ 还有 {} 个错误", task.errors.len() - 2));
+                }
+            }
+            if let Some(outcome) = task
+                .outcome
+                .as_ref()
+                .filter(|_| task.status != Status::Empty)
+            {
+                if *outcome != Outcome::Passed {
+                    println!("      ↳ {} {}", outcome.icon(), outcome.describe());
+                }
+            }
+        }
+    }
+
+    if !report.other_errors.is_empty() {
+        println!();
+        println!("{}", tr("⚠️  其他编译错误:"));
+        for error in &report.other_errors {
+            println!("      ↳ {}", error.summary());
+        }
+    }
+
+    // demo.rs 的错误和编译失败练习中多出来的错误
+    let compile_fail_errors = report
+        .tasks
+        .iter()
+        .filter(|task| task.kind == TaskKind::CompileFail)
+        .flat_map(|task| &task.errors);
+    explain::print_for(compile.diagnostics.iter().chain(compile_fail_errors));
+
+    println!();
+    println!(
+        "{}",
+        t!(
+            "📊 完成 {}/{}，可编译 {}，有错误 {}，未开始 {}",
+            report.count(Status::Done),
+            report.tasks.len(),
+            report.count(Status::Compiles),
+            report.count(Status::Broken),
+            report.count(Status::Empty)
+        )
+    );
+}
+
+pub fn progress(course: &Course, module: Option<&str>) -> Result<(), String> {
+    let progress = Progress::load(&Progress::path(course))?;
+
+    if let Some(module) = module {
+        let lesson = course.find(module)?;
+        return print_module_progress(&progress, lesson);
+    }
+
+    println!("{}", tr("📈 学习进度"));
+    println!();
+    print_progress_row([
+        "#",
+        tr("模块"),
+        tr("练习"),
+        tr("编译次数"),
+        tr("最近尝试"),
+        tr("用时"),
+        tr("状态"),
+    ]);
+
+    let mut completed = 0;
+    for (index, lesson) in course.lessons.iter().enumerate() {
+        let number = (index + 1).to_string();
+
+        let Some(module) = progress.module(&lesson.id) else {
+            print_progress_row([&number, &lesson.id, "-", "0", "-", "-", tr("⬜ 未开始")]);
+            continue;
+        };
+
+        let exercises = if module.exercises.is_empty() {
+            "-".to_string()
+        } else {
+            format!("{}/{}", module.done_count(), module.exercises.len())
+        };
+        let last_attempt = module
+            .last_attempt
+            .map(progress::format_timestamp)
+            .unwrap_or_else(|| "-".to_string());
+        let time_to_pass = module
+            .time_to_pass()
+            .map(progress::format_span)
+            .unwrap_or_else(|| "-".to_string());
+        let status = if module.is_complete() {
+            completed += 1;
+            tr("✅ 完成")
+        } else {
+            tr("🟡 进行中")
+        };
+
+        print_progress_row([
+            &number,
+            &lesson.id,
+            &exercises,
+            &module.compile_attempts.to_string(),
+            &last_attempt,
+            &time_to_pass,
+            status,
+        ]);
+    }
+
+    println!();
+    println!(
+        "{}",
+        t!("📊 已完成 {}/{} 个模块", completed, course.lessons.len())
+    );
+    Ok(())
+}
+
+fn print_progress_row(columns: [&str; 7]) {
+    println!(
+        "  {}  {} {} {}  {}  {}  {}",
+        ui::pad_left(columns[0], 2),
+        ui::pad(columns[1], 20),
+        ui::pad_left(columns[2], 7),
+        ui::pad_left(columns[3], 8),
+        ui::pad(columns[4], 16),
+        ui::pad_left(columns[5], 6),
+        columns[6]
+    );
+}
+
+fn print_module_progress(progress: &Progress, lesson: &Lesson) -> Result<(), String> {
+    let id = &lesson.id;
+    println!("📈 {} - {}", id, lesson.label());
+
+    let Some(module) = progress.module(id) else {
+        println!("{}", t!("   还没有任何记录，先运行 check {} 试试", id));
+        return Ok(());
+    };
+
+    let format_time = |time: Option<u64>| {
+        time.map(progress::format_timestamp)
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    println!(
+        "{}",
+        t!("   首次尝试: {}", format_time(module.first_attempt))
+    );
+    println!(
+        "{}",
+        t!("   最近尝试: {}", format_time(module.last_attempt))
+    );
+    println!(
+        "{}",
+        t!(
+            "   编译次数: {}（成功 {} 次）",
+            module.compile_attempts,
+            module.successful_compiles
+        )
+    );
+    if let Some(seconds) = module.time_to_pass() {
+        println!(
+            "{}",
+            t!(
+                "   全部通过: {}（用时 {}）",
+                format_time(module.completed_at),
+                progress::format_span(seconds)
+            )
+        );
+    }
+
+    if !module.exercises.is_empty() {
+        println!();
+        for exercise in &module.exercises {
+            let passed = exercise
+                .completed_at
+                .map(|time| t!("，通过于 {}", progress::format_timestamp(time)))
+                .unwrap_or_default();
+            let hints = if exercise.hints_used > 0 {
+                t!("，💡 提示 {} 次", exercise.hints_used)
+            } else {
+                String::new()
+            };
+            println!(
+                "  {} {} ({}{}{})",
+                exercise.status.icon(),
+                lesson.exercise_title(&exercise.name),
+                exercise.status.label(),
+                passed,
+                hints
+            );
+        }
+    }
+
+    Ok(())
+}
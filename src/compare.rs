@@ -0,0 +1,227 @@
+// TypeScript ⇄ Rust 对照
+// 把 example.ts 中的每个声明和参考答案（answer.md 的「Rust 完整实现」）中对应的 Rust 代码放在一起显示，
+// 名称的对应规则和 coverage 相同：camelCase → snake_case，course.toml 的 ts_names 可以覆盖。
+// 终端足够宽时左右两栏并排，窄终端上下排列；超过一屏时分页
+
+use std::fs;
+use std::io::{self, IsTerminal};
+
+use crate::answer::{self, Snippet};
+use crate::course::{Course, Lesson};
+use crate::coverage::{self, Link, TsItem, TsKind};
+use crate::highlight::{Highlighter, Language};
+use crate::i18n::{t, tr};
+use crate::solution;
+use crate::ui;
+
+// 每栏至少这么宽才左右并排
+const MIN_COLUMN: usize = 40;
+const FRAME: &str = "90";
+const TITLE: &str = "1;36";
+
+struct Pair {
+    ts: TsItem,
+    ts_source: String,
+    rust_name: String,
+    rust_source: String,
+}
+
+pub fn compare(course: &Course, module: &str, name: Option<&str>) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let example_path = lesson.example_path();
+    let example = fs::read_to_string(&example_path)
+        .map_err(|e| t!("无法读取 {}: {}", example_path.display(), e))?;
+    let reference = answer::snippets(&solution::assemble(lesson)?)?;
+    let (mut pairs, mut unpaired) = pair(lesson, &example, &reference);
+
+    if let Some(name) = name {
+        let wanted = |ts: &TsItem, rust: Option<&str>| {
+            ts.name == name
+                || rust.is_some_and(|rust| {
+                    rust == name
+                        || rust == coverage::snake_case(name)
+                        || coverage::type_name(rust) == name
+                })
+        };
+        pairs.retain(|pair| wanted(&pair.ts, Some(&pair.rust_name)));
+        unpaired.retain(|item| wanted(item, None));
+        if pairs.is_empty() {
+            return match unpaired.first() {
+                Some(item) => Err(t!(
+                    "{}（example.ts 第 {} 行）在参考答案中没有对应的 Rust 代码",
+                    item.name,
+                    item.line
+                )),
+                None => Err(t!("example.ts 和参考答案中都没有 '{}'", name)),
+            };
+        }
+    }
+
+    let color = io::stdout().is_terminal();
+    let (width, _) = ui::terminal_size();
+    let mut output = t!(
+        "⇄ {} - {}: example.ts ⇄ answer.md（{} 组对照）\n",
+        lesson.id,
+        lesson.display_title(),
+        pairs.len()
+    );
+    for (index, pair) in pairs.iter().enumerate() {
+        output.push('\n');
+        output += &render_pair(index + 1, pair, width, color);
+    }
+
+    if name.is_none() && !unpaired.is_empty() {
+        let names: Vec<&str> = unpaired.iter().map(|item| item.name.as_str()).collect();
+        output.push('\n');
+        output += &t!(
+            "🔸 参考答案中没有对应 Rust 代码的 TS 声明: {}\n",
+            names.join(", ")
+        );
+    }
+    ui::page(&output);
+    Ok(())
+}
+
+// 按 coverage 的规则把 TS 声明和参考答案中的代码配对，返回配对结果和找不到 Rust 代码的 TS 声明
+fn pair(lesson: &Lesson, example: &str, reference: &[Snippet]) -> (Vec<Pair>, Vec<TsItem>) {
+    let names: Vec<String> = reference
+        .iter()
+        .filter_map(|snippet| snippet.name.clone())
+        .collect();
+    let mut pairs = Vec::new();
+    let mut unpaired = Vec::new();
+
+    for item in coverage::parse_typescript(example) {
+        let (rust_name, link) = coverage::counterpart(lesson, &item, &names);
+        let rust_source = rust_name
+            .as_deref()
+            .filter(|_| link != Link::Ignored)
+            .and_then(|name| rust_source(reference, name, item.kind));
+        match (rust_name, rust_source) {
+            (Some(rust_name), Some(rust_source)) => pairs.push(Pair {
+                ts_source: coverage::declaration_source(example, &item),
+                ts: item,
+                rust_name,
+                rust_source,
+            }),
+            _ => unpaired.push(item),
+        }
+    }
+    (pairs, unpaired)
+}
+
+// 函数带上方的注释；类型还要带上它的方法，拼成 impl 块
+fn rust_source(reference: &[Snippet], name: &str, kind: TsKind) -> Option<String> {
+    let snippet = reference
+        .iter()
+        .find(|snippet| snippet.name.as_deref() == Some(name))?;
+    let mut source = String::new();
+    for line in snippet.comment.lines() {
+        source.push_str(&format!("// {}\n", line));
+    }
+    source.push_str(&snippet.source);
+
+    let owner = coverage::type_name(name);
+    let methods: Vec<&Snippet> = reference
+        .iter()
+        .filter(|snippet| {
+            snippet
+                .name
+                .as_deref()
+                .and_then(|name| name.split_once("::"))
+                .is_some_and(|(method_owner, _)| method_owner == owner)
+        })
+        .collect();
+    if kind != TsKind::Function && !methods.is_empty() {
+        source.push_str(&format!("\nimpl {} {{\n", owner));
+        let bodies: Vec<&str> = methods
+            .iter()
+            .map(|method| method.source.as_str())
+            .collect();
+        source.push_str(&bodies.join("\n"));
+        source.push_str("}\n");
+    }
+    Some(source)
+}
+
+fn render_pair(number: usize, pair: &Pair, width: usize, color: bool) -> String {
+    let title = format!("━━ {}. {} ⇄ {} ", number, pair.ts.name, pair.rust_name);
+    let rule = "━".repeat(width.min(100).saturating_sub(ui::display_width(&title)));
+    let ts_label = t!("TypeScript · example.ts 第 {} 行", pair.ts.line);
+    let rust_label = tr("Rust · 参考答案");
+    let mut output = ui::paint(TITLE, &format!("{}{}", title, rule), color);
+    output.push('\n');
+
+    let column = width.saturating_sub(3) / 2;
+    if column >= MIN_COLUMN {
+        let left = code_lines(&pair.ts_source, Language::TypeScript, column, color);
+        let right = code_lines(&pair.rust_source, Language::Rust, column, color);
+        let separator = ui::paint(FRAME, " │ ", color);
+        output += &format!(
+            "{}{}{}\n",
+            ui::paint(TITLE, &ui::pad(&ts_label, column), color),
+            separator,
+            ui::paint(TITLE, rust_label, color)
+        );
+        output += &ui::paint(
+            FRAME,
+            &format!("{}┼{}", "─".repeat(column + 1), "─".repeat(column + 1)),
+            color,
+        );
+        output.push('\n');
+        for row in 0..left.len().max(right.len()) {
+            let (text, used) = left.get(row).cloned().unwrap_or_default();
+            let (right_text, _) = right.get(row).cloned().unwrap_or_default();
+            output += &format!(
+                "{}{}{}{}\n",
+                text,
+                " ".repeat(column.saturating_sub(used)),
+                separator,
+                right_text
+            );
+        }
+    } else {
+        for (label, source, language) in [
+            (ts_label.as_str(), &pair.ts_source, Language::TypeScript),
+            (rust_label, &pair.rust_source, Language::Rust),
+        ] {
+            output += &ui::paint(FRAME, &format!("── {} ──", label), color);
+            output.push('\n');
+            for (text, _) in code_lines(source, language, width, color) {
+                output += &text;
+                output.push('\n');
+            }
+        }
+    }
+    output
+}
+
+// 高亮后的代码行及其显示宽度；超过栏宽的行折到下一行，用 ↪ 标出
+fn code_lines(source: &str, language: Language, width: usize, color: bool) -> Vec<(String, usize)> {
+    let mut highlighter = Highlighter::new(language);
+    let mut lines = Vec::new();
+
+    for line in source.lines() {
+        let line = line.replace('\t', "    ");
+        let mut current = String::new();
+        let mut used = 0;
+        for (kind, text) in highlighter.line(&line) {
+            let mut piece = String::new();
+            for c in text.chars() {
+                let char_width = ui::display_width(&c.to_string());
+                if used + char_width > width && used > 0 {
+                    current += &ui::paint(kind.ansi(), &piece, color);
+                    lines.push((std::mem::take(&mut current), used));
+                    piece.clear();
+                    current = ui::paint(FRAME, "↪ ", color);
+                    used = 2;
+                }
+                piece.push(c);
+                used += char_width;
+            }
+            current += &ui::paint(kind.ansi(), &piece, color);
+        }
+        lines.push((current, used));
+    }
+    lines
+}
@@ -0,0 +1,271 @@
+// 编译失败练习
+// basic/<id>/compile_fail/*.rs 中每个文件都是一个独立的小程序，目标是让 rustc 报出指定的错误，
+// 文件名以对应的练习序号开头（01_use_after_move.rs），按名称顺序检查。
+// 预期的错误用注释标注（和 rustc 自己的 UI 测试写法一致）：
+//   代码 //~ ERROR E0382     错误出现在这一行
+//   代码 //~^ ERROR E0382    错误出现在上一行，每多一个 ^ 再往上一行
+//   //~v ERROR E0499         错误出现在下一行，方便在留给学习者的空行上方预先标注
+// 用 --error-format=json 编译后确认每个错误码都出现在预期的行上，并且没有其他错误
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::checker::{self, Status, Task, TaskKind};
+use crate::course::Lesson;
+use crate::diagnostics::Diagnostic;
+use crate::i18n::t;
+use crate::runner::{self, BuildDir};
+
+pub const DIR: &str = "compile_fail";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    pub line: usize,
+    // 错误码（E0382），也可以是错误信息的一部分
+    pub pattern: String,
+}
+
+impl Expectation {
+    fn matches(&self, error: &Diagnostic) -> bool {
+        error.line == Some(self.line)
+            && (error.code.as_deref() == Some(self.pattern.as_str())
+                || error.message.contains(&self.pattern))
+    }
+}
+
+// 模块没有 compile_fail 目录时返回空列表
+pub fn tasks(lesson: &Lesson) -> Result<Vec<Task>, String> {
+    let build_dir = BuildDir::new(&format!("{}-compile-fail", lesson.id))?;
+    files(&lesson.dir)?
+        .iter()
+        .map(|path| evaluate(lesson, path, build_dir.path()))
+        .collect()
+}
+
+// 编译失败练习的名称（check 中显示的名称），不需要编译
+pub fn names(lesson_dir: &Path) -> Result<Vec<String>, String> {
+    files(lesson_dir)?
+        .iter()
+        .map(|path| {
+            let source =
+                fs::read_to_string(path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+            Ok(name(path, &source))
+        })
+        .collect()
+}
+
+fn files(lesson_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let dir = lesson_dir.join(DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<_> = fs::read_dir(&dir)
+        .map_err(|e| t!("无法读取 {}: {}", dir.display(), e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+// 文件第一行注释中的标题，没有时用文件名
+fn name(path: &Path, source: &str) -> String {
+    title(source).unwrap_or_else(|| stem(path))
+}
+
+fn evaluate(lesson: &Lesson, path: &Path, build_dir: &Path) -> Result<Task, String> {
+    let source = fs::read_to_string(path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+    let stem = stem(path);
+
+    let expected = parse_annotations(&source);
+    let compiled = runner::compile(path, build_dir)?;
+    let errors: Vec<&Diagnostic> = compiled
+        .diagnostics
+        .iter()
+        .filter(|d| d.is_error())
+        .collect();
+
+    let mut problems: Vec<Diagnostic> = errors
+        .iter()
+        .filter(|error| !expected.iter().any(|e| e.matches(error)))
+        .map(|error| (*error).clone())
+        .collect();
+    problems.extend(
+        expected
+            .iter()
+            .filter(|e| !errors.iter().any(|error| e.matches(error)))
+            .map(|e| missing(e, &errors)),
+    );
+
+    let status = if compiled.success {
+        // 什么都还没写的模板当然能编译通过
+        match syn::parse_file(&source) {
+            Ok(file) if is_untouched(&file) => Status::Empty,
+            _ => Status::Compiles,
+        }
+    } else if problems.is_empty() && !expected.is_empty() {
+        Status::Done
+    } else {
+        Status::Broken
+    };
+
+    let name = name(path, &source);
+    Ok(Task {
+        kind: TaskKind::CompileFail,
+        title: lesson.exercise_title(&name),
+        name,
+        file: Some(format!("{}/{}.rs", DIR, stem)),
+        start_line: 1,
+        end_line: source.lines().count().max(1),
+        status,
+        errors: problems,
+        outcome: None,
+    })
+}
+
+// 解析 //~ 注释
+pub fn parse_annotations(source: &str) -> Vec<Expectation> {
+    let mut expectations = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let Some((_, annotation)) = line.split_once("//~") else {
+            continue;
+        };
+        let up = annotation.chars().take_while(|c| *c == '^').count();
+        let down = annotation.chars().take_while(|c| *c == 'v').count();
+        let rest = annotation[up + down..].trim_start();
+
+        let Some(pattern) = rest.strip_prefix("ERROR") else {
+            continue;
+        };
+        let target = (index + 1 + down).checked_sub(up).filter(|line| *line > 0);
+        if let Some(line) = target {
+            expectations.push(Expectation {
+                line,
+                pattern: pattern.trim().to_string(),
+            });
+        }
+    }
+
+    expectations
+}
+
+// 文件第一行 "// 编译失败练习：移动后使用 (E0382)" 中的标题
+fn title(source: &str) -> Option<String> {
+    let first = source.lines().next()?.trim().strip_prefix("//")?.trim();
+    let (_, title) = first.split_once(['：', ':'])?;
+    Some(title.trim().to_string())
+}
+
+// 模板里的辅助函数是现成的，只看 main 有没有动过
+fn is_untouched(file: &syn::File) -> bool {
+    file.items.iter().all(|item| match item {
+        syn::Item::Fn(function) if function.sig.ident == "main" => {
+            checker::is_scaffold(&function.block.stmts)
+        }
+        _ => true,
+    })
+}
+
+// 预期错误没有出现时生成的说明；同一行有其他错误时一并提示
+fn missing(expected: &Expectation, errors: &[&Diagnostic]) -> Diagnostic {
+    let actual: Vec<String> = errors
+        .iter()
+        .filter(|error| error.line == Some(expected.line))
+        .map(|error| error.code.clone().unwrap_or_else(|| error.message.clone()))
+        .collect();
+    let message = match actual.as_slice() {
+        [] => t!("预期这一行出现 {}，但没有出现", expected.pattern),
+        _ => t!(
+            "预期这一行出现 {}，实际是 {}",
+            expected.pattern,
+            actual.join(", ")
+        ),
+    };
+
+    Diagnostic {
+        level: "error".to_string(),
+        code: None,
+        rendered: t!("第 {} 行: {}\n", expected.line, message),
+        message,
+        line: Some(expected.line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expect(line: usize, pattern: &str) -> Expectation {
+        Expectation {
+            line,
+            pattern: pattern.to_string(),
+        }
+    }
+
+    #[test]
+    fn same_line_and_caret_annotations() {
+        let source = "\
+fn main() {
+    let s2 = s1;
+    println!(\"{}\", s1); //~ ERROR E0382
+    let r = &mut v;
+    //~^ ERROR E0499
+    //~^^^ ERROR borrow of moved value
+}";
+        assert_eq!(
+            parse_annotations(source),
+            vec![
+                expect(3, "E0382"),
+                expect(4, "E0499"),
+                expect(3, "borrow of moved value"),
+            ]
+        );
+    }
+
+    #[test]
+    fn v_annotations_point_below() {
+        let source = "\
+fn main() {
+    //~v ERROR E0499
+    let second = &mut s;
+    //~vv ERROR E0502
+    // 在这里写代码
+    println!(\"{}\", first);
+}";
+        assert_eq!(
+            parse_annotations(source),
+            vec![expect(3, "E0499"), expect(6, "E0502")]
+        );
+    }
+
+    #[test]
+    fn ignores_prose_and_out_of_range_targets() {
+        let source = "\
+//~^ ERROR E0382
+// 标有 //~ 的注释说明了错误应该出现的位置
+fn main() {} //~ WARN unused";
+        assert!(parse_annotations(source).is_empty());
+    }
+
+    #[test]
+    fn title_comes_from_the_first_comment() {
+        assert_eq!(
+            title("// 编译失败练习：移动后继续使用 (E0382)\nfn main() {}").as_deref(),
+            Some("移动后继续使用 (E0382)")
+        );
+        assert_eq!(
+            title("// Compile-fail exercise: use after move\n").as_deref(),
+            Some("use after move")
+        );
+        assert_eq!(title("fn main() {}"), None);
+    }
+}
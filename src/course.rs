@@ -0,0 +1,371 @@
+// 课程结构 - 读取课程清单 course.toml，再扫描 basic/ 目录发现课程模块
+// 每个模块目录只要包含 example.ts / demo.rs / answer.md 三个文件就会被自动识别；
+// 顺序、标题和标记以 course.toml 为准，清单中没有的目录按名称排在后面（validate 会报告）
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::i18n::{self, t, tr};
+
+// 每个模块必须包含的三个文件
+pub const LESSON_FILES: [&str; 3] = ["example.ts", "demo.rs", "answer.md"];
+
+// 课程清单，位于项目根目录
+pub const MANIFEST: &str = "course.toml";
+
+// 本程序编译时开启的 cargo features，模块需要的 feature 没开启时 run / check 会提示
+const ENABLED_FEATURES: &[(&str, bool)] = &[
+    ("async", cfg!(feature = "async")),
+    ("database", cfg!(feature = "database")),
+    ("full", cfg!(feature = "full")),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => tr("入门"),
+            Difficulty::Intermediate => tr("中级"),
+            Difficulty::Advanced => tr("进阶"),
+        }
+    }
+}
+
+// course.toml 中的一个 [[module]]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModuleEntry {
+    pub id: String,
+    pub title: String,
+    pub title_en: String,
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub marker: String,
+    #[serde(default)]
+    pub marker_en: String,
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    #[serde(default)]
+    pub exercises: Vec<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    // example.ts 中的名称 → Rust 练习名称，自动转换（camelCase → snake_case）对不上时使用；
+    // 值为空字符串表示这个 TS 概念有意不设练习
+    #[serde(default)]
+    pub ts_names: BTreeMap<String, String>,
+    // 有意没有 TypeScript 对应的 Rust 练习
+    #[serde(default)]
+    pub rust_only: Vec<String>,
+    // 练习名称 → 英文标题，main 中的练习和编译失败练习的名称来自中文注释
+    #[serde(default)]
+    pub exercises_en: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(rename = "module", default)]
+    pub modules: Vec<ModuleEntry>,
+}
+
+impl Manifest {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(MANIFEST)
+    }
+
+    // 没有清单时返回 None，此时退回到按目录名排序
+    pub fn load(root: &Path) -> Result<Option<Manifest>, String> {
+        let path = Manifest::path(root);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content =
+            fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| t!("{} 格式错误: {}", path.display(), e))
+    }
+
+    pub fn entry(&self, id: &str) -> Option<&ModuleEntry> {
+        self.modules.iter().find(|entry| entry.id == id)
+    }
+
+    fn order_of(&self, id: &str) -> usize {
+        self.modules
+            .iter()
+            .position(|entry| entry.id == id)
+            .unwrap_or(self.modules.len())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lesson {
+    pub id: String,
+    pub title: String,
+    // 英文标题，清单中没有的模块为空
+    pub title_en: String,
+    pub marker: String,
+    pub marker_en: String,
+    // 清单中没有的模块没有难度
+    pub difficulty: Option<Difficulty>,
+    pub prerequisites: Vec<String>,
+    pub exercises: Vec<String>,
+    pub features: Vec<String>,
+    pub ts_names: BTreeMap<String, String>,
+    pub rust_only: Vec<String>,
+    pub exercises_en: BTreeMap<String, String>,
+    pub dir: PathBuf,
+}
+
+impl Lesson {
+    pub fn example_path(&self) -> PathBuf {
+        self.dir.join("example.ts")
+    }
+
+    pub fn demo_path(&self) -> PathBuf {
+        self.dir.join("demo.rs")
+    }
+
+    pub fn answer_path(&self) -> PathBuf {
+        self.dir.join("answer.md")
+    }
+
+    // answer.md 的英文版：讲解文字翻译成英文，代码块与 answer.md 完全相同
+    pub fn answer_en_path(&self) -> PathBuf {
+        self.dir.join("answer.en.md")
+    }
+
+    // 当前界面语言的讲解，英文版不存在时用 answer.md
+    pub fn localized_answer_path(&self) -> PathBuf {
+        let english = self.answer_en_path();
+        if i18n::is_en() && english.is_file() {
+            english
+        } else {
+            self.answer_path()
+        }
+    }
+
+    // 参考答案的标准输出，由 verify 命令生成
+    pub fn golden_path(&self) -> PathBuf {
+        self.dir.join("expected_output.txt")
+    }
+
+    // 隐藏测试，check 时与 demo.rs 一起编译
+    pub fn tests_path(&self) -> PathBuf {
+        self.dir.join("tests.rs")
+    }
+
+    // 当前界面语言的标题，英文标题为空时用中文
+    pub fn display_title(&self) -> &str {
+        if i18n::is_en() && !self.title_en.is_empty() {
+            &self.title_en
+        } else {
+            &self.title
+        }
+    }
+
+    // 练习在当前界面语言下显示的名称，没有英文标题时用原名
+    pub fn exercise_title(&self, name: &str) -> String {
+        match self.exercises_en.get(name) {
+            Some(title) if i18n::is_en() => title.clone(),
+            _ => name.to_string(),
+        }
+    }
+
+    // 标题加上 ⭐ 标记，用于列表显示
+    pub fn label(&self) -> String {
+        let marker = if i18n::is_en() && !self.marker_en.is_empty() {
+            &self.marker_en
+        } else {
+            &self.marker
+        };
+        if marker.is_empty() {
+            self.display_title().to_string()
+        } else {
+            format!("{} {}", self.display_title(), marker)
+        }
+    }
+
+    // 与 cargo example 名称一致：variables-constants → variables_constants
+    pub fn example_name(&self) -> String {
+        example_name(&self.id)
+    }
+
+    // 需要但本程序编译时没有开启的 cargo features
+    pub fn missing_features(&self) -> Vec<&str> {
+        self.features
+            .iter()
+            .map(String::as_str)
+            .filter(|feature| {
+                !ENABLED_FEATURES
+                    .iter()
+                    .any(|(name, enabled)| name == feature && *enabled)
+            })
+            .collect()
+    }
+}
+
+// 查找课程根目录：环境变量 → 当前目录向上查找 → 编译时的项目目录
+pub fn root() -> PathBuf {
+    if let Ok(root) = env::var("RUST_TUTORIAL_ROOT") {
+        return PathBuf::from(root);
+    }
+
+    if let Ok(cwd) = env::current_dir() {
+        for dir in cwd.ancestors() {
+            if dir.join("basic").is_dir() {
+                return dir.to_path_buf();
+            }
+        }
+    }
+
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn example_name(id: &str) -> String {
+    id.replace('-', "_")
+}
+
+// 缺少文件的目录，list 时给出提示而不是静默忽略
+#[derive(Debug)]
+pub struct IncompleteLesson {
+    pub id: String,
+    pub missing: Vec<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct Course {
+    pub root: PathBuf,
+    pub manifest: Option<Manifest>,
+    pub lessons: Vec<Lesson>,
+    pub incomplete: Vec<IncompleteLesson>,
+}
+
+impl Course {
+    pub fn discover() -> Result<Course, String> {
+        Course::load(&root())
+    }
+
+    pub fn load(root: &Path) -> Result<Course, String> {
+        let manifest = Manifest::load(root)?;
+        let basic = root.join("basic");
+        let entries =
+            fs::read_dir(&basic).map_err(|e| t!("无法读取课程目录 {}: {}", basic.display(), e))?;
+
+        let mut lessons = Vec::new();
+        let mut incomplete = Vec::new();
+
+        for entry in entries {
+            let entry = entry.map_err(|e| t!("无法读取目录项: {}", e))?;
+            let dir = entry.path();
+            if !dir.is_dir() {
+                continue;
+            }
+
+            let id = entry.file_name().to_string_lossy().to_string();
+            let missing: Vec<&'static str> = LESSON_FILES
+                .iter()
+                .copied()
+                .filter(|file| !dir.join(file).is_file())
+                .collect();
+
+            if !missing.is_empty() {
+                incomplete.push(IncompleteLesson { id, missing });
+                continue;
+            }
+
+            let lesson = match manifest.as_ref().and_then(|m| m.entry(&id)) {
+                Some(entry) => Lesson {
+                    id,
+                    title: entry.title.clone(),
+                    title_en: entry.title_en.clone(),
+                    marker: entry.marker.clone(),
+                    marker_en: entry.marker_en.clone(),
+                    difficulty: Some(entry.difficulty),
+                    prerequisites: entry.prerequisites.clone(),
+                    exercises: entry.exercises.clone(),
+                    features: entry.features.clone(),
+                    ts_names: entry.ts_names.clone(),
+                    rust_only: entry.rust_only.clone(),
+                    exercises_en: entry.exercises_en.clone(),
+                    dir,
+                },
+                None => Lesson {
+                    title: read_title(&dir.join("answer.md")).unwrap_or_else(|| id.clone()),
+                    title_en: String::new(),
+                    id,
+                    marker: String::new(),
+                    marker_en: String::new(),
+                    difficulty: None,
+                    prerequisites: Vec::new(),
+                    exercises: Vec::new(),
+                    features: Vec::new(),
+                    ts_names: BTreeMap::new(),
+                    rust_only: Vec::new(),
+                    exercises_en: BTreeMap::new(),
+                    dir,
+                },
+            };
+            lessons.push(lesson);
+        }
+
+        let order = |id: &str| manifest.as_ref().map_or(0, |m| m.order_of(id));
+        lessons.sort_by_key(|lesson| (order(&lesson.id), lesson.id.clone()));
+        incomplete.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(Course {
+            root: root.to_path_buf(),
+            manifest,
+            lessons,
+            incomplete,
+        })
+    }
+
+    // 支持三种写法：目录名、example 名（下划线）或序号
+    pub fn find(&self, name: &str) -> Result<&Lesson, String> {
+        if let Ok(index) = name.parse::<usize>() {
+            if index >= 1 && index <= self.lessons.len() {
+                return Ok(&self.lessons[index - 1]);
+            }
+        }
+
+        let normalized = name.trim_end_matches('/').replace('_', "-");
+        let normalized = normalized.rsplit('/').next().unwrap_or(&normalized);
+
+        self.lessons
+            .iter()
+            .find(|lesson| lesson.id == normalized)
+            .ok_or_else(|| t!("找不到模块 '{}'，使用 list 查看所有模块", name))
+    }
+
+    // 按课程顺序的下一个模块
+    pub fn next_after(&self, id: &str) -> Option<&Lesson> {
+        let index = self.lessons.iter().position(|lesson| lesson.id == id)?;
+        self.lessons.get(index + 1)
+    }
+}
+
+// answer.md 的第一行形如 "# 条件语句 - 完整答案"
+pub fn read_title(answer: &Path) -> Option<String> {
+    let content = fs::read_to_string(answer).ok()?;
+    let heading = content.lines().find(|line| line.starts_with("# "))?;
+    let title = heading.trim_start_matches("# ").trim();
+    let title = title.split(" - ").next().unwrap_or(title).trim();
+
+    if title.is_empty() {
+        None
+    } else {
+        Some(title.to_string())
+    }
+}
@@ -0,0 +1,543 @@
+// TypeScript → Rust 对照覆盖
+// 解析 example.ts 顶层的 function / interface / enum / type / class 声明（以及箭头函数常量），
+// 按 camelCase → snake_case 与 demo.rs 中的练习对应，course.toml 的 ts_names 可以覆盖自动转换；
+// 报告哪些 TS 概念还没有 Rust 练习，哪些 Rust 练习在 example.ts 中没有出处
+
+use std::fs;
+
+use crate::checker;
+use crate::course::{Course, Lesson};
+use crate::i18n::{t, tr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TsKind {
+    Function,
+    Interface,
+    Enum,
+    Type,
+    Class,
+}
+
+impl TsKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            TsKind::Function => "function",
+            TsKind::Interface => "interface",
+            TsKind::Enum => "enum",
+            TsKind::Type => "type",
+            TsKind::Class => "class",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TsItem {
+    pub name: String,
+    pub kind: TsKind,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Link {
+    // 按命名规则自动对应
+    Auto,
+    // course.toml 中的 ts_names 指定
+    Override,
+    // course.toml 中标记为有意不对应
+    Ignored,
+    Missing,
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub ts: Option<TsItem>,
+    pub rust: Option<String>,
+    pub link: Link,
+}
+
+pub struct Coverage {
+    pub rows: Vec<Row>,
+}
+
+impl Coverage {
+    // 没有 Rust 练习的 TS 概念
+    pub fn untested(&self) -> impl Iterator<Item = &Row> {
+        self.rows
+            .iter()
+            .filter(|row| row.ts.is_some() && row.link == Link::Missing)
+    }
+
+    // 没有 TS 出处的 Rust 练习
+    pub fn unmotivated(&self) -> impl Iterator<Item = &Row> {
+        self.rows
+            .iter()
+            .filter(|row| row.ts.is_none() && row.link == Link::Missing)
+    }
+
+    pub fn matched(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row.link, Link::Auto | Link::Override))
+            .count()
+    }
+}
+
+pub fn coverage(course: &Course, module: Option<&str>, table: bool) -> Result<(), String> {
+    let lessons: Vec<&Lesson> = match module {
+        Some(module) => vec![course.find(module)?],
+        None => course.lessons.iter().collect(),
+    };
+
+    for (index, lesson) in lessons.iter().enumerate() {
+        let coverage = analyze(lesson)?;
+        if index > 0 {
+            println!();
+        }
+        if table {
+            print_table(lesson, &coverage);
+        } else {
+            print_summary(lesson, &coverage);
+        }
+    }
+    Ok(())
+}
+
+pub fn analyze(lesson: &Lesson) -> Result<Coverage, String> {
+    let read = |path: std::path::PathBuf| {
+        fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))
+    };
+    let ts_items = parse_typescript(&read(lesson.example_path())?);
+    let rust_items: Vec<String> = checker::task_names(&read(lesson.demo_path())?)
+        .map_err(|e| t!("{} 的 demo.rs 无法解析，{}", lesson.id, e))?
+        .into_iter()
+        .filter(|name| checker::parse_exercise_marker(&format!("// {}", name)).is_none())
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut used: Vec<&str> = Vec::new();
+
+    for item in ts_items {
+        let (rust, link) = counterpart(lesson, &item, &rust_items);
+        if let Some(name) = &rust {
+            if let Some(found) = rust_items.iter().find(|other| *other == name) {
+                used.push(found);
+            }
+        }
+        rows.push(Row {
+            ts: Some(item),
+            rust,
+            link,
+        });
+    }
+
+    for name in &rust_items {
+        if used.contains(&name.as_str()) {
+            continue;
+        }
+        // 方法跟随它的类型：类型有 TS 出处时方法也算有
+        let owner_used = name
+            .split_once("::")
+            .is_some_and(|(owner, _)| used.iter().any(|used| type_name(used) == owner));
+        let link = if lesson.rust_only.contains(name) {
+            Link::Ignored
+        } else if owner_used {
+            Link::Auto
+        } else {
+            Link::Missing
+        };
+        rows.push(Row {
+            ts: None,
+            rust: Some(name.clone()),
+            link,
+        });
+    }
+
+    Ok(Coverage { rows })
+}
+
+// TS 声明对应的 Rust 名称：course.toml 的 ts_names 优先，其次按命名规则在 candidates 中查找
+pub fn counterpart(
+    lesson: &Lesson,
+    item: &TsItem,
+    candidates: &[String],
+) -> (Option<String>, Link) {
+    match lesson.ts_names.get(&item.name) {
+        Some(target) if target.is_empty() => (None, Link::Ignored),
+        Some(target) => (Some(target.clone()), Link::Override),
+        None => match candidates.iter().find(|name| matches(item, name)) {
+            Some(name) => (Some(name.clone()), Link::Auto),
+            None => (None, Link::Missing),
+        },
+    }
+}
+
+// TS 函数对应同名（snake_case）的 Rust 函数，interface / type / class / enum 对应同名的 struct 或 enum
+fn matches(item: &TsItem, rust: &str) -> bool {
+    match item.kind {
+        TsKind::Function => rust == snake_case(&item.name),
+        _ => {
+            (rust.starts_with("struct ") || rust.starts_with("enum "))
+                && type_name(rust) == item.name
+        }
+    }
+}
+
+// "struct User" → "User"
+pub fn type_name(rust: &str) -> &str {
+    rust.rsplit(' ').next().unwrap_or(rust)
+}
+
+// getUserPermissions → get_user_permissions，parseHTTPResponse → parse_http_response
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_lower)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
+// 只看大括号深度为 0 的行：顶层声明都从行首开始
+pub fn parse_typescript(source: &str) -> Vec<TsItem> {
+    let mut items: Vec<TsItem> = Vec::new();
+    let mut scanner = Scanner::default();
+
+    for (index, line) in source.lines().enumerate() {
+        let top_level = scanner.depth == 0 && !scanner.in_comment && !scanner.in_template;
+        scanner.feed(line);
+        if !top_level {
+            continue;
+        }
+        let Some((name, kind)) = declaration(line) else {
+            continue;
+        };
+        // 函数重载会声明多次
+        if name != "main" && !items.iter().any(|item| item.name == name) {
+            items.push(TsItem {
+                name,
+                kind,
+                line: index + 1,
+            });
+        }
+    }
+
+    items
+}
+
+// 声明的完整源码：上方连续的 // 注释、函数重载的多个签名，直到大括号闭合或语句结束
+pub fn declaration_source(source: &str, item: &TsItem) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut start = item.line - 1;
+    while start > 0 && lines[start - 1].trim_start().starts_with("//") {
+        start -= 1;
+    }
+
+    let mut scanner = Scanner::default();
+    let mut opened = false;
+    let mut end = item.line - 1;
+    while end < lines.len() {
+        let line = lines[end];
+        scanner.feed(line);
+        opened |= scanner.depth > 0 || line.contains('{');
+        end += 1;
+        if scanner.depth > 0 || scanner.in_comment || scanner.in_template {
+            continue;
+        }
+        // 没有大括号的声明以分号结束，或者行尾不再是 = | & , ( 这样需要续行的符号
+        let continues = line.trim_end().ends_with(['=', '|', '&', ',', '(']);
+        let overload_follows = lines
+            .get(end)
+            .and_then(|next| declaration(next))
+            .is_some_and(|(name, _)| name == item.name);
+        if (opened || !continues) && !overload_follows {
+            break;
+        }
+    }
+
+    let mut text = lines[start..end].join("\n");
+    text.push('\n');
+    text
+}
+
+fn declaration(line: &str) -> Option<(String, TsKind)> {
+    let mut rest = line.trim_start();
+    for modifier in ["export ", "default ", "declare ", "abstract ", "async "] {
+        if let Some(stripped) = rest.strip_prefix(modifier) {
+            rest = stripped.trim_start();
+        }
+    }
+
+    if let Some(after) = rest.strip_prefix("function") {
+        let after = after.trim_start_matches('*').trim_start();
+        return identifier(after).map(|name| (name, TsKind::Function));
+    }
+    for (keyword, kind) in [
+        ("interface ", TsKind::Interface),
+        ("const enum ", TsKind::Enum),
+        ("enum ", TsKind::Enum),
+        ("type ", TsKind::Type),
+        ("class ", TsKind::Class),
+    ] {
+        if let Some(after) = rest.strip_prefix(keyword) {
+            return identifier(after).map(|name| (name, kind));
+        }
+    }
+
+    // const add = (a, b) => ...、let callback = () => { ...
+    for keyword in ["const ", "let ", "var "] {
+        if let Some(after) = rest.strip_prefix(keyword) {
+            let name = identifier(after)?;
+            let (_, value) = after.split_once('=')?;
+            let value = value.trim_start();
+            let is_function = value.starts_with("function")
+                || (line.contains("=>")
+                    && (value.starts_with('(')
+                        || value.starts_with("async")
+                        || value.starts_with('<')));
+            return is_function.then_some((name, TsKind::Function));
+        }
+    }
+    None
+}
+
+fn identifier(text: &str) -> Option<String> {
+    let name: String = text
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+// 逐行跟踪大括号深度，跳过字符串、注释和模板字符串
+#[derive(Default)]
+struct Scanner {
+    depth: usize,
+    in_comment: bool,
+    in_template: bool,
+    // 模板字符串中 ${} 的嵌套深度，大于 0 时按普通代码扫描
+    template_depth: usize,
+}
+
+impl Scanner {
+    fn feed(&mut self, line: &str) {
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        let mut quote: Option<char> = None;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if self.in_comment {
+                if c == '*' && next == Some('/') {
+                    self.in_comment = false;
+                    i += 1;
+                }
+            } else if let Some(q) = quote {
+                if c == '\\' {
+                    i += 1;
+                } else if c == q {
+                    quote = None;
+                }
+            } else if self.in_template && self.template_depth == 0 {
+                if c == '\\' {
+                    i += 1;
+                } else if c == '`' {
+                    self.in_template = false;
+                } else if c == '$' && next == Some('{') {
+                    self.template_depth = 1;
+                    i += 1;
+                }
+            } else {
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        self.in_comment = true;
+                        i += 1;
+                    }
+                    '"' | '\'' => quote = Some(c),
+                    '`' => self.in_template = true,
+                    '{' if self.in_template => self.template_depth += 1,
+                    '}' if self.in_template => self.template_depth -= 1,
+                    '{' => self.depth += 1,
+                    '}' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+    }
+}
+
+fn print_summary(lesson: &Lesson, coverage: &Coverage) {
+    let untested: Vec<&Row> = coverage.untested().collect();
+    let unmotivated: Vec<&Row> = coverage.unmotivated().collect();
+
+    println!("🗺️  {} - {}", lesson.id, lesson.label());
+    println!(
+        "{}",
+        t!(
+            "   {} 组对应，{} 个 TS 概念没有 Rust 练习，{} 个 Rust 练习没有 TS 出处",
+            coverage.matched(),
+            untested.len(),
+            unmotivated.len()
+        )
+    );
+    if !untested.is_empty() {
+        println!("{}", tr("   ⚠️  没有 Rust 练习的 TS 概念:"));
+        for row in untested {
+            if let Some(item) = &row.ts {
+                println!(
+                    "{}",
+                    t!(
+                        "      {} {}（example.ts 第 {} 行）",
+                        item.kind.label(),
+                        item.name,
+                        item.line
+                    )
+                );
+            }
+        }
+    }
+    if !unmotivated.is_empty() {
+        println!("{}", tr("   ⚠️  没有 TS 出处的 Rust 练习:"));
+        for row in unmotivated {
+            if let Some(name) = &row.rust {
+                println!("      {}", name);
+            }
+        }
+    }
+}
+
+// Markdown 表格，可以直接贴进 README 或 answer.md
+fn print_table(lesson: &Lesson, coverage: &Coverage) {
+    println!("### {} ({})", lesson.display_title(), lesson.id);
+    println!();
+    println!("{}", tr("| TypeScript | 种类 | Rust | 说明 |"));
+    println!("| --- | --- | --- | --- |");
+    for row in &coverage.rows {
+        let (ts, kind) = match &row.ts {
+            Some(item) => (format!("`{}`", item.name), item.kind.label()),
+            None => ("—".to_string(), ""),
+        };
+        let rust = row
+            .rust
+            .as_ref()
+            .map(|name| format!("`{}`", name))
+            .unwrap_or_else(|| "—".to_string());
+        let note = match (row.link, row.ts.is_some()) {
+            (Link::Auto, _) => "",
+            (Link::Override, _) => tr("course.toml 指定"),
+            (Link::Ignored, _) => tr("有意不对应"),
+            (Link::Missing, true) => tr("⚠️ 没有 Rust 练习"),
+            (Link::Missing, false) => tr("⚠️ 没有 TS 出处"),
+        };
+        println!("| {} | {} | {} | {} |", ts, kind, rust, note);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case_splits_words_and_acronyms() {
+        assert_eq!(snake_case("getUserPermissions"), "get_user_permissions");
+        assert_eq!(snake_case("parseHTTPResponse"), "parse_http_response");
+        assert_eq!(snake_case("HTMLParser"), "html_parser");
+        assert_eq!(snake_case("toJSON"), "to_json");
+        assert_eq!(snake_case("base64Encode"), "base64_encode");
+        assert_eq!(snake_case("already_snake"), "already_snake");
+    }
+
+    const SOURCE: &str = r#"export function add(a: number, b: number): number {
+    return a + b;
+}
+
+export async function fetchUser(id: number): Promise<string> {
+    const url = `/users/${id}`;
+    return url;
+}
+
+const double = (x: number) => x * 2;
+export const greet = async (name: string) => {
+    return `hello ${name}`;
+};
+const limit = 10;
+
+function format(value: string): string;
+function format(value: number): string;
+function format(value: string | number): string {
+    return String(value);
+}
+
+const message = "function notAFunction() {}";
+const snippet = `(a) => a`;
+const template = `
+function alsoNotAFunction() {}
+`;
+
+function main() {}
+"#;
+
+    #[test]
+    fn parses_top_level_functions() {
+        let items: Vec<(String, TsKind, usize)> = parse_typescript(SOURCE)
+            .into_iter()
+            .map(|item| (item.name, item.kind, item.line))
+            .collect();
+        assert_eq!(
+            items,
+            [
+                ("add".to_string(), TsKind::Function, 1),
+                ("fetchUser".to_string(), TsKind::Function, 5),
+                ("double".to_string(), TsKind::Function, 10),
+                ("greet".to_string(), TsKind::Function, 11),
+                ("format".to_string(), TsKind::Function, 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn overloads_belong_to_one_declaration() {
+        let items = parse_typescript(SOURCE);
+        let format = items.iter().find(|item| item.name == "format").unwrap();
+        let text = declaration_source(SOURCE, format);
+        assert_eq!(text.lines().count(), 5);
+        assert!(text.starts_with("function format(value: string): string;"));
+        assert!(text.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn recognizes_type_declarations() {
+        let kinds = [
+            ("export interface User {", "User", TsKind::Interface),
+            ("const enum Color {", "Color", TsKind::Enum),
+            ("export type Id = string | number;", "Id", TsKind::Type),
+            ("export default class Stack<T> {", "Stack", TsKind::Class),
+            ("declare abstract class Shape {", "Shape", TsKind::Class),
+            ("function* ids() {", "ids", TsKind::Function),
+        ];
+        for (line, name, kind) in kinds {
+            assert_eq!(
+                declaration(line),
+                Some((name.to_string(), kind)),
+                "{}",
+                line
+            );
+        }
+        assert_eq!(declaration("let count = 0;"), None);
+        assert_eq!(declaration("return add(1, 2);"), None);
+    }
+}
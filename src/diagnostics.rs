@@ -0,0 +1,154 @@
+// rustc 诊断信息解析
+// 编译时使用 --error-format=json，这样可以拿到错误码和行号，而不是一大段文本
+
+use serde::Deserialize;
+
+use crate::i18n::t;
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub level: String,
+    pub code: Option<String>,
+    pub message: String,
+    // 主要位置所在的行（从 1 开始），部分诊断没有位置信息
+    pub line: Option<usize>,
+    pub rendered: String,
+}
+
+impl Diagnostic {
+    // "aborting due to N previous errors" 只是汇总，不算作独立的错误
+    pub fn is_error(&self) -> bool {
+        self.level == "error" && !self.message.starts_with("aborting due to")
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.level == "warning"
+            && !self.message.contains("warning emitted")
+            && !self.message.contains("warnings emitted")
+    }
+
+    // 单行摘要，例如 "E0308 第 42 行: mismatched types"
+    pub fn summary(&self) -> String {
+        let mut text = String::new();
+        if let Some(code) = &self.code {
+            text.push_str(code);
+            text.push(' ');
+        }
+        if let Some(line) = self.line {
+            text.push_str(&t!("第 {} 行: ", line));
+        }
+        text.push_str(&self.message);
+        text
+    }
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    message: String,
+    code: Option<RawCode>,
+    level: String,
+    spans: Vec<RawSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    line_start: usize,
+    is_primary: bool,
+}
+
+// 解析 rustc 的 stderr；无法识别为 JSON 的行原样保留
+pub fn parse(stderr: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for line in stderr.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<RawDiagnostic>(line) {
+            Ok(raw) => {
+                let line = raw
+                    .spans
+                    .iter()
+                    .find(|span| span.is_primary)
+                    .or(raw.spans.first())
+                    .map(|span| span.line_start);
+
+                diagnostics.push(Diagnostic {
+                    level: raw.level,
+                    code: raw.code.map(|code| code.code),
+                    rendered: raw.rendered.unwrap_or_else(|| raw.message.clone()),
+                    message: raw.message,
+                    line,
+                });
+            }
+            Err(_) => {
+                let level = if line.starts_with("error") {
+                    "error"
+                } else {
+                    "note"
+                };
+                diagnostics.push(Diagnostic {
+                    level: level.to_string(),
+                    code: None,
+                    message: line.to_string(),
+                    line: None,
+                    rendered: format!("{}\n", line),
+                });
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MISMATCHED: &str = r#"{"$message_type":"diagnostic","message":"mismatched types","code":{"code":"E0308","explanation":"..."},"level":"error","spans":[{"file_name":"demo.rs","line_start":3,"line_end":3,"is_primary":false},{"file_name":"demo.rs","line_start":5,"line_end":5,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n"}"#;
+
+    #[test]
+    fn uses_primary_span_line() {
+        let diagnostics = parse(MISMATCHED);
+        assert_eq!(diagnostics.len(), 1);
+        let error = &diagnostics[0];
+        assert!(error.is_error());
+        assert_eq!(error.code.as_deref(), Some("E0308"));
+        assert_eq!(error.line, Some(5));
+        assert_eq!(error.rendered, "error[E0308]: mismatched types\n");
+    }
+
+    #[test]
+    fn summaries_are_not_errors_or_warnings() {
+        let stderr = concat!(
+            r#"{"message":"unused variable: `x`","code":{"code":"unused_variables"},"level":"warning","spans":[{"line_start":2,"is_primary":true}],"rendered":"warning: unused variable\n"}"#,
+            "\n",
+            r#"{"message":"1 warning emitted","code":null,"level":"warning","spans":[],"rendered":"warning: 1 warning emitted\n"}"#,
+            "\n",
+            r#"{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"rendered":null}"#,
+        );
+        let diagnostics = parse(stderr);
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics[0].is_warning());
+        assert!(!diagnostics[1].is_warning());
+        assert!(!diagnostics[2].is_error());
+        assert_eq!(diagnostics[2].line, None);
+        assert_eq!(diagnostics[2].rendered, "aborting due to 1 previous error");
+    }
+
+    #[test]
+    fn keeps_plain_text_lines() {
+        let diagnostics = parse("\nerror: linking with `cc` failed\nnote: some note\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].is_error());
+        assert_eq!(diagnostics[0].rendered, "error: linking with `cc` failed\n");
+        assert_eq!(diagnostics[1].level, "note");
+    }
+}
@@ -0,0 +1,280 @@
+// 按行比较两段文本（最长公共子序列），用于参考答案和输出对比
+
+use std::io::{self, IsTerminal};
+
+use crate::ui::paint;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change<'a> {
+    Same(&'a str),
+    // 在自定义比较下相等、但原文不同的行（旧行，新行）
+    Similar(&'a str, &'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+pub fn lines<'a>(old: &'a str, new: &'a str) -> Vec<Change<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    pairs(&old, &new, |a, b| a == b)
+}
+
+// 中间不同的部分超过这个规模（约 3000 × 3000 行）时不再求最长公共子序列，避免输出很大时卡住
+const MAX_COMPARISONS: usize = 10_000_000;
+
+// 自定义相等判断的版本，例如忽略空白或浮点格式差异
+// 用 Hirschberg 算法求最长公共子序列，只保存两行长度表，内存与行数成正比；
+// 先去掉相同的开头和结尾，输出基本一致时几乎不需要比较
+pub fn pairs<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    equal: impl Fn(&str, &str) -> bool,
+) -> Vec<Change<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| equal(a, b)).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| equal(a, b))
+        .count();
+
+    let mut changes = Vec::with_capacity(old.len().max(new.len()));
+    for (a, b) in old.iter().zip(new).take(prefix) {
+        changes.push(matched(a, b));
+    }
+    let old_rest = &old[prefix..old.len() - suffix];
+    let new_rest = &new[prefix..new.len() - suffix];
+    if old_rest.len().saturating_mul(new_rest.len()) > MAX_COMPARISONS {
+        by_position(old_rest, new_rest, &equal, &mut changes);
+    } else {
+        align(old_rest, new_rest, &equal, &mut changes);
+    }
+    for (a, b) in old[old.len() - suffix..]
+        .iter()
+        .zip(&new[new.len() - suffix..])
+    {
+        changes.push(matched(a, b));
+    }
+    changes
+}
+
+fn matched<'a>(old: &'a str, new: &'a str) -> Change<'a> {
+    if old == new {
+        Change::Same(new)
+    } else {
+        Change::Similar(old, new)
+    }
+}
+
+// 按行号逐行对照，多出来的行算作删除或新增
+fn by_position<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    equal: &impl Fn(&str, &str) -> bool,
+    changes: &mut Vec<Change<'a>>,
+) {
+    for (a, b) in old.iter().zip(new) {
+        if equal(a, b) {
+            changes.push(matched(a, b));
+        } else {
+            changes.push(Change::Removed(a));
+            changes.push(Change::Added(b));
+        }
+    }
+    changes.extend(
+        old[new.len().min(old.len())..]
+            .iter()
+            .map(|line| Change::Removed(line)),
+    );
+    changes.extend(
+        new[old.len().min(new.len())..]
+            .iter()
+            .map(|line| Change::Added(line)),
+    );
+}
+
+// 把 old 从中间分成两半，找到 new 中让两边公共子序列之和最大的切分点，再分别递归
+fn align<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    equal: &impl Fn(&str, &str) -> bool,
+    changes: &mut Vec<Change<'a>>,
+) {
+    if new.is_empty() {
+        changes.extend(old.iter().map(|line| Change::Removed(line)));
+        return;
+    }
+    if old.len() <= 1 {
+        // 只有一行时直接找它在 new 中第一次出现的位置
+        match old.first().and_then(|line| {
+            new.iter()
+                .position(|other| equal(line, other))
+                .map(|j| (line, j))
+        }) {
+            Some((line, j)) => {
+                changes.extend(new[..j].iter().map(|line| Change::Added(line)));
+                changes.push(matched(line, new[j]));
+                changes.extend(new[j + 1..].iter().map(|line| Change::Added(line)));
+            }
+            None => {
+                changes.extend(old.iter().map(|line| Change::Removed(line)));
+                changes.extend(new.iter().map(|line| Change::Added(line)));
+            }
+        }
+        return;
+    }
+
+    let middle = old.len() / 2;
+    let forward = lcs_lengths(old[..middle].iter(), new.iter(), equal);
+    let backward = lcs_lengths(old[middle..].iter().rev(), new.iter().rev(), equal);
+    let split = (0..=new.len())
+        .max_by_key(|&j| (forward[j] + backward[new.len() - j], std::cmp::Reverse(j)))
+        .unwrap_or(0);
+
+    align(&old[..middle], &new[..split], equal, changes);
+    align(&old[middle..], &new[split..], equal, changes);
+}
+
+// lengths[j] = old 和 new 的前 j 行的最长公共子序列长度，逐行滚动计算
+fn lcs_lengths<'a, 'b>(
+    old: impl Iterator<Item = &'a &'b str>,
+    new: impl Iterator<Item = &'a &'b str> + Clone,
+    equal: &impl Fn(&str, &str) -> bool,
+) -> Vec<usize>
+where
+    'b: 'a,
+{
+    let width = new.clone().count();
+    let mut previous = vec![0usize; width + 1];
+    let mut current = vec![0usize; width + 1];
+    for a in old {
+        for (j, b) in new.clone().enumerate() {
+            current[j + 1] = if equal(a, b) {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+// 输出到终端时使用红/绿色，仅格式不同的行用黄色 "~" 标出
+pub fn print(changes: &[Change], old_label: &str, new_label: &str) {
+    let color = io::stdout().is_terminal();
+
+    println!("{}", paint("31", &format!("--- {}", old_label), color));
+    println!("{}", paint("32", &format!("+++ {}", new_label), color));
+    for change in changes {
+        match change {
+            Change::Same(line) => println!(" {}", line),
+            Change::Similar(_, line) => println!("{}", paint("33", &format!("~{}", line), color)),
+            Change::Removed(line) => println!("{}", paint("31", &format!("-{}", line), color)),
+            Change::Added(line) => println!("{}", paint("32", &format!("+{}", line), color)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 从变化列表还原两边的文本
+    fn sides<'a>(changes: &[Change<'a>]) -> (Vec<&'a str>, Vec<&'a str>) {
+        let mut old = Vec::new();
+        let mut new = Vec::new();
+        for change in changes {
+            match *change {
+                Change::Same(line) => {
+                    old.push(line);
+                    new.push(line);
+                }
+                Change::Similar(a, b) => {
+                    old.push(a);
+                    new.push(b);
+                }
+                Change::Removed(line) => old.push(line),
+                Change::Added(line) => new.push(line),
+            }
+        }
+        (old, new)
+    }
+
+    #[test]
+    fn identical_text_has_no_changes() {
+        let changes = lines("a\nb\nc", "a\nb\nc");
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, Change::Same(_))));
+        assert_eq!(changes.len(), 3);
+    }
+
+    #[test]
+    fn finds_insertions_and_removals() {
+        let changes = lines("a\nb\nc\nd", "a\nc\nd\ne");
+        assert_eq!(
+            changes,
+            vec![
+                Change::Same("a"),
+                Change::Removed("b"),
+                Change::Same("c"),
+                Change::Same("d"),
+                Change::Added("e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_equality_marks_similar_lines() {
+        let old = ["x = 1.0", "done"];
+        let new = ["x = 1", "done"];
+        let changes = pairs(&old, &new, |a, b| {
+            a.trim_end_matches(".0") == b.trim_end_matches(".0")
+        });
+        assert_eq!(
+            changes,
+            vec![Change::Similar("x = 1.0", "x = 1"), Change::Same("done")]
+        );
+    }
+
+    #[test]
+    fn keeps_a_longest_common_subsequence() {
+        let old = ["a", "b", "c", "a", "b", "b", "a"];
+        let new = ["c", "b", "a", "b", "a", "c"];
+        let changes = pairs(&old, &new, |a, b| a == b);
+        let same = changes
+            .iter()
+            .filter(|change| matches!(change, Change::Same(_)))
+            .count();
+        assert_eq!(same, 4);
+        assert_eq!(sides(&changes), (old.to_vec(), new.to_vec()));
+    }
+
+    #[test]
+    fn handles_empty_sides() {
+        assert_eq!(lines("", "a"), vec![Change::Added("a")]);
+        assert_eq!(lines("a", ""), vec![Change::Removed("a")]);
+        assert!(lines("", "").is_empty());
+    }
+
+    #[test]
+    fn large_changes_are_compared_by_position() {
+        let old: Vec<String> = (0..20_000).map(|i| format!("line {}", i)).collect();
+        let mut new = old.clone();
+        new[10_000] = "changed".to_string();
+        new.push("tail".to_string());
+        let old: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new: Vec<&str> = new.iter().map(String::as_str).collect();
+
+        let changes = pairs(&old, &new, |a, b| a == b);
+        assert_eq!(sides(&changes), (old, new));
+        assert_eq!(
+            changes
+                .iter()
+                .filter(|change| !matches!(change, Change::Same(_)))
+                .count(),
+            3
+        );
+    }
+}
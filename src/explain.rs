@@ -0,0 +1,200 @@
+// 编译错误讲解
+// rustc 的错误信息对 TypeScript 开发者来说往往很陌生，这里为最常见的错误码准备了中英双语的说明：
+// 同样的写法在 TypeScript 里为什么可以，在 Rust 里为什么不行，以及通常怎么改
+
+use crate::clippy;
+use crate::diagnostics::Diagnostic;
+use crate::i18n::{t, tr};
+
+pub struct Explanation {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub title_en: &'static str,
+    // TypeScript 中能通过的对应写法
+    pub typescript: &'static str,
+    pub detail: &'static str,
+    pub detail_en: &'static str,
+    pub fix: &'static str,
+    pub fix_en: &'static str,
+    // 讲解这个概念的课程模块
+    pub lesson: &'static str,
+}
+
+pub const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "E0382",
+        title: "使用了已经被移动的值",
+        title_en: "use of a moved value",
+        typescript: "const a = { name: \"Tom\" };\nconst b = a;\nconsole.log(a.name); // ✅ a 和 b 指向同一个对象",
+        detail: "TypeScript 中赋值和传参只是复制了对象的引用，两个变量可以同时使用。\
+                 Rust 中 String、Vec 这类值只有一个所有者，赋值或传给函数后所有权就转移了，原来的变量不能再用。",
+        detail_en: "In TypeScript, assignment and argument passing copy a reference, so both variables stay usable. \
+                    In Rust, values like String and Vec have exactly one owner; assigning or passing them moves \
+                    ownership and the original variable becomes unusable.",
+        fix: "只需要读取时传引用 &a；确实需要两份数据时用 a.clone()；或者调整顺序，在移动之前使用原变量。",
+        fix_en: "Pass a reference (&a) when you only need to read, call a.clone() when you really need two copies, \
+                 or use the original variable before it is moved.",
+        lesson: "ownership-borrowing",
+    },
+    Explanation {
+        code: "E0499",
+        title: "同时存在多个可变借用",
+        title_en: "more than one mutable borrow at a time",
+        typescript: "const list = [1, 2];\nconst r1 = list;\nconst r2 = list;\nr1.push(3);\nr2.push(4); // ✅ 随时可以通过任意引用修改",
+        detail: "TypeScript 允许任意多个引用同时修改同一个对象。\
+                 Rust 规定同一时间只能有一个可变引用（&mut），这样编译期就能排除数据竞争和迭代时修改集合之类的问题。",
+        detail_en: "TypeScript lets any number of references mutate the same object. \
+                    Rust allows only one mutable reference (&mut) at a time, which rules out data races and \
+                    mutation-during-iteration bugs at compile time.",
+        fix: "让第一个可变引用用完之后再创建第二个（借用在最后一次使用后结束），或者用 {} 缩小它的作用域。",
+        fix_en: "Finish using the first mutable reference before creating the second one (a borrow ends at its \
+                 last use), or limit its scope with a {} block.",
+        lesson: "ownership-borrowing",
+    },
+    Explanation {
+        code: "E0502",
+        title: "可变借用与不可变借用冲突",
+        title_en: "mutable borrow conflicts with an immutable borrow",
+        typescript: "const items = [1, 2, 3];\nconst first = items[0];\nitems.push(4);\nconsole.log(first); // ✅ 读和写可以随意交错",
+        detail: "TypeScript 中读取和修改可以随意交错。\
+                 Rust 中存在不可变引用（&）时不能再创建可变引用（&mut），反之亦然：\
+                 例如 Vec 扩容后旧的引用会指向已经释放的内存，编译器因此拒绝这种写法。",
+        detail_en: "In TypeScript, reads and writes can interleave freely. \
+                    In Rust you cannot take a mutable reference (&mut) while an immutable one (&) is alive, and \
+                    vice versa: after a Vec grows, an old reference would point into freed memory, so the \
+                    compiler rejects it.",
+        fix: "先完成读取（或者 clone / 复制出需要的值），再进行修改；不要在遍历集合的同时修改它。",
+        fix_en: "Finish reading first (or clone / copy out the value you need) and mutate afterwards; do not \
+                 modify a collection while iterating over it.",
+        lesson: "ownership-borrowing",
+    },
+    Explanation {
+        code: "E0106",
+        title: "缺少生命周期标注",
+        title_en: "missing lifetime specifier",
+        typescript: "function longest(x: string, y: string): string {\n  return x.length > y.length ? x : y; // ✅ 垃圾回收保证返回值一直有效\n}",
+        detail: "TypeScript 有垃圾回收，返回的引用永远有效。\
+                 Rust 没有垃圾回收，函数返回引用时编译器需要知道它借用自哪个参数，才能保证调用者使用它时数据还活着。\
+                 longest 有两个引用参数，编译器无法自己推断。",
+        detail_en: "TypeScript is garbage collected, so a returned reference is always valid. \
+                    Rust has no GC: when a function returns a reference the compiler must know which parameter it \
+                    borrows from, to ensure the data outlives its use. With two reference parameters, as in \
+                    longest, it cannot infer this on its own.",
+        fix: "添加生命周期参数，例如 fn longest<'a>(x: &'a str, y: &'a str) -> &'a str；或者返回拥有所有权的 String。",
+        fix_en: "Add a lifetime parameter, e.g. fn longest<'a>(x: &'a str, y: &'a str) -> &'a str, or return an \
+                 owned String instead.",
+        lesson: "ownership-borrowing",
+    },
+    Explanation {
+        code: "E0308",
+        title: "类型不匹配",
+        title_en: "mismatched types",
+        typescript: "let total: number = 1;\ntotal = 2.5;          // ✅ 只有一种 number\nconst s: string = `${total}`;",
+        detail: "TypeScript 只有一种 number，很多地方会自动转换或放宽类型。\
+                 Rust 区分 i32、u32、f64、String、&str 等类型，并且从不隐式转换；\
+                 另外函数最后一个表达式后面多写了分号，返回值就会变成 ()，也会报这个错误。",
+        detail_en: "TypeScript has a single number type and widens or coerces in many places. \
+                    Rust distinguishes i32, u32, f64, String, &str and so on, and never converts implicitly; \
+                    an extra semicolon after a function's final expression also turns the return value into () \
+                    and triggers this error.",
+        fix: "用 as 转换数字类型（x as f64），用 .to_string() / String::from 得到 String，用 & 得到 &str；\
+              检查函数末尾的表达式是不是多了分号。",
+        fix_en: "Convert numbers with as (x as f64), get a String with .to_string() / String::from, borrow a \
+                 &str with &, and check for a stray semicolon after the final expression.",
+        lesson: "data-types",
+    },
+    Explanation {
+        code: "E0004",
+        title: "match 没有覆盖所有情况",
+        title_en: "non-exhaustive patterns in match",
+        typescript: "switch (status) {\n  case \"active\": return 1;\n  // ✅ 漏掉其他情况也能编译，运行时返回 undefined\n}",
+        detail: "TypeScript 的 switch 漏掉分支也能编译，只会在运行时得到 undefined。\
+                 Rust 的 match 必须穷尽所有可能的值，新增枚举变体时编译器会指出所有需要更新的地方。",
+        detail_en: "A TypeScript switch compiles even when cases are missing and simply yields undefined at \
+                    runtime. A Rust match must be exhaustive, so adding an enum variant makes the compiler \
+                    point at every match that needs updating.",
+        fix: "为缺少的变体补上分支（错误信息会列出来），或者在最后加一个 _ => ... 通配分支。",
+        fix_en: "Add arms for the missing variants (the error message lists them), or add a final _ => ... \
+                 wildcard arm.",
+        lesson: "pattern-matching",
+    },
+];
+
+pub fn find(code: &str) -> Option<&'static Explanation> {
+    EXPLANATIONS
+        .iter()
+        .find(|explanation| explanation.code.eq_ignore_ascii_case(code))
+}
+
+// 为诊断中出现的错误码各打印一次讲解
+pub fn print_for<'a>(diagnostics: impl IntoIterator<Item = &'a Diagnostic>) {
+    let mut seen = Vec::new();
+    for diagnostic in diagnostics.into_iter().filter(|d| d.is_error()) {
+        let Some(explanation) = diagnostic.code.as_deref().and_then(find) else {
+            continue;
+        };
+        if !seen.contains(&explanation.code) {
+            seen.push(explanation.code);
+            print(explanation);
+        }
+    }
+}
+
+pub fn print(explanation: &Explanation) {
+    println!();
+    println!(
+        "📘 {} {} / {}",
+        explanation.code, explanation.title, explanation.title_en
+    );
+    println!("{}", tr("   在 TypeScript 中 / In TypeScript:"));
+    for line in explanation.typescript.lines() {
+        println!("     {}", line);
+    }
+    println!("   🇨🇳 {}", explanation.detail);
+    println!("      👉 {}", explanation.fix);
+    println!("   🇬🇧 {}", explanation.detail_en);
+    println!("      👉 {}", explanation.fix_en);
+    println!("{}", t!("   📚 相关课程 / Lesson: {}", explanation.lesson));
+}
+
+// explain 命令：不带参数时列出所有讲解过的错误码
+pub fn explain(code: Option<&str>) -> Result<(), String> {
+    let Some(code) = code else {
+        println!("{}", tr("📘 有讲解的编译错误:"));
+        for explanation in EXPLANATIONS {
+            println!(
+                "  {}  {} / {}",
+                explanation.code, explanation.title, explanation.title_en
+            );
+        }
+        println!();
+        println!("{}", tr("📎 有讲解的 clippy lint:"));
+        for explanation in clippy::EXPLANATIONS {
+            println!(
+                "  {}  {} / {}",
+                explanation.lint, explanation.title, explanation.title_en
+            );
+        }
+        println!();
+        println!(
+            "{}",
+            tr("查看讲解: cargo run -- explain E0382 或 cargo run -- explain needless_range_loop")
+        );
+        return Ok(());
+    };
+
+    if let Some(explanation) = find(code) {
+        print(explanation);
+        return Ok(());
+    }
+    // 也可以查看 clippy lint 的讲解，例如 explain needless_range_loop
+    if let Some(explanation) = clippy::find(code) {
+        clippy::print_explanation(explanation);
+        return Ok(());
+    }
+    Err(t!(
+        "没有 {} 的讲解，可以运行 rustc --explain {} 查看官方说明",
+        code,
+        code
+    ))
+}
@@ -0,0 +1,340 @@
+// 逐个练习运行 demo.rs
+// 模板中还没完成的函数体是 todo!()，直接运行时第一个练习就会 panic，后面的练习都看不到结果。
+// 这里把 main 中每个 "// 练习 N: ..." 块改写成闭包，交给 catch_unwind 逐个执行：
+// 某个练习 panic 只影响它自己，每个练习分别报告 "通过"、"还没有实现" 或 "panicked with ..."。
+// 改写只在练习标记所在的行首插入代码，行号和 demo.rs 保持一致
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use syn::spanned::Spanned;
+use syn::Item;
+
+use crate::checker;
+use crate::i18n::{t, tr};
+use crate::runner::{self, RunOutput};
+
+// 追加到改写后源码末尾的运行时；结果逐行写到 --exercise-report= 指定的文件，进程被终止也不会丢失。
+// __TODO_MESSAGE__ / __PANIC_MESSAGE__ 在改写时替换成当前界面语言的格式字符串
+const RUNTIME: &str = r#"
+#[doc(hidden)]
+mod __tutorial_harness {
+    use std::io::Write;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Mutex, Once};
+
+    static HOOK: Once = Once::new();
+    static INSIDE: AtomicBool = AtomicBool::new(false);
+    static PANIC: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+    pub fn exercise(name: &str, body: impl FnOnce()) {
+        HOOK.call_once(|| {
+            let default = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !INSIDE.load(Ordering::SeqCst) {
+                    return default(info);
+                }
+                let payload = info.payload();
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "Box<dyn Any>".to_string());
+                let line = info.location().map_or(0, |location| location.line());
+                *PANIC.lock().unwrap_or_else(|e| e.into_inner()) = Some((message, line));
+            }));
+        });
+
+        record(&["start", name]);
+        INSIDE.store(true, Ordering::SeqCst);
+        let result = panic::catch_unwind(AssertUnwindSafe(body));
+        INSIDE.store(false, Ordering::SeqCst);
+        if result.is_ok() {
+            record(&["passed", name]);
+            return;
+        }
+
+        let (message, line) = PANIC
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_default();
+        if message.starts_with("not yet implemented") || message.starts_with("not implemented") {
+            println!(__TODO_MESSAGE__, name, line);
+            record(&["todo", name, &line.to_string()]);
+        } else {
+            println!(__PANIC_MESSAGE__, name, message, line);
+            let message = message.replace(['\t', '\n'], " ");
+            record(&["panic", name, &line.to_string(), &message]);
+        }
+    }
+
+    fn record(fields: &[&str]) {
+        let Some(path) = std::env::args()
+            .find_map(|arg| arg.strip_prefix("--exercise-report=").map(String::from))
+        else {
+            return;
+        };
+        let file = std::fs::OpenOptions::new().create(true).append(true).open(path);
+        if let Ok(mut file) = file {
+            let _ = writeln!(file, "{}", fields.join("\t"));
+        }
+    }
+}
+"#;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum Outcome {
+    Passed,
+    // todo!() / unimplemented!() 所在的行
+    Unimplemented { line: usize },
+    Panicked { message: String, line: usize },
+    // 练习开始后进程被终止（超出运行限制、栈溢出等），之后的练习都没有运行
+    Killed { reason: String },
+}
+
+impl Outcome {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Outcome::Passed => "✅",
+            Outcome::Unimplemented { .. } => "⬜",
+            Outcome::Panicked { .. } => "💥",
+            Outcome::Killed { .. } => "⛔",
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Outcome::Passed => tr("运行通过").to_string(),
+            Outcome::Unimplemented { line } => {
+                t!("还没有实现 (not yet implemented，第 {} 行)", line)
+            }
+            Outcome::Panicked { message, line } => {
+                t!("panicked with: {}（第 {} 行）", message, line)
+            }
+            Outcome::Killed { reason } => reason.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExerciseRun {
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+// 在 main 的练习标记前插入闭包的开头和结尾；main 中没有练习标记时返回 None
+pub fn instrument(source: &str) -> Option<String> {
+    let file = syn::parse_file(source).ok()?;
+    let main = file.items.iter().find_map(|item| match item {
+        Item::Fn(function) if function.sig.ident == "main" => Some(function),
+        _ => None,
+    })?;
+    let main_start = main.span().start().line;
+    let main_end = main.span().end().line;
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let mut opened = false;
+    for line_number in main_start + 1..main_end {
+        let line = &mut lines[line_number - 1];
+        let Some(title) = checker::parse_exercise_marker(line) else {
+            continue;
+        };
+        let close = if opened { "}); " } else { "" };
+        *line = format!(
+            "{}crate::__tutorial_harness::exercise({:?}, || {{ {}",
+            close, title, line
+        );
+        opened = true;
+    }
+    if !opened {
+        return None;
+    }
+    let last = &mut lines[main_end - 1];
+    *last = format!("}}); {}", last);
+
+    let mut instrumented = lines.join("\n");
+    instrumented.push('\n');
+    let todo = format!(
+        "⬜ {{}}: {}",
+        tr("还没有实现 (not yet implemented，第 {} 行)")
+    );
+    let panic = format!("💥 {{}}: {}", tr("panicked with: {}（第 {} 行）"));
+    instrumented.push_str(
+        &RUNTIME
+            .replace("__TODO_MESSAGE__", &format!("{:?}", todo))
+            .replace("__PANIC_MESSAGE__", &format!("{:?}", panic)),
+    );
+    Some(instrumented)
+}
+
+// 编译改写后的程序；练习之间共用变量等原因无法改写时返回 None，由调用方按整个程序运行
+pub fn build(source: &str, build_dir: &Path) -> Result<Option<PathBuf>, String> {
+    let Some(instrumented) = instrument(source) else {
+        return Ok(None);
+    };
+    let path = build_dir.join("harness.rs");
+    fs::write(&path, instrumented).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    let compiled = runner::compile_quiet(&path, build_dir)?;
+    Ok(compiled.success.then_some(compiled.binary))
+}
+
+// 运行改写后的程序，返回每个已经开始的练习的结果
+pub fn run(
+    binary: &Path,
+    build_dir: &Path,
+    stream: bool,
+) -> Result<(Vec<ExerciseRun>, RunOutput), String> {
+    let report = build_dir.join("exercises.txt");
+    let _ = fs::remove_file(&report);
+    let arg = format!("--exercise-report={}", report.display());
+    let output = runner::execute(binary, &[&arg], stream)?;
+    let records = fs::read_to_string(&report).unwrap_or_default();
+    Ok((parse_records(&records, &output), output))
+}
+
+fn parse_records(records: &str, output: &RunOutput) -> Vec<ExerciseRun> {
+    let mut runs: Vec<ExerciseRun> = Vec::new();
+    let mut running: Option<String> = None;
+
+    for record in records.lines() {
+        let fields: Vec<&str> = record.splitn(4, '\t').collect();
+        let line = || {
+            fields
+                .get(2)
+                .and_then(|line| line.parse().ok())
+                .unwrap_or(0)
+        };
+        let outcome = match fields.as_slice() {
+            ["start", name] => {
+                running = Some(name.to_string());
+                continue;
+            }
+            ["passed", _] => Outcome::Passed,
+            ["todo", _, _] => Outcome::Unimplemented { line: line() },
+            ["panic", _, _, message] => Outcome::Panicked {
+                message: message.to_string(),
+                line: line(),
+            },
+            _ => continue,
+        };
+        if let Some(name) = running.take() {
+            runs.push(ExerciseRun { name, outcome });
+        }
+    }
+
+    // 开始了但没有结果的练习就是进程被终止时正在运行的那个
+    if let Some(name) = running {
+        let reason = match output.limit {
+            Some(limit) => limit.describe(),
+            None => runner::describe_status(output.success, output.exit_code),
+        };
+        runs.push(ExerciseRun {
+            name,
+            outcome: Outcome::Killed { reason },
+        });
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const SOURCE: &str = "\
+fn main() {
+    println!(\"开始\");
+    // 练习 1: 基本变量声明
+    let x = 1;
+    println!(\"{}\", x);
+
+    // 练习 2: 类型推断
+    todo!();
+}
+
+fn helper() {}
+";
+
+    #[test]
+    fn wraps_each_exercise_in_a_closure() {
+        let instrumented = instrument(SOURCE).expect("main 中有练习标记");
+        let lines: Vec<&str> = instrumented.lines().collect();
+        let open = |title: &str| {
+            format!(
+                "crate::__tutorial_harness::exercise({:?}, || {{     // {}",
+                title, title
+            )
+        };
+        assert_eq!(lines[1], "    println!(\"开始\");");
+        assert_eq!(lines[2], open("练习 1: 基本变量声明"));
+        assert_eq!(lines[6], format!("}}); {}", open("练习 2: 类型推断")));
+        assert_eq!(lines[8], "}); }");
+    }
+
+    #[test]
+    fn keeps_line_numbers_of_the_original() {
+        let instrumented = instrument(SOURCE).unwrap();
+        let original: Vec<&str> = SOURCE.lines().collect();
+        let lines: Vec<&str> = instrumented.lines().collect();
+        // panic 和 todo!() 报告的行号直接对应 demo.rs
+        for (number, line) in original.iter().enumerate() {
+            assert!(lines[number].ends_with(line), "第 {} 行", number + 1);
+        }
+    }
+
+    #[test]
+    fn returns_none_without_markers_or_when_unparsable() {
+        assert!(instrument("fn main() {\n    println!(\"hi\");\n}\n").is_none());
+        assert!(instrument("fn main() {\n    // 练习 1: x\n").is_none());
+        assert!(instrument("fn helper() {}\n").is_none());
+    }
+
+    fn output(success: bool) -> RunOutput {
+        RunOutput {
+            success,
+            exit_code: if success { Some(0) } else { Some(101) },
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: Duration::ZERO,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn parses_exercise_records() {
+        let records = "start\t练习 1: a\npassed\t练习 1: a\n\
+                       start\t练习 2: b\ntodo\t练习 2: b\t12\n\
+                       start\t练习 3: c\npanic\t练习 3: c\t20\tindex out of bounds\n";
+        let runs = parse_records(records, &output(true));
+        let outcomes: Vec<(&str, &Outcome)> = runs
+            .iter()
+            .map(|run| (run.name.as_str(), &run.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("练习 1: a", &Outcome::Passed),
+                ("练习 2: b", &Outcome::Unimplemented { line: 12 }),
+                (
+                    "练习 3: c",
+                    &Outcome::Panicked {
+                        message: "index out of bounds".to_string(),
+                        line: 20
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unfinished_exercise_was_killed() {
+        let runs = parse_records("start\t练习 1: a\n", &output(false));
+        assert_eq!(runs.len(), 1);
+        assert!(matches!(runs[0].outcome, Outcome::Killed { .. }));
+    }
+}
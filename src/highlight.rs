@@ -0,0 +1,354 @@
+// Rust 和 TypeScript 代码的语法高亮
+// 只做词法层面的着色（关键字、类型、字符串、数字、注释、宏），不追求和编辑器完全一致；
+// 跨行的块注释和 TypeScript 模板字符串通过 Highlighter 中的状态延续到下一行
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Rust,
+    TypeScript,
+}
+
+impl Language {
+    // 代码块开头 ``` 后面的语言标记
+    pub fn from_tag(tag: &str) -> Option<Language> {
+        match tag.trim().to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "typescript" | "ts" | "javascript" | "js" => Some(Language::TypeScript),
+            _ => None,
+        }
+    }
+
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST_KEYWORDS,
+            Language::TypeScript => TS_KEYWORDS,
+        }
+    }
+
+    fn builtin_types(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST_TYPES,
+            Language::TypeScript => TS_TYPES,
+        }
+    }
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const RUST_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64", "bool", "char", "str",
+];
+
+const TS_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "keyof",
+    "let",
+    "new",
+    "null",
+    "of",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "var",
+    "while",
+    "yield",
+];
+
+const TS_TYPES: &[&str] = &[
+    "string", "number", "boolean", "any", "unknown", "never", "void", "object", "bigint", "symbol",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Plain,
+    Keyword,
+    Type,
+    String,
+    Number,
+    Comment,
+    Macro,
+}
+
+impl Kind {
+    // 终端中使用的 ANSI 颜色
+    pub fn ansi(self) -> &'static str {
+        match self {
+            Kind::Plain => "",
+            Kind::Keyword => "35",
+            Kind::Type => "36",
+            Kind::String => "32",
+            Kind::Number => "33",
+            Kind::Comment => "90",
+            Kind::Macro => "34",
+        }
+    }
+
+    // 网页中使用的 CSS 类名，普通文本不加
+    pub fn css_class(self) -> Option<&'static str> {
+        match self {
+            Kind::Plain => None,
+            Kind::Keyword => Some("kw"),
+            Kind::Type => Some("ty"),
+            Kind::String => Some("st"),
+            Kind::Number => Some("nu"),
+            Kind::Comment => Some("co"),
+            Kind::Macro => Some("ma"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Normal,
+    BlockComment,
+    Template,
+}
+
+pub struct Highlighter {
+    language: Language,
+    state: State,
+}
+
+impl Highlighter {
+    pub fn new(language: Language) -> Highlighter {
+        Highlighter {
+            language,
+            state: State::Normal,
+        }
+    }
+
+    // 把一行代码切成带类型的片段，拼起来就是原来的行
+    pub fn line(&mut self, line: &str) -> Vec<(Kind, String)> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut tokens = Tokens::default();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let rest = &chars[i..];
+            match self.state {
+                State::BlockComment => {
+                    let end = match find(rest, &['*', '/']) {
+                        Some(at) => {
+                            self.state = State::Normal;
+                            i + at + 2
+                        }
+                        None => chars.len(),
+                    };
+                    tokens.push(Kind::Comment, &chars[i..end]);
+                    i = end;
+                    continue;
+                }
+                State::Template => {
+                    let end = match closing_quote(&chars, i, '`') {
+                        Some(end) => {
+                            self.state = State::Normal;
+                            end
+                        }
+                        None => chars.len(),
+                    };
+                    tokens.push(Kind::String, &chars[i..end]);
+                    i = end;
+                    continue;
+                }
+                State::Normal => {}
+            }
+
+            let c = chars[i];
+            if rest.starts_with(&['/', '/']) {
+                tokens.push(Kind::Comment, rest);
+                break;
+            }
+            if rest.starts_with(&['/', '*']) {
+                self.state = State::BlockComment;
+                tokens.push(Kind::Comment, &rest[..2]);
+                i += 2;
+                continue;
+            }
+            if c == '"' || (c == '\'' && self.language == Language::TypeScript) {
+                let close = closing_quote(&chars, i + 1, c).unwrap_or(chars.len());
+                tokens.push(Kind::String, &chars[i..close]);
+                i = close;
+                continue;
+            }
+            if c == '`' && self.language == Language::TypeScript {
+                let close = closing_quote(&chars, i + 1, '`');
+                if close.is_none() {
+                    self.state = State::Template;
+                }
+                let close = close.unwrap_or(chars.len());
+                tokens.push(Kind::String, &chars[i..close]);
+                i = close;
+                continue;
+            }
+            if c == '\'' {
+                // Rust 中 'a' 是字符，'a 是生命周期
+                let length = char_literal(rest);
+                let kind = if length > 0 { Kind::String } else { Kind::Type };
+                let length = if length > 0 {
+                    length
+                } else {
+                    1 + rest[1..]
+                        .iter()
+                        .take_while(|c| c.is_alphanumeric() || **c == '_')
+                        .count()
+                };
+                tokens.push(kind, &rest[..length]);
+                i += length;
+                continue;
+            }
+            if c.is_ascii_digit() && !previous_is_word(&chars, i) {
+                let length = number_length(rest);
+                tokens.push(Kind::Number, &rest[..length]);
+                i += length;
+                continue;
+            }
+            if c.is_alphabetic() || c == '_' || c == '$' {
+                let length = rest
+                    .iter()
+                    .take_while(|c| c.is_alphanumeric() || **c == '_' || **c == '$')
+                    .count();
+                let word: String = rest[..length].iter().collect();
+                let is_macro = self.language == Language::Rust
+                    && rest.get(length) == Some(&'!')
+                    && rest.get(length + 1) != Some(&'=');
+                let kind = if is_macro {
+                    Kind::Macro
+                } else if self.language.keywords().contains(&word.as_str()) {
+                    Kind::Keyword
+                } else if self.language.builtin_types().contains(&word.as_str())
+                    || word.starts_with(|c: char| c.is_uppercase())
+                {
+                    Kind::Type
+                } else {
+                    Kind::Plain
+                };
+                let length = if is_macro { length + 1 } else { length };
+                tokens.push(kind, &rest[..length]);
+                i += length;
+                continue;
+            }
+            tokens.push(Kind::Plain, &rest[..1]);
+            i += 1;
+        }
+        tokens.0
+    }
+}
+
+// 相邻的同类片段合并在一起，减少颜色代码
+#[derive(Default)]
+struct Tokens(Vec<(Kind, String)>);
+
+impl Tokens {
+    fn push(&mut self, kind: Kind, chars: &[char]) {
+        if chars.is_empty() {
+            return;
+        }
+        match self.0.last_mut() {
+            Some((last, text)) if *last == kind => text.extend(chars),
+            _ => self.0.push((kind, chars.iter().collect())),
+        }
+    }
+}
+
+fn find(chars: &[char], pattern: &[char]) -> Option<usize> {
+    chars
+        .windows(pattern.len())
+        .position(|window| window == pattern)
+}
+
+// 从 start 开始找没有被转义的引号，返回引号之后的位置
+fn closing_quote(chars: &[char], start: usize, quote: char) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+// 'x'、'\n'、'\u{1F600}' 这样的字符字面量的长度，不是字符字面量时返回 0
+fn char_literal(chars: &[char]) -> usize {
+    match chars {
+        ['\'', '\\', 'u', '{', ..] => chars
+            .iter()
+            .position(|c| *c == '}')
+            .filter(|end| chars.get(end + 1) == Some(&'\''))
+            .map_or(0, |end| end + 2),
+        ['\'', '\\', _, '\'', ..] => 4,
+        ['\'', c, '\'', ..] if *c != '\\' => 3,
+        _ => 0,
+    }
+}
+
+fn previous_is_word(chars: &[char], i: usize) -> bool {
+    i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_')
+}
+
+// 42、3.14、1_000、0xff、2u8、1e-3；0..10 中的 .. 不算小数点
+fn number_length(chars: &[char]) -> usize {
+    let mut length = 0;
+    while length < chars.len() {
+        let c = chars[length];
+        let is_fraction = c == '.'
+            && chars
+                .get(length + 1)
+                .is_some_and(|next| next.is_ascii_digit())
+            && !chars[..length].contains(&'.');
+        let is_exponent_sign =
+            (c == '-' || c == '+') && length > 0 && matches!(chars[length - 1], 'e' | 'E');
+        if c.is_ascii_alphanumeric() || c == '_' || is_fraction || is_exponent_sign {
+            length += 1;
+        } else {
+            break;
+        }
+    }
+    length
+}
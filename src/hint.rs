@@ -0,0 +1,332 @@
+// 渐进式提示
+// 每次请求提示都会多揭示一级：
+//   1. demo.rs 模板里的 "// 提示：..." 注释
+//   2. answer.md 中最相关的讲解小节
+//   3. 参考答案中对应的函数签名和实现
+
+use std::collections::HashSet;
+use std::fs;
+
+use crate::answer::{self, Answer, Section, Snippet};
+use crate::checker::{self, Status, Task, TaskKind};
+use crate::course::Course;
+use crate::i18n::{t, tr};
+use crate::progress;
+use crate::solution;
+
+pub const MAX_LEVEL: u32 = 3;
+
+// 一次提示的内容，hint 命令打印它，serve 以 JSON 返回
+pub struct Hint {
+    pub module: String,
+    pub exercise: String,
+    // 本次揭示到第几级（1 ~ MAX_LEVEL）和累计使用次数
+    pub level: u32,
+    pub used: u32,
+    pub inline: Vec<String>,
+    // 第 2 级起才查找；没有找到相关小节时为 None
+    pub section: Option<Section>,
+    // 第 3 级才查找
+    pub reference: Option<String>,
+}
+
+pub fn hint(course: &Course, module: &str, exercise: Option<&str>) -> Result<(), String> {
+    let hint = next_hint(course, module, exercise)?;
+
+    println!(
+        "{}",
+        t!(
+            "💡 {} / {} 的提示（第 {}/{} 级，已使用 {} 次）",
+            hint.module,
+            hint.exercise,
+            hint.level,
+            MAX_LEVEL,
+            hint.used
+        )
+    );
+
+    println!();
+    println!("{}", tr("── 第 1 级：模板中的提示 ──"));
+    if hint.inline.is_empty() {
+        println!("{}", tr("  （模板中没有针对这个练习的提示）"));
+    }
+    for line in &hint.inline {
+        println!("  • {}", line);
+    }
+
+    if hint.level >= 2 {
+        println!();
+        match &hint.section {
+            Some(section) => {
+                println!(
+                    "{}",
+                    t!(
+                        "── 第 2 级：answer.md「{}」（第 {} 行）──",
+                        section.title,
+                        section.line
+                    )
+                );
+                print!("{}", section.body.trim_end());
+                println!();
+            }
+            None => {
+                println!("{}", tr("── 第 2 级：answer.md ──"));
+                println!("{}", tr("  （answer.md 中没有找到相关的讲解小节）"));
+            }
+        }
+    }
+
+    if hint.level >= 3 {
+        println!();
+        println!("{}", tr("── 第 3 级：参考实现 ──"));
+        match &hint.reference {
+            Some(source) => print!("{}", source),
+            None => println!("{}", t!("  （参考答案中没有找到 {}）", hint.exercise)),
+        }
+    }
+
+    if hint.level < MAX_LEVEL {
+        println!();
+        println!(
+            "{}",
+            t!(
+                "🔓 还需要更多帮助？再次运行 hint {} \"{}\" 查看下一级提示",
+                hint.module,
+                hint.exercise
+            )
+        );
+    }
+
+    Ok(())
+}
+
+// 找到要提示的练习（默认是第一个没完成的），记录一次使用并按累计次数决定揭示到第几级
+pub fn next_hint(course: &Course, module: &str, exercise: Option<&str>) -> Result<Hint, String> {
+    let lesson = course.find(module)?;
+    let report = checker::check(lesson)?;
+    if let Some(error) = &report.parse_error {
+        return Err(t!("demo.rs 有语法错误，先修复后再获取提示: {}", error));
+    }
+
+    let task = match exercise {
+        Some(query) => find_task(&report.tasks, query)?,
+        None => report
+            .tasks
+            .iter()
+            .find(|task| task.status != Status::Done)
+            .ok_or_else(|| t!("{} 的所有练习都已完成，不需要提示啦", lesson.id))?,
+    };
+
+    // 编译失败练习在 compile_fail/ 下的单独文件中
+    let path = match &task.file {
+        Some(file) => lesson.dir.join(file),
+        None => lesson.demo_path(),
+    };
+    let source = fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+    let answer = Answer::load_localized(lesson)?;
+
+    let mut used = 1;
+    progress::update(course, |progress| {
+        used = progress.record_hint(&lesson.id, &task.name)
+    });
+    let level = used.clamp(1, MAX_LEVEL);
+
+    let section = if level >= 2 {
+        best_section(&answer.sections(), task).cloned()
+    } else {
+        None
+    };
+    let reference = if level >= 3 {
+        let snippets = answer::snippets(&solution::assemble(lesson)?)?;
+        reference_snippet(&snippets, task).map(|snippet| snippet.source.clone())
+    } else {
+        None
+    };
+
+    Ok(Hint {
+        module: lesson.id.clone(),
+        exercise: task.title.clone(),
+        level,
+        used,
+        inline: inline_hints(&source, task),
+        section,
+        reference,
+    })
+}
+
+// 可以写完整名称（中文或英文标题）、练习序号 ("3"、"练习 3" 或 "Exercise 3")，或名称的一部分
+pub fn find_task<'a>(tasks: &'a [Task], query: &str) -> Result<&'a Task, String> {
+    let query = query.trim();
+
+    if let Some(task) = tasks
+        .iter()
+        .find(|task| task.name == query || task.title == query)
+    {
+        return Ok(task);
+    }
+
+    let number = query
+        .trim_start_matches("练习")
+        .trim_start_matches("Exercise")
+        .trim();
+    if let Ok(number) = number.parse::<u32>() {
+        if let Some(task) = tasks.iter().find(|task| {
+            task.kind == TaskKind::Exercise && exercise_number(&task.name) == Some(number)
+        }) {
+            return Ok(task);
+        }
+    }
+
+    let lowered = query.to_lowercase();
+    let matches: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| {
+            task.name.to_lowercase().contains(&lowered)
+                || task.title.to_lowercase().contains(&lowered)
+        })
+        .collect();
+
+    match matches.as_slice() {
+        [task] => Ok(task),
+        [] => Err(t!("找不到练习 '{}'，使用 check 查看所有练习", query)),
+        _ => Err(t!(
+            "'{}' 匹配到多个练习: {}",
+            query,
+            matches
+                .iter()
+                .map(|task| task.title.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+// "练习 3: ? 运算符" → 3
+pub fn exercise_number(name: &str) -> Option<u32> {
+    let rest = name.strip_prefix("练习")?.trim_start();
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+// "练习 3: ? 运算符" → "? 运算符"；"Counter::next" → "next"；"struct User" → "User"
+fn short_name(task: &Task) -> &str {
+    match task.kind {
+        TaskKind::Exercise => task
+            .name
+            .split_once([':', '：'])
+            .map(|(_, title)| title.trim())
+            .unwrap_or(&task.name),
+        TaskKind::Function => task.name.rsplit("::").next().unwrap_or(&task.name),
+        TaskKind::Type => task.name.split_whitespace().last().unwrap_or(&task.name),
+        TaskKind::CompileFail => task.name.split(" (").next().unwrap_or(&task.name),
+    }
+}
+
+// 练习范围内（以及函数上方）的 "提示" 注释；没有提示时退回到 TODO 注释
+fn inline_hints(source: &str, task: &Task) -> Vec<String> {
+    // demo.rs 中缺失的练习（start_line 为 0）没有可以查找的范围
+    if task.start_line == 0 {
+        return Vec::new();
+    }
+    let lines: Vec<&str> = source.lines().collect();
+    let mut start = task.start_line;
+
+    if matches!(task.kind, TaskKind::Function | TaskKind::Type) {
+        while start > 1 && lines[start - 2].trim().starts_with("//") {
+            start -= 1;
+        }
+    }
+
+    let comments: Vec<String> = lines[start - 1..task.end_line.min(lines.len())]
+        .iter()
+        .filter_map(|line| line.trim().strip_prefix("//"))
+        .map(|comment| comment.trim().to_string())
+        .collect();
+
+    let hints: Vec<String> = comments
+        .iter()
+        .filter_map(|comment| {
+            comment
+                .strip_prefix("提示")
+                .or_else(|| comment.strip_prefix("注意"))
+        })
+        .map(|hint| hint.trim_start_matches(['：', ':']).trim().to_string())
+        .collect();
+
+    if !hints.is_empty() {
+        return hints;
+    }
+
+    comments
+        .iter()
+        .filter_map(|comment| comment.strip_prefix("TODO"))
+        .map(|todo| todo.trim_start_matches(['：', ':']).trim().to_string())
+        .collect()
+}
+
+fn best_section<'a>(sections: &'a [Section], task: &Task) -> Option<&'a Section> {
+    let name = short_name(task);
+
+    sections
+        .iter()
+        .filter(|section| {
+            !section.title.contains("完整实现")
+                && !section.title.contains("Complete implementation")
+                && !section.body.trim().is_empty()
+        })
+        .map(|section| {
+            let mut score =
+                similarity(name, &section.title) * 2.0 + similarity(name, &section.body);
+            if matches!(task.kind, TaskKind::Function | TaskKind::Type) {
+                // 函数名和类型名直接出现在讲解代码里最能说明相关
+                score += section.body.matches(name).count().min(3) as f64;
+            }
+            (score, section)
+        })
+        .filter(|(score, _)| *score > 0.3)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, section)| section)
+}
+
+fn reference_snippet<'a>(snippets: &'a [Snippet], task: &Task) -> Option<&'a Snippet> {
+    if matches!(task.kind, TaskKind::Function | TaskKind::Type) {
+        return snippets
+            .iter()
+            .find(|snippet| snippet.name.as_deref() == Some(task.name.as_str()));
+    }
+
+    // 参考答案里同样有 "// 练习 N" 标记时直接对应，否则按注释内容匹配最相近的一段
+    let number = exercise_number(&task.name);
+    if let Some(snippet) = snippets.iter().find(|snippet| {
+        number.is_some() && snippet.name.as_deref().and_then(exercise_number) == number
+    }) {
+        return Some(snippet);
+    }
+
+    let title = short_name(task);
+    snippets
+        .iter()
+        .map(|snippet| (similarity(title, &snippet.comment), snippet))
+        .filter(|(score, _)| *score > 0.3)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, snippet)| snippet)
+}
+
+// query 的字符二元组有多少出现在 text 中（0.0 ~ 1.0），对中文和英文都适用
+fn similarity(query: &str, text: &str) -> f64 {
+    let wanted = bigrams(query);
+    if wanted.is_empty() {
+        return 0.0;
+    }
+    let present = bigrams(text);
+    wanted.intersection(&present).count() as f64 / wanted.len() as f64
+}
+
+fn bigrams(text: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = text
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
@@ -0,0 +1,315 @@
+// 界面语言
+// 中文是源语言：代码中直接写中文文案，用 tr("...") / t!("...", 参数) 包起来；
+// 选择英文时到 locales/en.toml 中用中文原文查找译文，找不到时原样显示中文，validate 会列出这些未翻译的文案。
+// 语言的优先级：--lang 参数 → 环境变量 RUST_TUTORIAL_LANG → lang 命令保存的偏好 → 中文
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprCall, Lit, LitStr, Macro, Token};
+
+// 英文目录在编译时嵌入，运行时不依赖课程目录
+pub const EN_CATALOG: &str = include_str!("../locales/en.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lang {
+    Zh,
+    En,
+}
+
+impl Lang {
+    pub fn parse(value: &str) -> Result<Lang, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "zh" | "zh-cn" | "cn" => Ok(Lang::Zh),
+            "en" | "en-us" => Ok(Lang::En),
+            other => Err(t!("不支持的语言 '{}'，可选: zh | en", other)),
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Zh => "zh",
+            Lang::En => "en",
+        }
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+static CATALOG: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+// 程序启动时调用一次，之后的 tr / t! 都使用这个语言
+pub fn init(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+pub fn current() -> Lang {
+    *LANG.get().unwrap_or(&Lang::Zh)
+}
+
+pub fn is_en() -> bool {
+    current() == Lang::En
+}
+
+// 没有 --lang 时按环境变量和保存的偏好决定
+pub fn detect(root: &Path) -> Lang {
+    if let Some(lang) = env::var("RUST_TUTORIAL_LANG")
+        .ok()
+        .and_then(|value| Lang::parse(&value).ok())
+    {
+        return lang;
+    }
+    Settings::load(root).lang.unwrap_or(Lang::Zh)
+}
+
+pub fn catalog() -> &'static HashMap<String, String> {
+    CATALOG.get_or_init(|| parse_catalog(EN_CATALOG).unwrap_or_default())
+}
+
+pub fn parse_catalog(content: &str) -> Result<HashMap<String, String>, String> {
+    toml::from_str(content).map_err(|e| format!("locales/en.toml 格式错误: {}", e))
+}
+
+// 不带参数的文案
+pub fn tr(key: &'static str) -> &'static str {
+    if !is_en() {
+        return key;
+    }
+    catalog().get(key).map_or(key, String::as_str)
+}
+
+// t! 的实现：先翻译再填入参数
+pub fn translate(key: &'static str, args: &[&dyn Display]) -> String {
+    fill(tr(key), args)
+}
+
+// 占位符只支持 {} 和按位置的 {0}、{1}，{{ 和 }} 是花括号本身
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let mut index = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    index.push(c);
+                }
+                let position = if index.is_empty() {
+                    next += 1;
+                    next - 1
+                } else {
+                    index.parse().unwrap_or(usize::MAX)
+                };
+                if let Some(arg) = args.get(position) {
+                    output.push_str(&arg.to_string());
+                }
+            }
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+// 用法和 format! 一样，返回翻译后的 String
+macro_rules! t {
+    ($key:literal $(,)?) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:literal, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::translate($key, &[$(&$arg as &dyn ::std::fmt::Display),+])
+    };
+}
+pub(crate) use t;
+
+// 源码中 tr("...") 和 t!("...", ..) 用到的文案及所在行，validate 用它找出没有翻译的文案
+pub fn keys(source: &str) -> Result<Vec<(String, usize)>, String> {
+    let file = syn::parse_file(source).map_err(|e| t!("第 {} 行: {}", e.span().start().line, e))?;
+    let mut collector = KeyCollector { keys: Vec::new() };
+    collector.visit_file(&file);
+    Ok(collector.keys)
+}
+
+struct KeyCollector {
+    keys: Vec<(String, usize)>,
+}
+
+impl<'ast> Visit<'ast> for KeyCollector {
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        let is_tr = matches!(&*call.func, Expr::Path(path) if path.path.is_ident("tr"));
+        if let (true, Some(Expr::Lit(literal))) = (is_tr, call.args.first()) {
+            if let Lit::Str(key) = &literal.lit {
+                self.keys.push((key.value(), key.span().start().line));
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        if mac.path.is_ident("t") {
+            if let Ok(key) = mac.parse_body_with(|input: syn::parse::ParseStream| {
+                let key: LitStr = input.parse()?;
+                input.parse::<proc_macro2::TokenStream>()?;
+                Ok(key)
+            }) {
+                self.keys.push((key.value(), key.span().start().line));
+            }
+        }
+        // println!("{}", tr("...")) 这样写在宏参数里的调用
+        if let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+// 占位符的数量，译文必须和原文一致
+pub fn placeholders(template: &str) -> usize {
+    template.replace("{{", "").matches('{').count()
+}
+
+// 英文内容中是否还留有中文（标点也算）
+pub fn has_chinese(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c as u32, 0x3000..=0x303F | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xFF00..=0xFFEF)
+    })
+}
+
+// .tutorial/settings.json，目前只保存界面语言
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<Lang>,
+}
+
+impl Settings {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".tutorial").join("settings.json")
+    }
+
+    // 文件不存在或无法解析时使用默认设置
+    pub fn load(root: &Path) -> Settings {
+        fs::read_to_string(Settings::path(root))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), String> {
+        let path = Settings::path(root);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| t!("无法创建目录 {}: {}", dir.display(), e))?;
+        }
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| t!("无法序列化设置: {}", e))?;
+        fs::write(&path, content + "\n").map_err(|e| t!("无法写入 {}: {}", path.display(), e))
+    }
+}
+
+// lang 命令：不带参数时显示当前语言，带参数时保存偏好
+pub fn lang(root: &Path, value: Option<&str>) -> Result<(), String> {
+    let Some(value) = value else {
+        println!("{}", t!("🌐 当前界面语言: {}", current().code()));
+        println!(
+            "{}",
+            tr("   可选: zh（中文）/ en（English），例如 cargo run -- lang en")
+        );
+        return Ok(());
+    };
+
+    let lang = Lang::parse(value)?;
+    let mut settings = Settings::load(root);
+    settings.lang = Some(lang);
+    settings.save(root)?;
+    // 保存后的提示用新语言显示
+    let message = match lang {
+        Lang::Zh => "🌐 界面语言已设置为中文",
+        Lang::En => "🌐 Interface language set to English",
+    };
+    println!("{} ({})", message, Settings::path(root).display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_sequential_and_positional_placeholders() {
+        assert_eq!(
+            fill("{} 还有 {} 项未完成", &[&"functions", &3]),
+            "functions 还有 3 项未完成"
+        );
+        assert_eq!(
+            fill("{0}: missing templates/{0}/demo.rs", &[&"loops"]),
+            "loops: missing templates/loops/demo.rs"
+        );
+        assert_eq!(fill("{1} before {0}", &[&"a", &"b"]), "b before a");
+    }
+
+    #[test]
+    fn keeps_escaped_braces_and_skips_missing_arguments() {
+        assert_eq!(fill("fn {}() {{ }}", &[&"main"]), "fn main() { }");
+        assert_eq!(fill("{} and {}", &[&1]), "1 and ");
+        assert_eq!(fill("{x}", &[&1]), "");
+    }
+
+    #[test]
+    fn untranslated_language_returns_the_key() {
+        assert!(!is_en());
+        assert_eq!(translate("第 {} 行", &[&42]), "第 42 行");
+        assert_eq!(tr("完成"), "完成");
+    }
+
+    #[test]
+    fn catalog_translations_keep_placeholders() {
+        let catalog = parse_catalog(EN_CATALOG).expect("locales/en.toml 可以解析");
+        assert!(!catalog.is_empty());
+        for (key, value) in &catalog {
+            assert_eq!(placeholders(key), placeholders(value), "{}", key);
+        }
+    }
+
+    #[test]
+    fn collects_keys_from_tr_calls_and_t_macros() {
+        let source = r#"
+fn main() {
+    println!("{}", tr("你好"));
+    let message = t!("第 {} 行", 3);
+    let plain = "不是文案";
+}
+"#;
+        let keys = keys(source).unwrap();
+        assert_eq!(
+            keys,
+            vec![("你好".to_string(), 3), ("第 {} 行".to_string(), 4)]
+        );
+    }
+
+    #[test]
+    fn detects_chinese_text_and_punctuation() {
+        assert!(has_chinese("练习"));
+        assert!(has_chinese("done，next"));
+        assert!(!has_chinese("Exercise 1: Basic variables"));
+    }
+}
@@ -0,0 +1,752 @@
+// TypeScript 习惯检查
+// 从 TypeScript 转过来的代码常常能编译、也能通过测试，但写法不像 Rust：
+// &String 参数、用 .clone() 绕过借用检查、按下标遍历、末尾的 return、== true、到处 unwrap()。
+// 这里用 syn 遍历 demo.rs 找出这些写法并给出改写建议，只作为风格建议，不影响练习是否完成
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{
+    BinOp, Block, Expr, ExprBinary, ExprClosure, ExprForLoop, ExprMethodCall, ExprRange, FnArg,
+    Ident, ImplItemFn, ItemFn, ItemMod, Lit, Macro, Pat, ReturnType, Signature, Stmt, Token, Type,
+};
+
+use crate::checker::Task;
+use crate::i18n::{t, tr};
+use crate::signatures;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rule {
+    StringRef,
+    Clone,
+    IndexLoop,
+    TrailingReturn,
+    BoolCompare,
+    Unwrap,
+}
+
+impl Rule {
+    // 报告中使用的规则名
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::StringRef => "string_ref",
+            Rule::Clone => "clone",
+            Rule::IndexLoop => "index_loop",
+            Rule::TrailingReturn => "trailing_return",
+            Rule::BoolCompare => "bool_compare",
+            Rule::Unwrap => "unwrap",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Rule::StringRef => tr("参数类型用 &str 代替 &String"),
+            Rule::Clone => tr("用借用代替 .clone()"),
+            Rule::IndexLoop => tr("直接遍历集合，不用下标"),
+            Rule::TrailingReturn => tr("函数末尾不需要 return"),
+            Rule::BoolCompare => tr("布尔值不需要和 true / false 比较"),
+            Rule::Unwrap => tr("避免随手 unwrap()"),
+        }
+    }
+
+    // clippy 中对应的 lint，两边都报告时 check 只显示这里的建议
+    pub fn clippy_lint(self) -> Option<&'static str> {
+        match self {
+            Rule::StringRef => Some("clippy::ptr_arg"),
+            Rule::Clone => Some("clippy::redundant_clone"),
+            Rule::IndexLoop => Some("clippy::needless_range_loop"),
+            Rule::TrailingReturn => Some("clippy::needless_return"),
+            Rule::BoolCompare => Some("clippy::bool_comparison"),
+            Rule::Unwrap => None,
+        }
+    }
+
+    // 对应的 TypeScript 习惯，以及为什么 Rust 里不这么写
+    pub fn reason(self) -> &'static str {
+        match self {
+            Rule::StringRef => {
+                tr("TS 里只有一种 string；Rust 中 &str 既能接收 &String 也能接收字面量，&String 只会限制调用方")
+            }
+            Rule::Clone => {
+                tr("TS 里赋值只复制引用；Rust 的 .clone() 会复制整份数据，只需要读取时传引用即可")
+            }
+            Rule::IndexLoop => {
+                tr("TS 里常写 for (let i = 0; i < arr.length; i++)；Rust 的迭代器没有越界检查的开销，也不会写错边界")
+            }
+            Rule::TrailingReturn => {
+                tr("TS 函数必须写 return；Rust 中块的最后一个表达式（不加分号）就是返回值，return 只用于提前返回")
+            }
+            Rule::BoolCompare => tr("TS 里 == true 有时是为了排除 truthy 值；Rust 的 bool 不会隐式转换，直接用即可"),
+            Rule::Unwrap => {
+                tr("TS 里忽略 undefined 最多得到运行时错误；unwrap() 遇到 None / Err 会让整个程序 panic，应该处理或向上传递错误")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: Rule,
+    pub line: usize,
+    pub before: String,
+    pub after: String,
+}
+
+pub fn lint(source: &str) -> Result<Vec<Finding>, String> {
+    let file = syn::parse_file(source).map_err(|e| t!("第 {} 行: {}", e.span().start().line, e))?;
+    let mut linter = Linter {
+        source,
+        findings: Vec::new(),
+        fallible: Vec::new(),
+    };
+    linter.visit_file(&file);
+
+    let mut findings = linter.findings;
+    findings.sort_by_key(|finding| finding.line);
+    Ok(findings)
+}
+
+struct Linter<'a> {
+    source: &'a str,
+    findings: Vec<Finding>,
+    // 当前所在的函数（或闭包）能否用 ? 传递错误，栈顶为最内层
+    fallible: Vec<bool>,
+}
+
+impl Linter<'_> {
+    fn text(&self, node: &dyn Spanned) -> String {
+        signatures::source_text(self.source, node.span())
+    }
+
+    fn push(&mut self, rule: Rule, span: Span, before: String, after: String) {
+        self.findings.push(Finding {
+            rule,
+            line: span.start().line,
+            before,
+            after,
+        });
+    }
+
+    fn check_function(&mut self, sig: &Signature, block: &Block) {
+        let string_refs = sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(arg) => is_string_ref(&arg.ty),
+            FnArg::Receiver(_) => false,
+        });
+        if string_refs {
+            let before = self.text(sig);
+            let after = before.replace("&String", "&str");
+            self.push(Rule::StringRef, sig.span(), before, after);
+        }
+
+        if let Some(Stmt::Expr(Expr::Return(ret), _)) = block.stmts.last() {
+            if let Some(value) = &ret.expr {
+                let before = self.text(ret);
+                let after = self.text(value);
+                self.push(
+                    Rule::TrailingReturn,
+                    ret.span(),
+                    format!("{};", before),
+                    after,
+                );
+            }
+        }
+    }
+
+    // 多余的 .clone() 要看原值之后还用不用，所以按块检查语句：
+    // f(x.clone()) 之后 x 不再出现，可以直接传 x；
+    // let y = x.clone(); 之后 x 不再出现且 y 只被读取，可以借用 &x。
+    // clippy::redundant_clone 只在装了 clippy 时才有，也认不出第二种写法
+    fn check_clones(&mut self, stmts: &[Stmt]) {
+        for (index, stmt) in stmts.iter().enumerate() {
+            let rest = &stmts[index + 1..];
+            if let Some((name, original)) = cloned_binding(stmt) {
+                if !mentions(rest, original) && only_read(rest, name) {
+                    self.push(
+                        Rule::Clone,
+                        stmt.span(),
+                        format!("let {} = {}.clone();", name, original),
+                        format!("let {} = &{};", name, original),
+                    );
+                }
+                continue;
+            }
+
+            let expr = match stmt {
+                Stmt::Expr(expr, _) => expr,
+                Stmt::Local(local) => match &local.init {
+                    Some(init) => &*init.expr,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let args = match expr {
+                Expr::Call(call) => &call.args,
+                Expr::MethodCall(call) => &call.args,
+                _ => continue,
+            };
+            for arg in args {
+                let Some(original) = cloned_ident(arg) else {
+                    continue;
+                };
+                // 只处理本块里 let 出来的值（不是引用），clone 出现在循环或闭包里时移动会出错
+                if owned_local(&stmts[..index], original)
+                    && count(stmt.to_token_stream(), original) == 1
+                    && !mentions(rest, original)
+                {
+                    let before = self.text(expr);
+                    let after = before.replacen(&self.text(arg), &original.to_string(), 1);
+                    self.push(Rule::Clone, arg.span(), before, after);
+                }
+            }
+        }
+    }
+
+    fn enter_function(&mut self, sig: &Signature) {
+        let fallible = match &sig.output {
+            ReturnType::Type(_, ty) => returns_result_or_option(ty),
+            ReturnType::Default => false,
+        };
+        self.fallible.push(fallible);
+    }
+}
+
+impl<'ast> Visit<'ast> for Linter<'_> {
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        // 测试模块里的 unwrap() 是正常写法
+        let is_test = module.attrs.iter().any(|attr| {
+            attr.path().is_ident("cfg") && attr.meta.to_token_stream().to_string().contains("test")
+        });
+        if !is_test {
+            visit::visit_item_mod(self, module);
+        }
+    }
+
+    fn visit_item_fn(&mut self, function: &'ast ItemFn) {
+        self.check_function(&function.sig, &function.block);
+        self.enter_function(&function.sig);
+        visit::visit_item_fn(self, function);
+        self.fallible.pop();
+    }
+
+    fn visit_impl_item_fn(&mut self, method: &'ast ImplItemFn) {
+        self.check_function(&method.sig, &method.block);
+        self.enter_function(&method.sig);
+        visit::visit_impl_item_fn(self, method);
+        self.fallible.pop();
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        // 闭包里的 ? 作用于闭包本身，不能传递到外层函数
+        let fallible = match &closure.output {
+            ReturnType::Type(_, ty) => returns_result_or_option(ty),
+            ReturnType::Default => false,
+        };
+        self.fallible.push(fallible);
+        visit::visit_expr_closure(self, closure);
+        self.fallible.pop();
+    }
+
+    fn visit_block(&mut self, block: &'ast Block) {
+        self.check_clones(&block.stmts);
+        visit::visit_block(self, block);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if call.args.is_empty() {
+            let receiver = self.text(&call.receiver);
+            if call.method == "unwrap" {
+                let after = if self.fallible.last().copied().unwrap_or(false) {
+                    format!("{}?", receiver)
+                } else {
+                    t!(
+                        "{}.expect(\"说明为什么这里不会失败\")  // 或者用 match / if let 处理",
+                        receiver
+                    )
+                };
+                self.push(
+                    Rule::Unwrap,
+                    call.method.span(),
+                    format!("{}.unwrap()", receiver),
+                    after,
+                );
+            }
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_for_loop(&mut self, for_loop: &'ast ExprForLoop) {
+        if let Expr::Range(range) = &*for_loop.expr {
+            if let Some(collection) = indexed_collection(range) {
+                let pattern = self.text(&for_loop.pat);
+                let collection = self.text(collection);
+                self.push(
+                    Rule::IndexLoop,
+                    for_loop.span(),
+                    format!("for {} in {} {{", pattern, self.text(range)),
+                    t!(
+                        "for item in &{} {{  // 需要下标时: for ({}, item) in {}.iter().enumerate()",
+                        collection, pattern, collection
+                    ),
+                );
+            }
+        }
+        visit::visit_expr_for_loop(self, for_loop);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        let negate = match binary.op {
+            BinOp::Eq(_) => Some(false),
+            BinOp::Ne(_) => Some(true),
+            _ => None,
+        };
+        if let Some(negate) = negate {
+            let operands = match (bool_literal(&binary.left), bool_literal(&binary.right)) {
+                (None, Some(value)) => Some((&binary.left, value)),
+                (Some(value), None) => Some((&binary.right, value)),
+                _ => None,
+            };
+            if let Some((operand, value)) = operands {
+                let operand = self.text(operand);
+                // x == false 与 x != true 都等价于 !x
+                let after = if value != negate {
+                    operand
+                } else {
+                    format!("!{}", operand)
+                };
+                self.push(Rule::BoolCompare, binary.span(), self.text(binary), after);
+            }
+        }
+        visit::visit_expr_binary(self, binary);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        // println!、format!、assert! 等宏的参数也是普通表达式
+        if let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+// x.clone() 中的 x
+fn cloned_ident(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::MethodCall(call) if call.method == "clone" && call.args.is_empty() => {
+            path_ident(&call.receiver)
+        }
+        _ => None,
+    }
+}
+
+fn path_ident(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Path(path) if path.qself.is_none() => path.path.get_ident(),
+        _ => None,
+    }
+}
+
+fn binding(pat: &Pat) -> Option<&Ident> {
+    match pat {
+        Pat::Ident(ident) if ident.by_ref.is_none() && ident.subpat.is_none() => Some(&ident.ident),
+        Pat::Type(typed) => binding(&typed.pat),
+        _ => None,
+    }
+}
+
+// let y = x.clone(); 中的 (y, x)，let mut 说明本来就打算修改副本
+fn cloned_binding(stmt: &Stmt) -> Option<(&Ident, &Ident)> {
+    let Stmt::Local(local) = stmt else {
+        return None;
+    };
+    let name = match &local.pat {
+        Pat::Ident(ident) if ident.mutability.is_some() => return None,
+        pat => binding(pat)?,
+    };
+    let original = cloned_ident(&local.init.as_ref()?.expr)?;
+    Some((name, original))
+}
+
+// 最近一次绑定 name 的 let 语句，初始值不是引用
+fn owned_local(stmts: &[Stmt], name: &Ident) -> bool {
+    stmts
+        .iter()
+        .rev()
+        .find_map(|stmt| match stmt {
+            Stmt::Local(local) if binding(&local.pat) == Some(name) => Some(local),
+            _ => None,
+        })
+        .and_then(|local| local.init.as_ref())
+        .is_some_and(|init| !matches!(&*init.expr, Expr::Reference(_)))
+}
+
+fn mentions(stmts: &[Stmt], name: &Ident) -> bool {
+    stmts
+        .iter()
+        .any(|stmt| count(stmt.to_token_stream(), name) > 0)
+}
+
+// 按 token 计数，宏的参数也算在内
+fn count(tokens: TokenStream, name: &Ident) -> usize {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => usize::from(ident == *name),
+            TokenTree::Group(group) => count(group.stream(), name),
+            _ => 0,
+        })
+        .sum()
+}
+
+// 只接收 &self 的常用方法
+const READ_METHODS: &[&str] = &[
+    "len",
+    "is_empty",
+    "iter",
+    "contains",
+    "contains_key",
+    "get",
+    "first",
+    "last",
+    "starts_with",
+    "ends_with",
+    "chars",
+    "bytes",
+    "lines",
+    "split",
+    "trim",
+    "as_str",
+    "to_string",
+    "to_uppercase",
+    "to_lowercase",
+    "keys",
+    "values",
+];
+
+// 格式化宏的参数按引用使用
+const FORMAT_MACROS: &[&str] = &[
+    "println",
+    "print",
+    "eprintln",
+    "eprint",
+    "format",
+    "write",
+    "writeln",
+    "panic",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+];
+
+// name 在 stmts 中至少出现一次，而且每次都只是读取：&name、只读方法、比较、格式化宏的参数
+fn only_read(stmts: &[Stmt], name: &Ident) -> bool {
+    let mut uses = Uses {
+        name,
+        reads: 0,
+        other: false,
+    };
+    for stmt in stmts {
+        uses.visit_stmt(stmt);
+    }
+    uses.reads > 0 && !uses.other
+}
+
+struct Uses<'a> {
+    name: &'a Ident,
+    reads: usize,
+    other: bool,
+}
+
+impl Uses<'_> {
+    fn is_name(&self, expr: &Expr) -> bool {
+        path_ident(expr) == Some(self.name)
+    }
+}
+
+impl<'ast> Visit<'ast> for Uses<'_> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::Path(_) if self.is_name(expr) => self.other = true,
+            Expr::Reference(reference)
+                if reference.mutability.is_none() && self.is_name(&reference.expr) =>
+            {
+                self.reads += 1;
+            }
+            Expr::MethodCall(call)
+                if self.is_name(&call.receiver)
+                    && READ_METHODS.iter().any(|method| call.method == method) =>
+            {
+                self.reads += 1;
+                for arg in &call.args {
+                    self.visit_expr(arg);
+                }
+            }
+            Expr::Index(index) if self.is_name(&index.expr) => {
+                self.reads += 1;
+                self.visit_expr(&index.index);
+            }
+            Expr::Binary(binary)
+                if matches!(
+                    binary.op,
+                    BinOp::Eq(_)
+                        | BinOp::Ne(_)
+                        | BinOp::Lt(_)
+                        | BinOp::Le(_)
+                        | BinOp::Gt(_)
+                        | BinOp::Ge(_)
+                ) =>
+            {
+                for operand in [&binary.left, &binary.right] {
+                    if self.is_name(operand) {
+                        self.reads += 1;
+                    } else {
+                        self.visit_expr(operand);
+                    }
+                }
+            }
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let format = FORMAT_MACROS.iter().any(|name| mac.path.is_ident(name));
+        match mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            Ok(args) => {
+                for arg in &args {
+                    if format && self.is_name(arg) {
+                        self.reads += 1;
+                    } else {
+                        self.visit_expr(arg);
+                    }
+                }
+            }
+            // 解析不了的宏只能按 token 判断
+            Err(_) => self.other |= count(mac.tokens.clone(), self.name) > 0,
+        }
+    }
+}
+
+fn is_string_ref(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_none() => {
+            matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("String"))
+        }
+        _ => false,
+    }
+}
+
+fn returns_result_or_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Result" || segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+// 0..v.len() 中的 v
+fn indexed_collection(range: &ExprRange) -> Option<&Expr> {
+    let starts_at_zero = match range.start.as_deref() {
+        Some(Expr::Lit(literal)) => literal.lit.to_token_stream().to_string() == "0",
+        _ => false,
+    };
+    match range.end.as_deref() {
+        Some(Expr::MethodCall(call))
+            if starts_at_zero
+                && call.method == "len"
+                && call.args.is_empty()
+                && matches!(range.limits, syn::RangeLimits::HalfOpen(_)) =>
+        {
+            Some(&call.receiver)
+        }
+        _ => None,
+    }
+}
+
+fn bool_literal(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Bool(value) => Some(value.value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// 与 check 的完成度报告分开显示，按练习分组
+pub fn print(findings: &[Finding], tasks: &[Task]) {
+    if findings.is_empty() {
+        return;
+    }
+    println!();
+    println!(
+        "{}",
+        t!(
+            "🧹 TypeScript 习惯（{} 条风格建议，不影响完成度）:",
+            findings.len()
+        )
+    );
+    for finding in findings {
+        let task = tasks.iter().find(|task| {
+            task.file.is_none() && task.start_line <= finding.line && finding.line <= task.end_line
+        });
+        let location = match task {
+            Some(task) => t!("第 {} 行，{}", finding.line, task.title),
+            None => t!("第 {} 行", finding.line),
+        };
+        println!("{}", t!("  💡 {}（{}）", finding.rule.title(), location));
+        println!("{}", t!("      之前: {}", finding.before));
+        println!("{}", t!("      之后: {}", finding.after));
+    }
+
+    // 每条规则的原因只说一次
+    let mut explained = Vec::new();
+    for finding in findings {
+        if !explained.contains(&finding.rule) {
+            explained.push(finding.rule);
+        }
+    }
+    println!();
+    for rule in explained {
+        println!("  📘 {}: {}", rule.title(), rule.reason());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(source: &str) -> Vec<(Rule, usize)> {
+        lint(source)
+            .unwrap()
+            .into_iter()
+            .map(|finding| (finding.rule, finding.line))
+            .collect()
+    }
+
+    #[test]
+    fn flags_typescript_habits() {
+        let source = r#"
+fn length(s: &String) -> usize {
+    return s.len();
+}
+
+fn total(values: &Vec<i32>) -> i32 {
+    let mut sum = 0;
+    for i in 0..values.len() {
+        sum += values[i];
+    }
+    sum
+}
+
+fn check(flag: bool) -> bool {
+    flag == true
+}
+"#;
+        assert_eq!(
+            rules(source),
+            [
+                (Rule::StringRef, 2),
+                (Rule::TrailingReturn, 3),
+                (Rule::IndexLoop, 8),
+                (Rule::BoolCompare, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn unwrap_suggests_question_mark_only_in_fallible_functions() {
+        let source = r#"
+fn parse(text: &str) -> Result<i32, std::num::ParseIntError> {
+    let value = text.parse::<i32>().unwrap();
+    Ok(value)
+}
+
+fn main() {
+    let value = "1".parse::<i32>().unwrap();
+}
+"#;
+        let findings = lint(source).unwrap();
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].after, "text.parse::<i32>()?");
+        assert!(findings[1].after.contains(".expect("));
+    }
+
+    #[test]
+    fn flags_clones_that_only_silence_the_borrow_checker() {
+        let source = r#"
+fn greet(name: String) {
+    println!("hello {}", name);
+}
+
+fn main() {
+    let name = String::from("Ferris");
+    greet(name.clone());
+
+    let words = vec![String::from("a")];
+    let copy = words.clone();
+    println!("{} {:?}", copy.len(), copy);
+}
+"#;
+        let findings = lint(source).unwrap();
+        let clones: Vec<_> = findings
+            .iter()
+            .filter(|finding| finding.rule == Rule::Clone)
+            .map(|finding| {
+                (
+                    finding.line,
+                    finding.before.as_str(),
+                    finding.after.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            clones,
+            [
+                (8, "greet(name.clone())", "greet(name)"),
+                (11, "let copy = words.clone();", "let copy = &words;"),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_clones_whose_original_is_still_used() {
+        // 所有权练习里特意用 clone 让两个值都能使用
+        let source = r#"
+fn take(v: Vec<i32>) {}
+
+fn main() {
+    let s4 = String::from("clone me");
+    let s5 = s4.clone();
+    println!("s4: {}, s5: {}", s4, s5);
+
+    let numbers = vec![1, 2, 3];
+    take(numbers.clone());
+    println!("{:?}", numbers);
+
+    let owned = numbers.clone();
+    for n in owned {
+        println!("{}", n);
+    }
+}
+"#;
+        assert!(rules(source).is_empty());
+    }
+
+    #[test]
+    fn skips_test_modules() {
+        let source = r#"
+#[cfg(test)]
+mod tests {
+    fn helper() -> i32 {
+        "1".parse::<i32>().unwrap()
+    }
+}
+"#;
+        assert!(rules(source).is_empty());
+    }
+}
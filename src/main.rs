@@ -1,57 +1,201 @@
 // Rust Tutorial for TypeScript Developers
-// 主入口文件 - 项目介绍和使用指南
+// 主入口文件 - 课程命令行工具
+
+mod answer;
+mod checker;
+mod cli;
+mod clippy;
+mod commands;
+mod compare;
+mod compile_fail;
+mod course;
+mod coverage;
+mod diagnostics;
+mod diff;
+mod explain;
+mod harness;
+mod highlight;
+mod hint;
+mod i18n;
+mod lint;
+mod markdown;
+mod progress;
+mod report;
+mod reset;
+mod runner;
+mod serve;
+mod signatures;
+mod site;
+mod solution;
+mod testsuite;
+mod ui;
+mod validate;
+mod verify;
+mod watch;
+
+use std::env;
+use std::process;
+
+use cli::Command;
+use course::Course;
+use i18n::{t, tr};
 
 fn main() {
-    println!("🦀 欢迎来到 Rust Tutorial for TypeScript Developers! ✨");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let lang = match cli::take_lang(&mut args) {
+        Ok(lang) => lang,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            process::exit(2);
+        }
+    };
+    i18n::init(lang.unwrap_or_else(|| i18n::detect(&course::root())));
+
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            eprintln!();
+            cli::print_usage();
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = execute(command) {
+        eprintln!("{}", t!("❌ 错误: {}", e));
+        process::exit(1);
+    }
+}
+
+fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Intro => {
+            let course = Course::discover()?;
+            print_intro(&course);
+            Ok(())
+        }
+        Command::Help => {
+            cli::print_usage();
+            Ok(())
+        }
+        Command::List => commands::list(&Course::discover()?),
+        Command::Show {
+            module,
+            file,
+            section,
+        } => commands::show(&Course::discover()?, &module, file, section),
+        Command::Run { module } => commands::run(&Course::discover()?, &module),
+        Command::Compare { module, name } => {
+            compare::compare(&Course::discover()?, &module, name.as_deref())
+        }
+        Command::Serve { port } => serve::serve(&Course::discover()?, port),
+        Command::BuildSite { out } => site::build_site(&Course::discover()?, out.as_deref()),
+        Command::Check { module, report } => {
+            commands::check_modules(&Course::discover()?, module.as_deref(), report)
+        }
+        Command::Progress { module } => {
+            commands::progress(&Course::discover()?, module.as_deref())
+        }
+        Command::Watch { module } => watch::watch(&Course::discover()?, module.as_deref()),
+        Command::Solution { module, diff, name } => {
+            solution::solution(&Course::discover()?, &module, diff, name.as_deref())
+        }
+        Command::Hint { module, exercise } => {
+            hint::hint(&Course::discover()?, &module, exercise.as_deref())
+        }
+        Command::Explain { code } => explain::explain(code.as_deref()),
+        Command::Reset { module } => reset::reset(&Course::discover()?, &module),
+        Command::Restore { module, backup } => {
+            reset::restore(&Course::discover()?, &module, backup.as_deref())
+        }
+        Command::Coverage { module, table } => {
+            coverage::coverage(&Course::discover()?, module.as_deref(), table)
+        }
+        Command::Lang { lang } => i18n::lang(&course::root(), lang.as_deref()),
+        Command::Validate { fix } => validate::validate(&Course::discover()?, fix),
+        Command::Verify {
+            module,
+            update,
+            strict_floats,
+            strict_whitespace,
+        } => verify::verify(
+            &Course::discover()?,
+            &module,
+            update,
+            verify::Tolerance {
+                floats: !strict_floats,
+                whitespace: !strict_whitespace,
+            },
+        ),
+    }
+}
+
+// 不带参数运行时显示项目介绍
+fn print_intro(course: &Course) {
+    println!("{}", tr("🦀 欢迎来到 Rust Tutorial for TypeScript Developers! ✨"));
     println!();
-    println!("这个教程专为熟悉 TypeScript 的开发者设计，");
-    println!("帮助你通过对比学习快速掌握 Rust 语言。");
+    println!("{}", tr("这个教程专为熟悉 TypeScript 的开发者设计，"));
+    println!("{}", tr("帮助你通过对比学习快速掌握 Rust 语言。"));
     println!();
     
-    println!("📚 基础课程模块:");
-    println!("  1. variables-constants  - 变量和常量");
-    println!("  2. data-types          - 数据类型");
-    println!("  3. ownership-borrowing - 所有权和借用 ⭐ Rust 核心");
-    println!("  4. functions           - 函数");
-    println!("  5. pattern-matching    - 模式匹配 ⭐ Rust 特色");
-    println!("  6. error-handling      - 错误处理");
-    println!("  7. loops-iterators     - 循环和迭代器");
+    println!("{}", tr("📚 基础课程模块:"));
+    for (index, lesson) in course.lessons.iter().enumerate() {
+        println!("  {}. {:<20} - {}", index + 1, lesson.id, lesson.label());
+    }
     println!();
     
-    println!("🚀 如何使用:");
-    println!("  1. 进入任意模块目录：cd basic/variables-constants");
-    println!("  2. 阅读 TypeScript 示例：查看 example.ts");
-    println!("  3. 完成 Rust 练习：编辑 demo.rs");
-    println!("  4. 运行代码测试：rustc demo.rs && ./demo");
-    println!("  5. 查看完整答案：阅读 answer.md");
+    println!("{}", tr("🚀 如何使用:"));
+    println!("{}", tr("  1. 进入任意模块目录：cd basic/variables-constants"));
+    println!("{}", tr("  2. 阅读 TypeScript 示例：查看 example.ts"));
+    println!("{}", tr("  3. 完成 Rust 练习：编辑 demo.rs"));
+    println!("{}", tr("  4. 运行代码测试：cargo run -- run variables-constants"));
+    println!("{}", tr("  5. 查看完整答案：阅读 answer.md"));
     println!();
     
-    println!("💡 或者使用 Cargo 运行示例:");
-    println!("  cargo run --example variables_constants");
-    println!("  cargo run --example data_types");
-    println!("  cargo run --example ownership_borrowing");
-    println!("  cargo run --example functions");
-    println!("  cargo run --example pattern_matching");
-    println!("  cargo run --example error_handling");
-    println!("  cargo run --example loops_iterators");
+    println!("{}", tr("💡 或者使用 Cargo 运行示例:"));
+    for lesson in &course.lessons {
+        println!("  cargo run --example {}", lesson.example_name());
+    }
+    println!();
+
+    println!("{}", tr("🧭 课程命令:"));
+    println!("{}", tr("  cargo run -- list              列出所有模块"));
+    println!("{}", tr("  cargo run -- show <module>     查看模块答案"));
+    println!("{}", tr("  cargo run -- run <module>      编译并运行 demo.rs"));
+    println!("{}", tr("  cargo run -- check <module>    检查练习完成情况"));
+    println!("{}", tr("  cargo run -- watch             边写边检查，自动进入下一课"));
+    println!("{}", tr("  cargo run -- hint <module>     获取下一个练习的提示"));
+    println!("{}", tr("  cargo run -- solution <module> 查看参考答案"));
+    println!("{}", tr("  cargo run -- verify <module>   对比运行输出与参考答案"));
+    println!("{}", tr("  cargo run -- reset <module>    重新开始（自动备份当前代码）"));
+    println!("{}", tr("  cargo run -- explain E0382     用 TypeScript 对比讲解编译错误"));
+    println!("{}", tr("  cargo run -- progress          查看学习进度"));
+    println!("{}", tr("  cargo run -- validate          校验 course.toml 与课程目录是否一致"));
+    println!("{}", tr("  cargo run -- coverage          查看 TypeScript 示例与 Rust 练习的对应关系"));
+    println!("{}", tr("  cargo run -- compare <module>  并排对照 TypeScript 示例和 Rust 参考答案"));
+    println!("{}", tr("  cargo run -- serve             在浏览器中编辑、运行和检查练习"));
+    println!("{}", tr("  cargo run -- build-site        生成可以离线浏览的课程网站"));
+    println!("{}", tr("  cargo run -- lang en           切换到英文界面（所有命令也可以加 --lang en，只对这一次生效）"));
+    println!("{}", tr("  cargo run -- help              查看全部命令"));
     println!();
     
-    println!("🎯 学习建议:");
-    println!("  • 按顺序完成每个模块");
-    println!("  • 动手编写代码，不要只看");
-    println!("  • 理解 Rust 编译器的错误信息");
-    println!("  • 对比 TypeScript 和 Rust 的设计哲学");
+    println!("{}", tr("🎯 学习建议:"));
+    println!("{}", tr("  • 按顺序完成每个模块"));
+    println!("{}", tr("  • 动手编写代码，不要只看"));
+    println!("{}", tr("  • 理解 Rust 编译器的错误信息"));
+    println!("{}", tr("  • 对比 TypeScript 和 Rust 的设计哲学"));
     println!();
     
-    println!("📖 重要概念:");
-    println!("  • 所有权系统 - Rust 的核心特性");
-    println!("  • 借用检查器 - 内存安全保证");
-    println!("  • 模式匹配 - 强大的控制流");
-    println!("  • 错误处理 - Result 和 Option 类型");
-    println!("  • 零成本抽象 - 性能与安全并重");
+    println!("{}", tr("📖 重要概念:"));
+    println!("{}", tr("  • 所有权系统 - Rust 的核心特性"));
+    println!("{}", tr("  • 借用检查器 - 内存安全保证"));
+    println!("{}", tr("  • 模式匹配 - 强大的控制流"));
+    println!("{}", tr("  • 错误处理 - Result 和 Option 类型"));
+    println!("{}", tr("  • 零成本抽象 - 性能与安全并重"));
     println!();
     
-    println!("开始你的 Rust 学习之旅吧！🎉");
+    println!("{}", tr("开始你的 Rust 学习之旅吧！🎉"));
     
     // 显示一个简单的 Rust 代码示例
     demonstrate_rust_features();
@@ -59,39 +203,39 @@ fn main() {
 
 fn demonstrate_rust_features() {
     println!();
-    println!("🔍 Rust 特性预览:");
+    println!("{}", tr("🔍 Rust 特性预览:"));
     
     // 所有权系统
     let owned_string = String::from("Hello, Rust!");
     let borrowed_string = &owned_string;
-    println!("  所有权: {} (借用: {})", owned_string, borrowed_string);
+    println!("{}", t!("  所有权: {} (借用: {})", owned_string, borrowed_string));
     
     // 模式匹配
     let number = 42;
     let result = match number {
-        0 => "零",
-        1..=10 => "小数",
-        11..=100 => "中数",
-        _ => "大数",
+        0 => tr("零"),
+        1..=10 => tr("小数"),
+        11..=100 => tr("中数"),
+        _ => tr("大数"),
     };
-    println!("  模式匹配: {} 是 {}", number, result);
+    println!("{}", t!("  模式匹配: {} 是 {}", number, result));
     
     // 错误处理
     let division = safe_divide(10.0, 2.0);
     match division {
-        Ok(result) => println!("  错误处理: 10 ÷ 2 = {}", result),
-        Err(e) => println!("  错误处理: {}", e),
+        Ok(result) => println!("{}", t!("  错误处理: 10 ÷ 2 = {}", result)),
+        Err(e) => println!("{}", t!("  错误处理: {}", e)),
     }
     
     // 迭代器
     let numbers: Vec<i32> = (1..=5).collect();
     let doubled: Vec<i32> = numbers.iter().map(|x| x * 2).collect();
-    println!("  迭代器: {:?} → {:?}", numbers, doubled);
+    println!("{}", t!("  迭代器: {} → {}", format!("{:?}", numbers), format!("{:?}", doubled)));
 }
 
 fn safe_divide(a: f64, b: f64) -> Result<f64, String> {
     if b == 0.0 {
-        Err("不能除以零".to_string())
+        Err(tr("不能除以零").to_string())
     } else {
         Ok(a / b)
     }
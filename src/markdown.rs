@@ -0,0 +1,649 @@
+// 渲染课程的 markdown（answer.md / answer.en.md），输出到终端（show）或 HTML（build-site）
+// 支持课程中用到的语法：标题、段落、列表、引用、分隔线、表格、代码块和行内的 **粗体**、`代码`、[链接](url)。
+// 终端中宽度按显示列数计算（中文和 emoji 占两列），段落按终端宽度折行，代码块不折行。
+// 二级标题（##）是"小节"，按顺序编号为 § 1、§ 2 ...，show --section N 和网站中的锚点都用这个编号
+
+use crate::highlight::{Highlighter, Kind, Language};
+use crate::ui;
+
+pub struct Rendered {
+    pub lines: Vec<String>,
+    // 每个小节在 lines 中的起始行
+    pub sections: Vec<usize>,
+}
+
+// 一段带样式的文字；style 是 ANSI 颜色代码，空字符串表示不着色
+type Span = (&'static str, String);
+
+const HEADING: &str = "1;35";
+const SECTION: &str = "1;36";
+const SUBHEADING: &str = "1;33";
+const BOLD: &str = "1";
+const CODE: &str = "33";
+const LINK: &str = "4;34";
+const FRAME: &str = "90";
+
+// 二级标题的文字，代码块中的 ## 不算
+pub fn sections(content: &str) -> Vec<String> {
+    let mut fenced = false;
+    let mut titles = Vec::new();
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            fenced = !fenced;
+        } else if let (false, Some((2, title))) = (fenced, heading(line.trim())) {
+            titles.push(plain(&inline(title, false)));
+        }
+    }
+    titles
+}
+
+// 按行切分出的块
+enum Block<'a> {
+    Blank,
+    Heading(usize, &'a str),
+    Rule,
+    Quote(&'a str),
+    // 缩进、有序列表的序号、内容
+    Item(usize, Option<&'a str>, &'a str),
+    Paragraph(&'a str),
+    Code(&'a str, Vec<&'a str>),
+    Table(Vec<&'a str>),
+}
+
+fn blocks(content: &str) -> Vec<Block<'_>> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if let Some(tag) = trimmed.strip_prefix("```") {
+            let end = (i + 1..lines.len())
+                .find(|&j| lines[j].trim().starts_with("```"))
+                .unwrap_or(lines.len());
+            blocks.push(Block::Code(tag.trim(), lines[i + 1..end].to_vec()));
+            i = end + 1;
+            continue;
+        }
+        if trimmed.starts_with('|') {
+            let end = (i..lines.len())
+                .find(|&j| !lines[j].trim().starts_with('|'))
+                .unwrap_or(lines.len());
+            blocks.push(Block::Table(lines[i..end].to_vec()));
+            i = end;
+            continue;
+        }
+
+        blocks.push(if trimmed.is_empty() {
+            Block::Blank
+        } else if let Some((level, title)) = heading(trimmed) {
+            Block::Heading(level, title)
+        } else if is_rule(trimmed) {
+            Block::Rule
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            Block::Quote(quote.trim_start())
+        } else if let Some((indent, number, text)) = list_item(line) {
+            Block::Item(indent, number, text)
+        } else {
+            Block::Paragraph(trimmed)
+        });
+        i += 1;
+    }
+    blocks
+}
+
+pub fn render(content: &str, width: usize, color: bool) -> Rendered {
+    let mut renderer = Renderer {
+        width: width.max(20),
+        color,
+        lines: Vec::new(),
+        sections: Vec::new(),
+    };
+
+    for block in blocks(content) {
+        match block {
+            Block::Blank => renderer.blank(),
+            Block::Heading(level, title) => renderer.heading(level, title),
+            Block::Rule => {
+                let rule = "─".repeat(renderer.width.min(60));
+                renderer.push(ui::paint(FRAME, &rule, color));
+            }
+            Block::Quote(text) => {
+                let prefix = ui::paint(FRAME, "│ ", color);
+                renderer.paragraph(text, &prefix, "  ");
+            }
+            Block::Item(indent, number, text) => {
+                let marker = match number {
+                    Some(number) => format!("{}  {}. ", " ".repeat(indent), number),
+                    None => format!("{}  • ", " ".repeat(indent)),
+                };
+                let hanging = " ".repeat(ui::display_width(&marker));
+                renderer.paragraph(text, &marker, &hanging);
+            }
+            Block::Paragraph(text) => renderer.paragraph(text, "", ""),
+            Block::Code(tag, code) => renderer.code_block(tag, &code),
+            Block::Table(rows) => renderer.table(&rows),
+        }
+    }
+
+    while renderer.lines.last().is_some_and(|line| line.is_empty()) {
+        renderer.lines.pop();
+    }
+    Rendered {
+        lines: renderer.lines,
+        sections: renderer.sections,
+    }
+}
+
+struct Renderer {
+    width: usize,
+    color: bool,
+    lines: Vec<String>,
+    sections: Vec<usize>,
+}
+
+impl Renderer {
+    fn push(&mut self, line: String) {
+        self.lines.push(line);
+    }
+
+    // 连续的空行只保留一行
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn heading(&mut self, level: usize, title: &str) {
+        let title = plain(&inline(title, false));
+        match level {
+            1 => {
+                self.push(ui::paint(HEADING, &title, self.color));
+                let underline = "═".repeat(ui::display_width(&title));
+                self.push(ui::paint(HEADING, &underline, self.color));
+            }
+            2 => {
+                self.blank();
+                self.sections.push(self.lines.len());
+                let title = format!("§ {}  {}", self.sections.len(), title);
+                let underline = "─".repeat(ui::display_width(&title));
+                self.push(ui::paint(SECTION, &title, self.color));
+                self.push(ui::paint(SECTION, &underline, self.color));
+            }
+            3 => {
+                self.blank();
+                self.push(ui::paint(SUBHEADING, &format!("▸ {}", title), self.color));
+            }
+            _ => self.push(ui::paint(BOLD, &title, self.color)),
+        }
+    }
+
+    // 折行时第一行用 first 作前缀，后面的行用 rest 缩进
+    fn paragraph(&mut self, text: &str, first: &str, rest: &str) {
+        let spans = inline(text, self.color);
+        let available = self.width.saturating_sub(ui::display_width(rest)).max(10);
+        for (index, line) in wrap(&spans, available).into_iter().enumerate() {
+            let prefix = if index == 0 { first } else { rest };
+            self.push(format!("{}{}", prefix, self.spans(&line)));
+        }
+    }
+
+    fn code_block(&mut self, tag: &str, code: &[&str]) {
+        let mut highlighter = Language::from_tag(tag).map(Highlighter::new);
+        self.push(ui::paint(FRAME, &format!("  ╭─ {}", tag), self.color));
+        for line in code {
+            let body = match (&mut highlighter, self.color) {
+                (Some(highlighter), true) => highlighter
+                    .line(line)
+                    .into_iter()
+                    .map(|(kind, text)| ui::paint(kind.ansi(), &text, true))
+                    .collect(),
+                _ => line.to_string(),
+            };
+            let gutter = if line.is_empty() { "  │" } else { "  │ " };
+            self.push(format!("{}{}", ui::paint(FRAME, gutter, self.color), body));
+        }
+        self.push(ui::paint(FRAME, "  ╰─", self.color));
+    }
+
+    fn table(&mut self, rows: &[&str]) {
+        let (rows, alignments) = table_cells(rows);
+        let cells: Vec<Vec<Vec<Span>>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| inline(cell, self.color)).collect())
+            .collect();
+
+        let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                cells
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| ui::display_width(&plain(cell)))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let color = self.color;
+        let border = |left: &str, middle: &str, right: &str| {
+            let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            ui::paint(
+                FRAME,
+                &format!("{}{}{}", left, segments.join(middle), right),
+                color,
+            )
+        };
+
+        self.push(border("┌", "┬", "┐"));
+        for (index, row) in cells.iter().enumerate() {
+            let mut line = ui::paint(FRAME, "│", self.color);
+            for (column, width) in widths.iter().enumerate() {
+                let empty = Vec::new();
+                let cell = row.get(column).unwrap_or(&empty);
+                let padding = width - ui::display_width(&plain(cell));
+                let (left, right) = match alignments.get(column).unwrap_or(&Align::Left) {
+                    Align::Left => (0, padding),
+                    Align::Right => (padding, 0),
+                    Align::Center => (padding / 2, padding - padding / 2),
+                };
+                let mut text = self.spans(cell);
+                // 表头加粗
+                if index == 0 && !alignments.is_empty() {
+                    text = ui::paint(BOLD, &plain(cell), self.color);
+                }
+                line.push_str(&format!(
+                    " {}{}{} {}",
+                    " ".repeat(left),
+                    text,
+                    " ".repeat(right),
+                    ui::paint(FRAME, "│", self.color)
+                ));
+            }
+            self.push(line);
+            if index == 0 && !alignments.is_empty() {
+                self.push(border("├", "┼", "┤"));
+            }
+        }
+        self.push(border("└", "┴", "┘"));
+    }
+
+    fn spans(&self, spans: &[Span]) -> String {
+        spans
+            .iter()
+            .map(|(style, text)| ui::paint(style, text, self.color))
+            .collect()
+    }
+}
+
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    fn parse(cell: &str) -> Align {
+        match (cell.starts_with(':'), cell.ends_with(':')) {
+            (true, true) => Align::Center,
+            (false, true) => Align::Right,
+            _ => Align::Left,
+        }
+    }
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let title = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, title.trim()))
+}
+
+fn is_rule(line: &str) -> bool {
+    line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|mark| line.chars().all(|c| c == *mark))
+}
+
+// "- 内容"、"* 内容"、"1. 内容"，返回缩进、序号和内容
+fn list_item(line: &str) -> Option<(usize, Option<&str>, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let trimmed = line.trim_start();
+    if let Some(text) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+    {
+        return Some((indent, None, text.trim()));
+    }
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    let text = trimmed[digits..].strip_prefix(". ")?;
+    (digits > 0).then(|| (indent, Some(&trimmed[..digits]), text.trim()))
+}
+
+// 表格的单元格和对齐方式，分隔行（|---|:---:|）只用来决定对齐
+fn table_cells(rows: &[&str]) -> (Vec<Vec<String>>, Vec<Align>) {
+    let mut cells = Vec::new();
+    let mut alignments = Vec::new();
+    for row in rows {
+        let row = split_row(row);
+        if row.iter().all(|cell| is_delimiter(cell)) && !row.is_empty() {
+            alignments = row.iter().map(|cell| Align::parse(cell)).collect();
+            continue;
+        }
+        cells.push(row);
+    }
+    (cells, alignments)
+}
+
+fn split_row(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    row.split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+fn is_delimiter(cell: &str) -> bool {
+    !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':'))
+}
+
+enum Part<'a> {
+    Text(&'a str),
+    Code(&'a str),
+    Bold(&'a str),
+    // 文字和地址
+    Link(&'a str, &'a str),
+}
+
+fn parts(text: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let next = rest.find(['`', '*', '[']).unwrap_or(rest.len());
+        if next > 0 {
+            parts.push(Part::Text(&rest[..next]));
+        }
+        rest = &rest[next..];
+        if rest.is_empty() {
+            break;
+        }
+
+        if let Some(code) = rest.strip_prefix('`') {
+            if let Some(end) = code.find('`') {
+                parts.push(Part::Code(&code[..end]));
+                rest = &code[end + 1..];
+                continue;
+            }
+        } else if let Some(bold) = rest.strip_prefix("**") {
+            if let Some(end) = bold.find("**") {
+                parts.push(Part::Bold(&bold[..end]));
+                rest = &bold[end + 2..];
+                continue;
+            }
+        } else if let Some(link) = rest.strip_prefix('[') {
+            let target = link
+                .find("](")
+                .and_then(|middle| Some((middle, link[middle..].find(')')? + middle)));
+            if let Some((middle, end)) = target {
+                parts.push(Part::Link(&link[..middle], &link[middle + 2..end]));
+                rest = &link[end + 1..];
+                continue;
+            }
+        }
+        let length = rest.chars().next().map_or(1, char::len_utf8);
+        parts.push(Part::Text(&rest[..length]));
+        rest = &rest[length..];
+    }
+    parts
+}
+
+// 终端中的行内样式；不着色时保留代码两边的反引号，方便区分
+fn inline(text: &str, color: bool) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut push = |style: &'static str, text: &str| match spans.last_mut() {
+        Some((last, existing)) if *last == style => existing.push_str(text),
+        _ => spans.push((style, text.to_string())),
+    };
+
+    for part in parts(text) {
+        match part {
+            Part::Text(text) => push("", text),
+            Part::Code(code) if color => push(CODE, code),
+            Part::Code(code) => push(CODE, &format!("`{}`", code)),
+            Part::Bold(text) => push(BOLD, text),
+            Part::Link(text, url) => {
+                push(LINK, text);
+                push("", &format!(" ({})", url));
+            }
+        }
+    }
+    spans
+}
+
+fn plain(spans: &[Span]) -> String {
+    spans.iter().map(|(_, text)| text.as_str()).collect()
+}
+
+// 按显示宽度折行：英文在空格处断开，中文可以在任意两个字之间断开，行首的空格去掉
+fn wrap(spans: &[Span], width: usize) -> Vec<Vec<Span>> {
+    let mut words: Vec<(&'static str, String)> = Vec::new();
+    for (style, text) in spans {
+        let mut word = String::new();
+        for c in text.chars() {
+            let breaks = c == ' ' || ui::display_width(&c.to_string()) > 1;
+            if breaks && !word.is_empty() {
+                words.push((style, std::mem::take(&mut word)));
+            }
+            word.push(c);
+            if breaks {
+                words.push((style, std::mem::take(&mut word)));
+            }
+        }
+        if !word.is_empty() {
+            words.push((style, word));
+        }
+    }
+
+    let mut lines: Vec<Vec<Span>> = vec![Vec::new()];
+    let mut used = 0;
+    for (style, word) in words {
+        let word_width = ui::display_width(&word);
+        if used + word_width > width && used > 0 {
+            lines.push(Vec::new());
+            used = 0;
+        }
+        if used == 0 && word == " " {
+            continue;
+        }
+        let line = lines.last_mut().expect("lines 至少有一行");
+        match line.last_mut() {
+            Some((last, text)) if *last == style => text.push_str(&word),
+            _ => line.push((style, word)),
+        }
+        used += word_width;
+    }
+    // 去掉行尾的空格
+    for line in &mut lines {
+        if let Some((_, text)) = line.last_mut() {
+            let trimmed = text.trim_end().len();
+            text.truncate(trimmed);
+        }
+    }
+    lines
+}
+
+// 高亮一整段代码（show ts / show rs 使用），不着色时原样返回
+pub fn highlight_code(code: &str, language: Language, color: bool) -> String {
+    if !color {
+        return code.to_string();
+    }
+    let mut highlighter = Highlighter::new(language);
+    let mut output = String::with_capacity(code.len() * 2);
+    for line in code.lines() {
+        for (kind, text) in highlighter.line(line) {
+            if kind == Kind::Plain {
+                output.push_str(&text);
+            } else {
+                output.push_str(&ui::paint(kind.ansi(), &text, true));
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+// 转换成 HTML 片段（build-site 使用）；二级标题带 id="section-N"，和 show --section N 的编号一致
+pub fn to_html(content: &str) -> String {
+    let mut html = String::new();
+    // 当前打开的块：段落、引用、列表中连续的行合并在一起
+    let mut open: Option<&str> = None;
+    let mut sections = 0;
+
+    for block in blocks(content) {
+        let tag = match &block {
+            Block::Paragraph(_) => Some("p"),
+            Block::Quote(_) => Some("blockquote"),
+            Block::Item(_, None, _) => Some("ul"),
+            Block::Item(_, Some(_), _) => Some("ol"),
+            _ => None,
+        };
+        if open.is_some() && open != tag {
+            close(&mut html, open.take());
+        }
+        if open.is_none() {
+            if let Some(tag) = tag {
+                match tag {
+                    "blockquote" => html.push_str("<blockquote><p>"),
+                    "p" => html.push_str("<p>"),
+                    _ => html.push_str(&format!("<{}>\n", tag)),
+                }
+                open = Some(tag);
+            }
+        } else if matches!(block, Block::Paragraph(_) | Block::Quote(_)) {
+            html.push('\n');
+        }
+
+        match block {
+            Block::Blank => {}
+            Block::Heading(level, title) => {
+                let level = level.min(4);
+                let id = if level == 2 {
+                    sections += 1;
+                    format!(" id=\"section-{}\"", sections)
+                } else {
+                    String::new()
+                };
+                html.push_str(&format!(
+                    "<h{0}{1}>{2}</h{0}>\n",
+                    level,
+                    id,
+                    inline_html(title)
+                ));
+            }
+            Block::Rule => html.push_str("<hr>\n"),
+            Block::Quote(text) | Block::Paragraph(text) => html.push_str(&inline_html(text)),
+            Block::Item(indent, _, text) => {
+                let class = if indent > 0 { " class=\"nested\"" } else { "" };
+                html.push_str(&format!("<li{}>{}</li>\n", class, inline_html(text)));
+            }
+            Block::Code(tag, code) => {
+                html.push_str(&format!(
+                    "<pre class=\"code\" data-lang=\"{}\"><code>",
+                    escape(tag)
+                ));
+                html.push_str(&highlight_html(&code.join("\n"), Language::from_tag(tag)));
+                html.push_str("</code></pre>\n");
+            }
+            Block::Table(rows) => table_html(&mut html, &rows),
+        }
+    }
+    close(&mut html, open);
+    html
+}
+
+fn close(html: &mut String, open: Option<&str>) {
+    match open {
+        Some("blockquote") => html.push_str("</p></blockquote>\n"),
+        Some(tag) => html.push_str(&format!("</{}>\n", tag)),
+        None => {}
+    }
+}
+
+fn table_html(html: &mut String, rows: &[&str]) {
+    let (rows, alignments) = table_cells(rows);
+    html.push_str("<table>\n");
+    for (index, row) in rows.iter().enumerate() {
+        let header = index == 0 && !alignments.is_empty();
+        let cell_tag = if header { "th" } else { "td" };
+        html.push_str("<tr>");
+        for (column, cell) in row.iter().enumerate() {
+            let style = match alignments.get(column) {
+                Some(Align::Center) => " style=\"text-align:center\"",
+                Some(Align::Right) => " style=\"text-align:right\"",
+                _ => "",
+            };
+            html.push_str(&format!(
+                "<{0}{1}>{2}</{0}>",
+                cell_tag,
+                style,
+                inline_html(cell)
+            ));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+fn inline_html(text: &str) -> String {
+    parts(text)
+        .into_iter()
+        .map(|part| match part {
+            Part::Text(text) => escape(text),
+            Part::Code(code) => format!("<code>{}</code>", escape(code)),
+            Part::Bold(text) => format!("<strong>{}</strong>", escape(text)),
+            Part::Link(text, url) => {
+                format!("<a href=\"{}\">{}</a>", escape(url), escape(text))
+            }
+        })
+        .collect()
+}
+
+// 高亮后的 HTML，颜色由网页的 CSS 决定；不认识的语言只转义
+pub fn highlight_html(code: &str, language: Option<Language>) -> String {
+    let Some(language) = language else {
+        return escape(code);
+    };
+    let mut highlighter = Highlighter::new(language);
+    let mut html = String::with_capacity(code.len() * 2);
+    for (index, line) in code.lines().enumerate() {
+        if index > 0 {
+            html.push('\n');
+        }
+        for (kind, text) in highlighter.line(line) {
+            match kind.css_class() {
+                Some(class) => html.push_str(&format!(
+                    "<span class=\"{}\">{}</span>",
+                    class,
+                    escape(&text)
+                )),
+                None => html.push_str(&escape(&text)),
+            }
+        }
+    }
+    html
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,257 @@
+// 学习进度记录
+// 每次 run / check 都会更新 .tutorial/progress.json，记录每个模块和每个练习的状态
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::{CheckReport, Status};
+use crate::course::Course;
+use crate::i18n::t;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub modules: BTreeMap<String, ModuleProgress>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ModuleProgress {
+    // 时间均为 Unix 时间戳（秒）
+    pub first_attempt: Option<u64>,
+    pub last_attempt: Option<u64>,
+    #[serde(default)]
+    pub compile_attempts: u32,
+    #[serde(default)]
+    pub successful_compiles: u32,
+    // 第一次全部通过的时间
+    pub completed_at: Option<u64>,
+    // 按 demo.rs 中出现的顺序保存
+    #[serde(default)]
+    pub exercises: Vec<ExerciseProgress>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExerciseProgress {
+    pub name: String,
+    pub status: Status,
+    pub first_attempt: Option<u64>,
+    pub last_attempt: Option<u64>,
+    pub completed_at: Option<u64>,
+    #[serde(default)]
+    pub hints_used: u32,
+}
+
+impl ExerciseProgress {
+    fn new(name: &str) -> ExerciseProgress {
+        ExerciseProgress {
+            name: name.to_string(),
+            status: Status::Empty,
+            first_attempt: None,
+            last_attempt: None,
+            completed_at: None,
+            hints_used: 0,
+        }
+    }
+}
+
+impl ModuleProgress {
+    pub fn done_count(&self) -> usize {
+        self.exercises
+            .iter()
+            .filter(|exercise| exercise.status == Status::Done)
+            .count()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed_at.is_some()
+            && !self.exercises.is_empty()
+            && self.done_count() == self.exercises.len()
+    }
+
+    // 从第一次尝试到全部通过花了多久
+    pub fn time_to_pass(&self) -> Option<u64> {
+        Some(self.completed_at?.saturating_sub(self.first_attempt?))
+    }
+
+    fn record_compile(&mut self, success: bool, now: u64) {
+        self.first_attempt.get_or_insert(now);
+        self.last_attempt = Some(now);
+        self.compile_attempts += 1;
+        if success {
+            self.successful_compiles += 1;
+        }
+    }
+}
+
+impl Progress {
+    pub fn path(course: &Course) -> PathBuf {
+        course.root.join(".tutorial").join("progress.json")
+    }
+
+    // 文件不存在时返回空进度
+    pub fn load(path: &Path) -> Result<Progress, String> {
+        if !path.exists() {
+            return Ok(Progress::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| t!("无法读取进度文件 {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| t!("进度文件 {} 格式错误: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| t!("无法创建目录 {}: {}", dir.display(), e))?;
+        }
+
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| t!("无法序列化进度: {}", e))?;
+        fs::write(path, content + "\n")
+            .map_err(|e| t!("无法写入进度文件 {}: {}", path.display(), e))
+    }
+
+    pub fn module(&self, id: &str) -> Option<&ModuleProgress> {
+        self.modules.get(id)
+    }
+
+    // run 只知道是否编译成功
+    pub fn record_run(&mut self, module: &str, compiled: bool) {
+        let now = now();
+        self.modules
+            .entry(module.to_string())
+            .or_default()
+            .record_compile(compiled, now);
+    }
+
+    // 返回这个练习累计使用提示的次数
+    pub fn record_hint(&mut self, module: &str, exercise: &str) -> u32 {
+        let entry = self.modules.entry(module.to_string()).or_default();
+        let index = match entry.exercises.iter().position(|old| old.name == exercise) {
+            Some(index) => index,
+            None => {
+                entry.exercises.push(ExerciseProgress::new(exercise));
+                entry.exercises.len() - 1
+            }
+        };
+
+        let exercise = &mut entry.exercises[index];
+        exercise.hints_used += 1;
+        exercise.hints_used
+    }
+
+    // check 能拿到每个练习的状态；tests_passing 是隐藏测试的结果（没有 tests.rs 时为 true），
+    // 练习和隐藏测试都通过才算完成了这个模块
+    pub fn record_check(&mut self, module: &str, report: &CheckReport, tests_passing: bool) {
+        let now = now();
+        let entry = self.modules.entry(module.to_string()).or_default();
+        entry.record_compile(report.compile.success, now);
+
+        if report.parse_error.is_some() {
+            return;
+        }
+
+        // 按本次检查的顺序重建列表，练习被改名或删除后不再保留旧记录
+        let mut previous = std::mem::take(&mut entry.exercises);
+        for task in &report.tasks {
+            let mut exercise = match previous.iter().position(|old| old.name == task.name) {
+                Some(index) => previous.swap_remove(index),
+                None => ExerciseProgress::new(&task.name),
+            };
+
+            exercise.status = task.status;
+            if task.status != Status::Empty {
+                exercise.first_attempt.get_or_insert(now);
+                exercise.last_attempt = Some(now);
+            }
+            if task.status == Status::Done {
+                exercise.completed_at.get_or_insert(now);
+            }
+            entry.exercises.push(exercise);
+        }
+
+        if report.is_complete() && tests_passing {
+            entry.completed_at.get_or_insert(now);
+        }
+    }
+}
+
+// 进度只是辅助信息，写入失败时给出提示但不影响命令本身
+pub fn update(course: &Course, change: impl FnOnce(&mut Progress)) {
+    let path = Progress::path(course);
+    let result = Progress::load(&path).and_then(|mut progress| {
+        change(&mut progress);
+        progress.save(&path)
+    });
+
+    if let Err(e) = result {
+        eprintln!("{}", t!("⚠️  无法更新学习进度: {}", e));
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+// 不引入 chrono，手动把时间戳转换为 "YYYY-MM-DD HH:MM"（UTC）
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Howard Hinnant 的 civil_from_days 算法
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
+
+pub fn format_span(seconds: u64) -> String {
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3_599 => format!("{}m", seconds / 60),
+        3_600..=86_399 => format!("{}h{}m", seconds / 3_600, seconds % 3_600 / 60),
+        _ => format!("{}d{}h", seconds / 86_400, seconds % 86_400 / 3_600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_utc_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+        assert_eq!(format_timestamp(1_709_251_199), "2024-02-29 23:59");
+        assert_eq!(format_timestamp(1_735_689_600 + 3_661), "2025-01-01 01:01");
+    }
+
+    #[test]
+    fn formats_spans_with_two_units_at_most() {
+        assert_eq!(format_span(59), "59s");
+        assert_eq!(format_span(60), "1m");
+        assert_eq!(format_span(3_600 + 120), "1h2m");
+        assert_eq!(format_span(2 * 86_400 + 5 * 3_600 + 59), "2d5h");
+    }
+}
@@ -0,0 +1,558 @@
+// 机器可读的检查报告
+// check --all --report json|junit 把每个模块的练习状态、隐藏测试、编译诊断、风格建议和耗时
+// 输出到标准输出，方便在 CI 中收集结果；JUnit XML 按模块分成 <testsuite>，练习和测试各是一个 <testcase>
+
+use std::fs;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::checker::{self, Status, TaskKind};
+use crate::clippy;
+use crate::course::{Course, Lesson};
+use crate::diagnostics::Diagnostic;
+use crate::harness;
+use crate::i18n::{t, tr};
+use crate::lint;
+use crate::progress;
+use crate::testsuite::{self, Verdict};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Junit,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Format, String> {
+        match value {
+            "json" => Ok(Format::Json),
+            "junit" | "xml" => Ok(Format::Junit),
+            other => Err(t!("未知的报告格式 '{}'，可选: json | junit", other)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    pub generated_at: u64,
+    pub duration_ms: u128,
+    pub modules: Vec<ModuleReport>,
+}
+
+#[derive(Serialize)]
+pub struct ModuleReport {
+    pub id: String,
+    pub title: String,
+    pub complete: bool,
+    pub duration_ms: u128,
+    // 模块无法检查时的原因（例如缺少 cargo features），此时其余字段为空
+    pub error: Option<String>,
+    pub parse_error: Option<String>,
+    pub compile: Option<CompileEntry>,
+    pub exercises: Vec<ExerciseEntry>,
+    // 模块没有 tests.rs 时为 None
+    pub tests: Option<TestsEntry>,
+    pub lints: Vec<LintEntry>,
+}
+
+#[derive(Serialize)]
+pub struct CompileEntry {
+    pub success: bool,
+    pub errors: usize,
+    pub warnings: usize,
+    pub duration_ms: u128,
+    pub diagnostics: Vec<DiagnosticEntry>,
+}
+
+#[derive(Serialize)]
+pub struct DiagnosticEntry {
+    pub level: String,
+    pub code: Option<String>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct ExerciseEntry {
+    pub name: String,
+    // 当前界面语言下的标题（--lang en 时来自 course.toml 的 exercises_en）
+    pub title: String,
+    pub kind: TaskKind,
+    pub status: Status,
+    pub location: String,
+    pub errors: Vec<DiagnosticEntry>,
+    // main 中的练习逐个运行的结果
+    pub outcome: Option<harness::Outcome>,
+}
+
+#[derive(Serialize)]
+pub struct TestsEntry {
+    pub duration_ms: u128,
+    // demo.rs 中有无法隔离的编译错误时，整个测试无法运行
+    pub blocked: Vec<DiagnosticEntry>,
+    pub functions: Vec<TestGroupEntry>,
+}
+
+#[derive(Serialize)]
+pub struct TestGroupEntry {
+    pub function: String,
+    pub verdict: Verdict,
+    pub compile_error: Option<String>,
+    pub cases: Vec<TestCaseEntry>,
+}
+
+#[derive(Serialize)]
+pub struct TestCaseEntry {
+    pub name: String,
+    pub passed: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LintEntry {
+    // "typescript_habit"（lint.rs）或 "clippy"
+    pub source: &'static str,
+    pub rule: String,
+    pub line: Option<usize>,
+    pub message: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<&Diagnostic> for DiagnosticEntry {
+    fn from(diagnostic: &Diagnostic) -> DiagnosticEntry {
+        DiagnosticEntry {
+            level: diagnostic.level.clone(),
+            code: diagnostic.code.clone(),
+            line: diagnostic.line,
+            message: diagnostic.message.clone(),
+        }
+    }
+}
+
+pub fn report(course: &Course, lessons: &[&Lesson], format: Format) -> Result<(), String> {
+    let start = Instant::now();
+    let modules: Vec<ModuleReport> = lessons
+        .iter()
+        .map(|lesson| check_module(course, lesson))
+        .collect();
+    let report = Report {
+        generated_at: progress::now(),
+        duration_ms: start.elapsed().as_millis(),
+        modules,
+    };
+
+    match format {
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| t!("无法生成 JSON 报告: {}", e))?
+        ),
+        Format::Junit => print!("{}", junit(&report)),
+    }
+
+    let incomplete = report
+        .modules
+        .iter()
+        .filter(|module| !module.complete)
+        .count();
+    match incomplete {
+        0 => Ok(()),
+        _ => Err(t!("{}/{} 个模块未完成", incomplete, report.modules.len())),
+    }
+}
+
+// 单个模块出错不影响其他模块，错误记录在报告里
+pub fn check_module(course: &Course, lesson: &Lesson) -> ModuleReport {
+    let start = Instant::now();
+    let mut module = ModuleReport {
+        id: lesson.id.clone(),
+        title: lesson.title.clone(),
+        complete: false,
+        duration_ms: 0,
+        error: None,
+        parse_error: None,
+        compile: None,
+        exercises: Vec::new(),
+        tests: None,
+        lints: Vec::new(),
+    };
+    if let Err(e) = fill_module(course, lesson, &mut module) {
+        module.error = Some(e);
+    }
+    module.duration_ms = start.elapsed().as_millis();
+    module
+}
+
+fn fill_module(course: &Course, lesson: &Lesson, module: &mut ModuleReport) -> Result<(), String> {
+    let missing = lesson.missing_features();
+    if !missing.is_empty() {
+        return Err(t!("需要开启 cargo features: {}", missing.join(", ")));
+    }
+
+    let report = checker::check(lesson)?;
+
+    let compile = &report.compile;
+    module.parse_error = report.parse_error.clone();
+    module.compile = Some(CompileEntry {
+        success: compile.success,
+        errors: compile.errors(),
+        warnings: compile.warnings(),
+        duration_ms: compile.duration.as_millis(),
+        diagnostics: compile
+            .diagnostics
+            .iter()
+            .filter(|d| d.is_error() || d.is_warning())
+            .map(DiagnosticEntry::from)
+            .collect(),
+    });
+    module.exercises = report
+        .tasks
+        .iter()
+        .map(|task| ExerciseEntry {
+            name: task.name.clone(),
+            title: task.title.clone(),
+            kind: task.kind,
+            status: task.status,
+            location: task.location(),
+            errors: task.errors.iter().map(DiagnosticEntry::from).collect(),
+            outcome: task.outcome.clone(),
+        })
+        .collect();
+    if report.parse_error.is_some() {
+        progress::update(course, |progress| {
+            progress.record_check(&lesson.id, &report, false)
+        });
+        return Ok(());
+    }
+
+    // 进度要等隐藏测试跑完再记录，测试无法运行时模块也不算完成
+    let tests_start = Instant::now();
+    let suite = testsuite::run(lesson);
+    let tests_passing =
+        matches!(&suite, Ok(suite) if suite.as_ref().is_none_or(|suite| suite.is_passing()));
+    progress::update(course, |progress| {
+        progress.record_check(&lesson.id, &report, tests_passing)
+    });
+    let suite = suite?;
+    module.tests = suite.map(|suite| TestsEntry {
+        duration_ms: tests_start.elapsed().as_millis(),
+        blocked: suite.blocked.iter().map(DiagnosticEntry::from).collect(),
+        functions: suite
+            .groups
+            .iter()
+            .map(|group| TestGroupEntry {
+                function: group.name.clone(),
+                verdict: group.verdict(),
+                compile_error: group.compile_error.clone(),
+                cases: group
+                    .cases
+                    .iter()
+                    .map(|case| TestCaseEntry {
+                        name: case.name.clone(),
+                        passed: case.passed,
+                        message: case.message.clone(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    });
+    module.complete = report.is_complete() && tests_passing;
+
+    let source = fs::read_to_string(lesson.demo_path())
+        .map_err(|e| t!("无法读取 {}: {}", lesson.demo_path().display(), e))?;
+    let findings = lint::lint(&source)?;
+    let clippy_lints = if compile.success {
+        clippy::run(lesson)?.unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    module.lints = findings
+        .iter()
+        .map(|finding| LintEntry {
+            source: "typescript_habit",
+            rule: finding.rule.id().to_string(),
+            line: Some(finding.line),
+            message: finding.rule.title().to_string(),
+            before: Some(finding.before.clone()),
+            after: Some(finding.after.clone()),
+        })
+        .chain(clippy_lints.iter().map(|lint| LintEntry {
+            source: "clippy",
+            rule: lint.code.clone().unwrap_or_default(),
+            line: lint.line,
+            message: lint.message.clone(),
+            before: None,
+            after: None,
+        }))
+        .collect();
+    Ok(())
+}
+
+// JUnit XML：<testsuites> / 每个模块一个 <testsuite> / 练习和隐藏测试各是 <testcase>
+// 未开始、还没有实现的练习和未实现的测试记为 skipped，编译错误记为 error，其余未完成记为 failure
+fn junit(report: &Report) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let mut suites = String::new();
+    let (mut total, mut total_failures, mut total_errors, mut total_skipped) = (0, 0, 0, 0);
+
+    for module in &report.modules {
+        let mut cases = Vec::new();
+        if let Some(error) = &module.error {
+            cases.push(Case {
+                class: module.id.clone(),
+                name: "check".to_string(),
+                outcome: Outcome::Error(error.clone()),
+            });
+        }
+        if let Some(error) = &module.parse_error {
+            cases.push(Case {
+                class: module.id.clone(),
+                name: "demo.rs".to_string(),
+                outcome: Outcome::Error(t!("语法错误: {}", error)),
+            });
+        }
+        for exercise in &module.exercises {
+            let errors = || {
+                exercise
+                    .errors
+                    .iter()
+                    .map(describe)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            let outcome = match exercise.status {
+                Status::Done => Outcome::Passed,
+                Status::Empty => Outcome::Skipped(tr("未开始").to_string()),
+                Status::Compiles => match &exercise.outcome {
+                    Some(harness::Outcome::Unimplemented { .. }) => {
+                        Outcome::Skipped(tr("还没有实现 (not yet implemented)").to_string())
+                    }
+                    Some(outcome) if *outcome != harness::Outcome::Passed => {
+                        Outcome::Failure(tr("运行时没有通过").to_string(), outcome.describe())
+                    }
+                    _ => Outcome::Failure(tr("可编译，但还没有完成").to_string(), errors()),
+                },
+                Status::Broken => Outcome::Error(errors()),
+            };
+            cases.push(Case {
+                class: format!("{}.exercises", module.id),
+                name: exercise.title.clone(),
+                outcome,
+            });
+        }
+        if let Some(tests) = &module.tests {
+            if !tests.blocked.is_empty() {
+                let errors: Vec<String> = tests.blocked.iter().map(describe).collect();
+                cases.push(Case {
+                    class: format!("{}.tests", module.id),
+                    name: "compile".to_string(),
+                    outcome: Outcome::Error(errors.join("\n")),
+                });
+            }
+            for group in &tests.functions {
+                for case in &group.cases {
+                    let message = case.message.clone().unwrap_or_default();
+                    let outcome = match (case.passed, group.verdict) {
+                        (true, _) => Outcome::Passed,
+                        (false, Verdict::Unimplemented) => {
+                            Outcome::Skipped(tr("未实现").to_string())
+                        }
+                        (false, Verdict::Broken) => Outcome::Error(message),
+                        (false, _) => Outcome::Failure(tr("断言失败").to_string(), message),
+                    };
+                    cases.push(Case {
+                        class: format!("{}.tests.{}", module.id, group.function),
+                        name: case.name.clone(),
+                        outcome,
+                    });
+                }
+                if let Some(error) = &group.compile_error {
+                    cases.push(Case {
+                        class: format!("{}.tests.{}", module.id, group.function),
+                        name: "compile".to_string(),
+                        outcome: Outcome::Error(error.clone()),
+                    });
+                }
+            }
+        }
+
+        let count = |f: fn(&Outcome) -> bool| cases.iter().filter(|case| f(&case.outcome)).count();
+        let failures = count(|o| matches!(o, Outcome::Failure(..)));
+        let errors = count(|o| matches!(o, Outcome::Error(_)));
+        let skipped = count(|o| matches!(o, Outcome::Skipped(_)));
+        total += cases.len();
+        total_failures += failures;
+        total_errors += errors;
+        total_skipped += skipped;
+
+        suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">\n",
+            escape(&module.id),
+            cases.len(),
+            failures,
+            errors,
+            skipped,
+            seconds(module.duration_ms)
+        ));
+        suites.push_str(&format!(
+            "    <properties>\n      <property name=\"title\" value=\"{}\"/>\n      <property name=\"complete\" value=\"{}\"/>\n    </properties>\n",
+            escape(&module.title),
+            module.complete
+        ));
+        for case in &cases {
+            suites.push_str(&case.to_xml());
+        }
+        // 编译诊断和风格建议不算测试结果，放在输出里供查看
+        if let Some(compile) = &module.compile {
+            if !compile.diagnostics.is_empty() {
+                let text: Vec<String> = compile.diagnostics.iter().map(describe).collect();
+                suites.push_str(&format!(
+                    "    <system-err>{}</system-err>\n",
+                    escape(&text.join("\n"))
+                ));
+            }
+        }
+        if !module.lints.is_empty() {
+            let text: Vec<String> = module
+                .lints
+                .iter()
+                .map(|lint| {
+                    let line = lint.line.map(|l| t!("第 {} 行 ", l)).unwrap_or_default();
+                    format!("[{}] {}{}: {}", lint.source, line, lint.rule, lint.message)
+                })
+                .collect();
+            suites.push_str(&format!(
+                "    <system-out>{}</system-out>\n",
+                escape(&text.join("\n"))
+            ));
+        }
+        suites.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str(&format!(
+        "<testsuites name=\"rust-tutorial-for-typescript\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">\n",
+        total,
+        total_failures,
+        total_errors,
+        total_skipped,
+        seconds(report.duration_ms)
+    ));
+    xml.push_str(&suites);
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+enum Outcome {
+    Passed,
+    // 简短说明，详细内容
+    Failure(String, String),
+    Error(String),
+    Skipped(String),
+}
+
+struct Case {
+    class: String,
+    name: String,
+    outcome: Outcome,
+}
+
+impl Case {
+    fn to_xml(&self) -> String {
+        let open = format!(
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            escape(&self.class),
+            escape(&self.name)
+        );
+        match &self.outcome {
+            Outcome::Passed => format!("{}/>\n", open),
+            Outcome::Failure(message, detail) => format!(
+                "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                open,
+                escape(message),
+                escape(detail)
+            ),
+            Outcome::Error(detail) => format!(
+                "{}>\n      <error message=\"{}\">{}</error>\n    </testcase>\n",
+                open,
+                escape(detail.lines().next().unwrap_or(tr("编译错误"))),
+                escape(detail)
+            ),
+            Outcome::Skipped(message) => format!(
+                "{}>\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                open,
+                escape(message)
+            ),
+        }
+    }
+}
+
+fn describe(diagnostic: &DiagnosticEntry) -> String {
+    let mut text = String::new();
+    if let Some(code) = &diagnostic.code {
+        text.push_str(code);
+        text.push(' ');
+    }
+    if let Some(line) = diagnostic.line {
+        text.push_str(&t!("第 {} 行: ", line));
+    }
+    text.push_str(&diagnostic.message);
+    text
+}
+
+fn seconds(ms: u128) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+// XML 属性和文本中的特殊字符；控制字符（例如 ANSI 颜色）在 XML 1.0 中不合法，直接去掉
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\t' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_xml_special_characters() {
+        assert_eq!(
+            escape(r#"a < b && c > "d" 'e'"#),
+            "a &lt; b &amp;&amp; c &gt; &quot;d&quot; &apos;e&apos;"
+        );
+    }
+
+    #[test]
+    fn drops_control_characters_but_keeps_line_breaks() {
+        assert_eq!(
+            escape("\x1b[31merror\x1b[0m\n\tnote\r"),
+            "[31merror[0m\n\tnote\r"
+        );
+        assert_eq!(escape("练习 1: 类型推断"), "练习 1: 类型推断");
+    }
+
+    #[test]
+    fn formats_durations_in_seconds() {
+        assert_eq!(seconds(0), "0.000");
+        assert_eq!(seconds(1_234), "1.234");
+    }
+
+    #[test]
+    fn parses_report_formats() {
+        assert_eq!(Format::parse("json"), Ok(Format::Json));
+        assert_eq!(Format::parse("xml"), Ok(Format::Junit));
+        assert!(Format::parse("html").is_err());
+    }
+}
@@ -0,0 +1,240 @@
+// 重置练习
+// 把 demo.rs 恢复为 templates/<module>/demo.rs 中的原始模板（编译时嵌入二进制，见 build.rs），不依赖 git；
+// 覆盖之前先把当前文件备份到 .tutorial/backups/<module>/<时间>/demo.rs，可以用 restore 找回
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::course::{Course, Lesson};
+use crate::i18n::{t, tr};
+use crate::progress;
+
+include!(concat!(env!("OUT_DIR"), "/templates.rs"));
+
+pub fn template(id: &str) -> Option<&'static str> {
+    TEMPLATES
+        .iter()
+        .find(|(name, _)| *name == id)
+        .map(|(_, source)| *source)
+}
+
+// 课程目录中 templates/<module>/demo.rs 的内容，找不到时用编译时嵌入的版本
+pub fn pristine(course: &Course, lesson: &Lesson) -> Option<String> {
+    let path = course
+        .root
+        .join("templates")
+        .join(&lesson.id)
+        .join("demo.rs");
+    fs::read_to_string(path)
+        .ok()
+        .or_else(|| template(&lesson.id).map(str::to_string))
+}
+
+pub fn reset(course: &Course, module: &str) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let template = template(&lesson.id).ok_or_else(|| {
+        t!(
+            "二进制中没有 {0} 的模板，请把原始练习放到 templates/{0}/demo.rs 后重新编译",
+            lesson.id
+        )
+    })?;
+
+    let path = lesson.demo_path();
+    let current = fs::read_to_string(&path).ok();
+    if current.as_deref() == Some(template) {
+        println!("{}", t!("✨ {} 已经是原始模板，无需重置", path.display()));
+        return Ok(());
+    }
+
+    let backup = match &current {
+        Some(source) => Some(backup(course, lesson, source)?),
+        None => None,
+    };
+
+    fs::write(&path, template).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    println!("{}", t!("🔄 已将 {} 恢复为原始模板", path.display()));
+
+    if let Some(name) = backup {
+        println!("{}", t!("💾 原来的代码已备份为 {}", name));
+        println!(
+            "{}",
+            t!("   找回: cargo run -- restore {} {}", lesson.id, name)
+        );
+    }
+    Ok(())
+}
+
+pub fn restore(course: &Course, module: &str, name: Option<&str>) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let backups = list(course, lesson)?;
+
+    let Some(name) = name else {
+        if backups.is_empty() {
+            println!(
+                "{}",
+                t!("📭 {} 还没有备份（reset 时会自动备份）", lesson.id)
+            );
+            return Ok(());
+        }
+        println!("{}", t!("💾 {} 的备份（从旧到新）:", lesson.id));
+        for backup in &backups {
+            let lines =
+                fs::read_to_string(backups_dir(course, lesson).join(backup).join("demo.rs"))
+                    .map(|source| source.lines().count())
+                    .unwrap_or(0);
+            println!("{}", t!("  {}  ({} 行)", backup, lines));
+        }
+        println!();
+        println!(
+            "{}",
+            t!("恢复: cargo run -- restore {} <备份名|latest>", lesson.id)
+        );
+        return Ok(());
+    };
+
+    let chosen = find_backup(&backups, name)?;
+    let backup_path = backups_dir(course, lesson).join(chosen).join("demo.rs");
+    let source = fs::read_to_string(&backup_path)
+        .map_err(|e| t!("无法读取 {}: {}", backup_path.display(), e))?;
+
+    // 恢复前同样备份当前文件，除非它就是原始模板或与备份相同
+    let path = lesson.demo_path();
+    if let Ok(current) = fs::read_to_string(&path) {
+        if current != source && Some(current.as_str()) != template(&lesson.id) {
+            let saved = backup(course, lesson, &current)?;
+            println!("{}", t!("💾 当前代码已备份为 {}", saved));
+        }
+    }
+
+    fs::write(&path, source).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    println!("{}", t!("♻️  已从备份 {} 恢复 {}", chosen, path.display()));
+    Ok(())
+}
+
+fn backups_dir(course: &Course, lesson: &Lesson) -> PathBuf {
+    course
+        .root
+        .join(".tutorial")
+        .join("backups")
+        .join(&lesson.id)
+}
+
+// 备份名就是时间，例如 2026-10-18_09-30-05（UTC），按名称排序即按时间排序；
+// 同一秒内的多次备份加上补零的序号（-002、-003 ...），保证 -010 排在 -002 之后
+fn backup(course: &Course, lesson: &Lesson, source: &str) -> Result<String, String> {
+    let now = progress::now();
+    let stamp = format!(
+        "{}-{:02}",
+        progress::format_timestamp(now)
+            .replace(' ', "_")
+            .replace(':', "-"),
+        now % 60
+    );
+
+    let root = backups_dir(course, lesson);
+    let mut name = stamp.clone();
+    let mut suffix = 2;
+    while root.join(&name).exists() {
+        name = format!("{}-{:03}", stamp, suffix);
+        suffix += 1;
+    }
+
+    let dir = root.join(&name);
+    fs::create_dir_all(&dir).map_err(|e| t!("无法创建目录 {}: {}", dir.display(), e))?;
+    let path = dir.join("demo.rs");
+    fs::write(&path, source).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    Ok(name)
+}
+
+fn list(course: &Course, lesson: &Lesson) -> Result<Vec<String>, String> {
+    let dir = backups_dir(course, lesson);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .map_err(|e| t!("无法读取 {}: {}", dir.display(), e))?
+        .flatten()
+        .filter(|entry| entry.path().join("demo.rs").exists())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+// 可以写完整名称、latest，或者名称的唯一前缀（例如日期）
+fn find_backup<'a>(backups: &'a [String], name: &str) -> Result<&'a str, String> {
+    if name == "latest" {
+        return backups
+            .last()
+            .map(String::as_str)
+            .ok_or_else(|| tr("还没有任何备份").to_string());
+    }
+    if let Some(exact) = backups.iter().find(|backup| *backup == name) {
+        return Ok(exact);
+    }
+
+    let matches: Vec<&String> = backups
+        .iter()
+        .filter(|backup| backup.starts_with(name))
+        .collect();
+    match matches.as_slice() {
+        [backup] => Ok(backup),
+        [] => Err(t!(
+            "找不到备份 '{}'，不带备份名运行 restore 查看全部备份",
+            name
+        )),
+        _ => Err(t!(
+            "'{}' 匹配到 {} 个备份，请写得更完整一些",
+            name,
+            matches.len()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backups() -> Vec<String> {
+        let mut names: Vec<String> = [
+            "2026-10-17_20-00-00",
+            "2026-10-18_09-30-05",
+            "2026-10-18_09-30-05-002",
+            "2026-10-18_09-30-05-010",
+            "2026-10-18_09-30-05-003",
+        ]
+        .map(String::from)
+        .to_vec();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn latest_is_the_last_backup_in_the_same_second() {
+        assert_eq!(
+            find_backup(&backups(), "latest").unwrap(),
+            "2026-10-18_09-30-05-010"
+        );
+        assert!(find_backup(&[], "latest").is_err());
+    }
+
+    #[test]
+    fn exact_names_win_over_prefixes() {
+        // 完整名称同时也是同一秒其他备份的前缀
+        assert_eq!(
+            find_backup(&backups(), "2026-10-18_09-30-05").unwrap(),
+            "2026-10-18_09-30-05"
+        );
+        assert_eq!(
+            find_backup(&backups(), "2026-10-17").unwrap(),
+            "2026-10-17_20-00-00"
+        );
+    }
+
+    #[test]
+    fn unknown_or_ambiguous_names_are_errors() {
+        assert!(find_backup(&backups(), "2025").is_err());
+        assert!(find_backup(&backups(), "2026-10-18").is_err());
+    }
+}
@@ -0,0 +1,496 @@
+// 编译和运行学习者的 demo.rs
+// 编译产物放在临时目录中，不会在课程目录里留下可执行文件；
+// 运行时限制墙上时间、CPU 时间、内存和输出大小，超出时报告是哪一项限制
+
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::diagnostics::{self, Diagnostic};
+use crate::i18n::{t, tr};
+
+// 每个进程独立的构建目录，离开作用域时自动删除
+pub struct BuildDir {
+    path: PathBuf,
+}
+
+impl BuildDir {
+    pub fn new(name: &str) -> Result<BuildDir, String> {
+        let path = env::temp_dir()
+            .join("rust-tutorial-for-typescript")
+            .join(format!("{}-{}", name, std::process::id()));
+        fs::create_dir_all(&path).map_err(|e| t!("无法创建构建目录 {}: {}", path.display(), e))?;
+        Ok(BuildDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for BuildDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// rustc 的编译结果
+#[derive(Debug)]
+pub struct CompileOutput {
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub duration: Duration,
+    pub binary: PathBuf,
+}
+
+impl CompileOutput {
+    pub fn errors(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.is_error()).count()
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.is_warning()).count()
+    }
+
+    // 和直接运行 rustc 时看到的文本一致
+    pub fn rendered(&self) -> String {
+        self.diagnostics
+            .iter()
+            .map(|d| d.rendered.as_str())
+            .collect()
+    }
+}
+
+// 程序的运行结果
+#[derive(Debug)]
+pub struct RunOutput {
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub duration: Duration,
+    // 程序因为超出资源限制被终止时，是哪一项限制
+    pub limit: Option<Limit>,
+}
+
+// 学习者程序的资源限制：死循环、指数级递归或者超大的集合不应该卡死终端
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    // 墙上时间，超时后直接结束进程
+    pub timeout: Duration,
+    // CPU 时间（RLIMIT_CPU），只在 Unix 上生效
+    pub cpu_seconds: u64,
+    // 地址空间上限（RLIMIT_AS），只在 Unix 上生效
+    pub memory_bytes: u64,
+    // stdout 和 stderr 各自最多保存的字节数，超过后结束进程
+    pub output_bytes: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            timeout: Duration::from_secs(10),
+            cpu_seconds: 5,
+            memory_bytes: 1024 * 1024 * 1024,
+            output_bytes: 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Timeout(Duration),
+    Cpu(u64),
+    Memory(u64),
+    Output(usize),
+}
+
+impl Limit {
+    pub fn describe(self) -> String {
+        match self {
+            Limit::Timeout(timeout) => t!(
+                "运行超过 {} 秒仍未结束，已被终止（是不是有没有 break 的 loop，或者在等待输入？）",
+                timeout.as_secs()
+            ),
+            Limit::Cpu(seconds) => t!(
+                "CPU 时间超过 {} 秒，已被终止（死循环，或者像 fibonacci(50) 这样指数级的递归？）",
+                seconds
+            ),
+            Limit::Memory(bytes) => t!(
+                "内存超过 {} MB，分配失败后被终止（集合是不是在无限增长？）",
+                bytes / 1024 / 1024
+            ),
+            Limit::Output(bytes) => t!(
+                "输出超过 {} KB，已被终止（是不是在循环里不停地打印？）",
+                bytes / 1024
+            ),
+        }
+    }
+}
+
+// 调用 rustc 编译单个文件，只有 rustc 无法启动时才返回 Err
+pub fn compile(source: &Path, build_dir: &Path) -> Result<CompileOutput, String> {
+    compile_with(source, build_dir, &[])
+}
+
+// 编译成 libtest 测试程序；测试只关心对错，警告全部关闭
+pub fn compile_tests(source: &Path, build_dir: &Path) -> Result<CompileOutput, String> {
+    compile_with(source, build_dir, &["--test", "--cap-lints=allow"])
+}
+
+// 编译工具生成的代码（例如逐个练习运行的版本），学习者已经在 compile 中看过警告了
+pub fn compile_quiet(source: &Path, build_dir: &Path) -> Result<CompileOutput, String> {
+    compile_with(source, build_dir, &["--cap-lints=allow"])
+}
+
+fn compile_with(source: &Path, build_dir: &Path, extra: &[&str]) -> Result<CompileOutput, String> {
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "demo".to_string());
+    let binary = build_dir.join(format!("{}{}", stem, env::consts::EXE_SUFFIX));
+
+    let mut command = Command::new(rustc());
+    command
+        .arg("--edition=2021")
+        .arg("--crate-name=demo")
+        .arg("--error-format=json")
+        .args(extra)
+        .arg("-o")
+        .arg(&binary)
+        .arg(source);
+    if io::stderr().is_terminal() {
+        command.arg("--json=diagnostic-rendered-ansi");
+    }
+
+    let start = Instant::now();
+    let output = command
+        .output()
+        .map_err(|e| t!("无法启动 rustc: {}（请确认已安装 Rust）", e))?;
+    let duration = start.elapsed();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    Ok(CompileOutput {
+        success: output.status.success(),
+        exit_code: output.status.code(),
+        diagnostics: diagnostics::parse(&stderr),
+        duration,
+        binary,
+    })
+}
+
+// 运行编译好的程序；stream 为 true 时一边运行一边把输出打印到终端
+pub fn execute(binary: &Path, args: &[&str], stream: bool) -> Result<RunOutput, String> {
+    execute_with(binary, args, stream, Limits::default())
+}
+
+pub fn execute_with(
+    binary: &Path,
+    args: &[&str],
+    stream: bool,
+    limits: Limits,
+) -> Result<RunOutput, String> {
+    let mut command = Command::new(binary);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    sandbox::apply(&mut command, &limits);
+
+    let cpu_before = sandbox::children_cpu();
+    let start = Instant::now();
+    let mut child = command
+        .spawn()
+        .map_err(|e| t!("无法运行 {}: {}", binary.display(), e))?;
+
+    let stdout = child.stdout.take().expect("stdout 已设置为 piped");
+    let stderr = child.stderr.take().expect("stderr 已设置为 piped");
+
+    // 任意一路输出超过上限时置位，主线程看到后结束进程
+    let overflow = Arc::new(AtomicBool::new(false));
+    let cap = limits.output_bytes;
+    let stdout_thread = {
+        let overflow = Arc::clone(&overflow);
+        thread::spawn(move || forward(stdout, stream, false, cap, &overflow))
+    };
+    let stderr_thread = {
+        let overflow = Arc::clone(&overflow);
+        thread::spawn(move || forward(stderr, stream, true, cap, &overflow))
+    };
+
+    let mut limit = None;
+    let status = loop {
+        if let Some(status) = child
+            .try_wait()
+            .map_err(|e| t!("等待程序结束失败: {}", e))?
+        {
+            break status;
+        }
+        if limit.is_none() {
+            if overflow.load(Ordering::Relaxed) {
+                limit = Some(Limit::Output(cap));
+            } else if start.elapsed() >= limits.timeout {
+                limit = Some(Limit::Timeout(limits.timeout));
+            }
+            if limit.is_some() {
+                let _ = child.kill();
+            }
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    let duration = start.elapsed();
+
+    let stdout = stdout_thread.join().unwrap_or_default();
+    let stderr = stderr_thread.join().unwrap_or_default();
+    // 这段时间内结束的子进程只有这一个（serve 中编译和运行也是排队进行的）
+    let cpu = sandbox::children_cpu().saturating_sub(cpu_before);
+    let limit = limit.or_else(|| sandbox::limit_hit(&status, &stderr, cpu, &limits));
+
+    Ok(RunOutput {
+        success: status.success(),
+        exit_code: status.code(),
+        stdout,
+        stderr,
+        duration,
+        limit,
+    })
+}
+
+// 按固定大小的块读取子进程输出，同时保存一份完整内容；超过上限后置位 overflow，之后只读取不保存，
+// 既不会让没有换行的输出在本进程里无限增长，也不会让子进程阻塞在写管道上
+fn forward<R: Read>(
+    mut reader: R,
+    stream: bool,
+    is_stderr: bool,
+    cap: usize,
+    overflow: &AtomicBool,
+) -> String {
+    let mut captured = Vec::new();
+    let mut buffer = [0u8; 8192];
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        };
+        let room = cap.saturating_sub(captured.len());
+        if read > room {
+            overflow.store(true, Ordering::Relaxed);
+        }
+        let chunk = &buffer[..read.min(room)];
+        if chunk.is_empty() {
+            continue;
+        }
+        if stream {
+            if is_stderr {
+                let _ = io::stderr().write_all(chunk);
+            } else {
+                let _ = io::stdout().write_all(chunk);
+                let _ = io::stdout().flush();
+            }
+        }
+        captured.extend_from_slice(chunk);
+    }
+
+    String::from_utf8_lossy(&captured).into_owned()
+}
+
+#[cfg(unix)]
+mod sandbox {
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::process::{Command, ExitStatus};
+    use std::time::Duration;
+
+    use super::{Limit, Limits};
+
+    pub fn apply(command: &mut Command, limits: &Limits) {
+        let cpu = limits.cpu_seconds;
+        let memory = limits.memory_bytes;
+        // SAFETY: 在 fork 之后、exec 之前只调用 setrlimit，它是 async-signal-safe 的，不分配内存也不加锁
+        unsafe {
+            command.pre_exec(move || {
+                // 软限制到期时收到 SIGXCPU，硬限制多留一秒兜底 SIGKILL
+                set(libc::RLIMIT_CPU, cpu, cpu + 1)?;
+                set(libc::RLIMIT_AS, memory, memory)?;
+                Ok(())
+            });
+        }
+    }
+
+    // glibc 中 RLIMIT_* 是 u32，其他平台是 c_int
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    type Resource = libc::__rlimit_resource_t;
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    type Resource = libc::c_int;
+
+    #[allow(clippy::unnecessary_cast)]
+    fn set(resource: Resource, soft: u64, hard: u64) -> std::io::Result<()> {
+        let limit = libc::rlimit {
+            rlim_cur: soft as libc::rlim_t,
+            rlim_max: hard as libc::rlim_t,
+        };
+        if unsafe { libc::setrlimit(resource, &limit) } == 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    }
+
+    // 已经结束并被回收的子进程累计使用的 CPU 时间（用户态 + 内核态）
+    pub fn children_cpu() -> Duration {
+        // SAFETY: rusage 是纯数据结构，全零是合法的初始值
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        if unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut usage) } != 0 {
+            return Duration::ZERO;
+        }
+        let time = |t: libc::timeval| {
+            Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+        };
+        time(usage.ru_utime) + time(usage.ru_stime)
+    }
+
+    // 根据退出信号判断是哪一项 rlimit 生效了；SIGKILL 也可能来自 OOM killer 等外部原因，
+    // 只有 CPU 时间确实到了硬限制才算超出 CPU 限制
+    pub fn limit_hit(
+        status: &ExitStatus,
+        stderr: &str,
+        cpu: Duration,
+        limits: &Limits,
+    ) -> Option<Limit> {
+        match status.signal()? {
+            libc::SIGXCPU => Some(Limit::Cpu(limits.cpu_seconds)),
+            libc::SIGKILL if cpu.as_secs() >= limits.cpu_seconds => {
+                Some(Limit::Cpu(limits.cpu_seconds))
+            }
+            // Rust 在内存分配失败时打印这句话然后 abort
+            libc::SIGABRT if stderr.contains("memory allocation of") => {
+                Some(Limit::Memory(limits.memory_bytes))
+            }
+            _ => None,
+        }
+    }
+}
+
+// 其他平台上只有超时和输出上限
+#[cfg(not(unix))]
+mod sandbox {
+    use std::process::{Command, ExitStatus};
+    use std::time::Duration;
+
+    use super::{Limit, Limits};
+
+    pub fn apply(_command: &mut Command, _limits: &Limits) {}
+
+    pub fn children_cpu() -> Duration {
+        Duration::ZERO
+    }
+
+    pub fn limit_hit(
+        _status: &ExitStatus,
+        _stderr: &str,
+        _cpu: Duration,
+        _limits: &Limits,
+    ) -> Option<Limit> {
+        None
+    }
+}
+
+// 允许通过 RUSTC 环境变量指定编译器，和 cargo 的约定一致
+pub fn rustc() -> String {
+    env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string())
+}
+
+pub fn describe_status(success: bool, exit_code: Option<i32>) -> String {
+    match (success, exit_code) {
+        (true, _) => tr("正常退出").to_string(),
+        (false, Some(code)) => t!("退出码 {}", code),
+        (false, None) => tr("被信号终止").to_string(),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() >= 1 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 编译一个小程序，在给定的限制下运行
+    fn run_program(name: &str, source: &str, limits: Limits) -> RunOutput {
+        let build_dir = BuildDir::new(&format!("runner-test-{}", name)).unwrap();
+        let path = build_dir.path().join("main.rs");
+        fs::write(&path, source).unwrap();
+        let compiled = compile(&path, build_dir.path()).unwrap();
+        assert!(compiled.success, "{}", compiled.rendered());
+        execute_with(&compiled.binary, &[], false, limits).unwrap()
+    }
+
+    fn small_limits() -> Limits {
+        Limits {
+            timeout: Duration::from_secs(5),
+            cpu_seconds: 1,
+            memory_bytes: 1024 * 1024 * 1024,
+            output_bytes: 4096,
+        }
+    }
+
+    #[test]
+    fn normal_programs_hit_no_limit() {
+        let output = run_program("ok", "fn main() { println!(\"hi\"); }", small_limits());
+        assert!(output.success);
+        assert_eq!(output.stdout, "hi\n");
+        assert_eq!(output.limit, None);
+    }
+
+    #[test]
+    fn sleeping_programs_time_out() {
+        let limits = Limits {
+            timeout: Duration::from_millis(300),
+            ..small_limits()
+        };
+        let source = "fn main() { std::thread::sleep(std::time::Duration::from_secs(30)); }";
+        let output = run_program("sleep", source, limits);
+        assert!(!output.success);
+        assert_eq!(output.limit, Some(Limit::Timeout(limits.timeout)));
+        assert!(output.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn print_loops_hit_the_output_limit() {
+        let source = "fn main() { loop { println!(\"again and again\"); } }";
+        let output = run_program("print", source, small_limits());
+        assert!(!output.success);
+        assert_eq!(output.limit, Some(Limit::Output(4096)));
+        assert!(output.stdout.len() <= 4096);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn busy_loops_hit_the_cpu_limit() {
+        let source = "fn main() {
+            let mut x: u64 = 0;
+            loop {
+                x = std::hint::black_box(x.wrapping_add(1));
+            }
+        }";
+        let output = run_program("busy", source, small_limits());
+        assert!(!output.success);
+        assert_eq!(output.limit, Some(Limit::Cpu(1)));
+    }
+}
@@ -0,0 +1,715 @@
+// 浏览器中的课程 IDE
+// serve 在 127.0.0.1 上启动 HTTP 服务：/ 是内置的单页界面（TypeScript 参考、编辑器、输出三个面板），
+// /api/... 是 JSON 接口。编译、运行、检查和提示与命令行共用 runner / checker / hint，进度写入同一个 progress.json。
+// 只用标准库：每个连接一个线程，编译相关的请求用锁排队（构建目录按进程区分，不能同时编译）。
+// 浏览器中其他网站也能访问 localhost，所以只接受 Host 为本机的请求，修改和运行代码还要求带 X-Tutorial 请求头
+// （跨站请求带自定义请求头需要 CORS 预检，这里不响应预检）
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::checker::Status;
+use crate::commands;
+use crate::course::Course;
+use crate::harness;
+use crate::highlight::Language;
+use crate::hint::{self, MAX_LEVEL};
+use crate::i18n::{self, t, tr};
+use crate::markdown::{self, escape};
+use crate::progress::Progress;
+use crate::report;
+use crate::runner;
+use crate::ui;
+
+pub const DEFAULT_PORT: u16 = 7878;
+// demo.rs 的大小上限
+const MAX_BODY: usize = 2 * 1024 * 1024;
+
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn json(status: u16, value: &Value) -> Response {
+        Response {
+            status,
+            content_type: "application/json; charset=utf-8",
+            body: value.to_string().into_bytes(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, &json!({ "error": message }))
+    }
+
+    fn html(body: String) -> Response {
+        Response {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body: body.into_bytes(),
+        }
+    }
+}
+
+pub fn serve(course: &Course, port: u16) -> Result<(), String> {
+    let address = format!("127.0.0.1:{}", port);
+    let listener = TcpListener::bind(&address).map_err(|e| t!("无法监听 {}: {}", address, e))?;
+    println!("{}", t!("🌐 课程 IDE 已启动: http://{}", address));
+    println!(
+        "{}",
+        tr("   在浏览器中打开上面的地址，编译仍使用本机的 rustc；按 Ctrl+C 停止")
+    );
+
+    let compiling = Mutex::new(());
+    thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let compiling = &compiling;
+            scope.spawn(move || connection(course, port, compiling, stream));
+        }
+    });
+    Ok(())
+}
+
+fn connection(course: &Course, port: u16, compiling: &Mutex<()>, mut stream: TcpStream) {
+    // 浏览器预先建立但不发送请求的连接不能一直占着线程
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(10)));
+    let start = Instant::now();
+    let (summary, response) = match read_request(&mut stream) {
+        Ok(request) => (
+            format!("{} {}", request.method, request.path),
+            handle(course, port, compiling, &request),
+        ),
+        Err(e) => (String::from("-"), Response::error(400, &e)),
+    };
+    // 界面和查询类请求很多，只记录会编译或修改文件的请求
+    if !summary.starts_with("GET") {
+        println!(
+            "  {} → {} ({})",
+            summary,
+            response.status,
+            runner::format_duration(start.elapsed())
+        );
+    }
+    let _ = write_response(&mut stream, &response);
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, String> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|e| t!("读取请求失败: {}", e))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(tr("请求格式错误").to_string());
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| t!("读取请求失败: {}", e))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = header.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: Vec::new(),
+    };
+    let length: usize = request
+        .header("Content-Length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    if length > MAX_BODY {
+        return Err(t!("请求内容超过 {} KB", MAX_BODY / 1024));
+    }
+    request.body = vec![0; length];
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|e| t!("读取请求失败: {}", e))?;
+    Ok(request)
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason,
+        response.content_type,
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+fn handle(course: &Course, port: u16, compiling: &Mutex<()>, request: &Request) -> Response {
+    let host = request.header("Host").unwrap_or("");
+    if host != format!("127.0.0.1:{}", port) && host != format!("localhost:{}", port) {
+        return Response::error(403, tr("只接受来自本机的请求"));
+    }
+    if request.method != "GET" && request.header("X-Tutorial").is_none() {
+        return Response::error(403, tr("缺少请求头 X-Tutorial"));
+    }
+
+    let path = request.path.split('?').next().unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+    // 编译和写入 demo.rs 的请求逐个进行
+    let lock = || {
+        compiling
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    };
+    let result = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", []) => return Response::html(page()),
+        ("GET", ["api", "modules"]) => Ok(modules(course)),
+        ("GET", ["api", "progress"]) => progress_summary(course),
+        ("GET", ["api", "modules", id, "demo"]) => demo(course, id),
+        ("PUT", ["api", "modules", id, "demo"]) => {
+            let _guard = lock();
+            save_demo(course, id, &request.body)
+        }
+        ("GET", ["api", "modules", id, "example"]) => example(course, id),
+        ("POST", ["api", "modules", id, "run"]) => {
+            let _guard = lock();
+            run(course, id)
+        }
+        ("POST", ["api", "modules", id, "check"]) => {
+            let _guard = lock();
+            check(course, id)
+        }
+        ("POST", ["api", "modules", id, "hint"]) => {
+            let _guard = lock();
+            hint(course, id, &request.body)
+        }
+        (method, _) => {
+            return Response::error(404, &t!("没有这个接口: {} {}", method, path));
+        }
+    };
+    match result {
+        Ok(value) => Response::json(200, &value),
+        Err(e) => Response::error(400, &e),
+    }
+}
+
+fn modules(course: &Course) -> Value {
+    let modules: Vec<Value> = course
+        .lessons
+        .iter()
+        .map(|lesson| {
+            json!({
+                "id": lesson.id,
+                "title": lesson.display_title(),
+                "label": lesson.label(),
+                "difficulty": lesson.difficulty.map(|difficulty| difficulty.label()),
+                "prerequisites": lesson.prerequisites,
+                "exercises": lesson.exercises,
+            })
+        })
+        .collect();
+    json!(modules)
+}
+
+// 每个模块完成了几个练习，以及 progress.json 的完整内容
+fn progress_summary(course: &Course) -> Result<Value, String> {
+    let progress = Progress::load(&Progress::path(course))?;
+    let modules: Vec<Value> = course
+        .lessons
+        .iter()
+        .map(|lesson| {
+            let module = progress.module(&lesson.id);
+            json!({
+                "id": lesson.id,
+                "label": lesson.label(),
+                "done": module.map_or(0, |module| module.done_count()),
+                "total": module.map_or(0, |module| module.exercises.len()),
+                "complete": module.is_some_and(|module| module.is_complete()),
+            })
+        })
+        .collect();
+    Ok(json!({ "modules": modules, "progress": progress }))
+}
+
+fn demo(course: &Course, id: &str) -> Result<Value, String> {
+    let lesson = course.find(id)?;
+    let path = lesson.demo_path();
+    let source = fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+    Ok(json!({ "path": path.display().to_string(), "source": source }))
+}
+
+fn save_demo(course: &Course, id: &str, body: &[u8]) -> Result<Value, String> {
+    let lesson = course.find(id)?;
+    let source = std::str::from_utf8(body).map_err(|_| tr("demo.rs 必须是 UTF-8 文本"))?;
+    let path = lesson.demo_path();
+    fs::write(&path, source).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    Ok(json!({ "saved": true }))
+}
+
+// TypeScript 参考：原文和高亮后的 HTML
+fn example(course: &Course, id: &str) -> Result<Value, String> {
+    let lesson = course.find(id)?;
+    let path = lesson.example_path();
+    let source = fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+    let html = markdown::highlight_html(&source, Some(Language::TypeScript));
+    Ok(json!({ "source": source, "html": html }))
+}
+
+// 和 run 命令相同的流程（commands::run_demo），输出收集起来一起返回；output 是给界面直接显示的文本
+fn run(course: &Course, id: &str) -> Result<Value, String> {
+    let lesson = course.find(id)?;
+    let result = commands::run_demo(course, lesson, false, |_| {})?;
+
+    let compiled = &result.report.compile;
+    let mut output = ui::strip_ansi(&compiled.rendered());
+    let compile = json!({
+        "success": compiled.success,
+        "errors": compiled.errors(),
+        "warnings": compiled.warnings(),
+        "duration_ms": compiled.duration.as_millis(),
+    });
+    let Some(run) = &result.output else {
+        output += &t!(
+            "❌ 编译失败: rustc {}，{} 个错误，{} 个警告 ({})",
+            runner::describe_status(compiled.success, compiled.exit_code),
+            compiled.errors(),
+            compiled.warnings(),
+            runner::format_duration(compiled.duration)
+        );
+        return Ok(json!({ "compile": compile, "run": null, "exercises": [], "output": output }));
+    };
+
+    output += &t!(
+        "✅ 编译成功: {} 个警告 ({})",
+        compiled.warnings(),
+        runner::format_duration(compiled.duration)
+    );
+    output.push('\n');
+    output += tr("🏃 运行输出:");
+    output += &format!(
+        "\n{}\n{}{}{}\n",
+        "-".repeat(40),
+        run.stdout,
+        run.stderr,
+        "-".repeat(40)
+    );
+    let icon = if run.success { "✅" } else { "❌" };
+    output += &t!(
+        "{} 程序{}: stdout {} 行，stderr {} 行 ({})",
+        icon,
+        runner::describe_status(run.success, run.exit_code),
+        run.stdout.lines().count(),
+        run.stderr.lines().count(),
+        runner::format_duration(run.duration)
+    );
+    output.push('\n');
+    if let Some(limit) = run.limit {
+        output += &format!("⛔ {}\n", limit.describe());
+    }
+    if result.whole_program {
+        output += tr("💡 main 中的练习之间共用了变量，无法逐个运行，已按整个程序运行");
+    } else {
+        output += &commands::exercise_runs(&result.report, &result.runs).0;
+    }
+
+    let exercises: Vec<Value> = result
+        .runs
+        .iter()
+        .map(|run| {
+            json!({
+                "name": run.name,
+                "title": lesson.exercise_title(&run.name),
+                "outcome": run.outcome,
+            })
+        })
+        .collect();
+    Ok(json!({
+        "compile": compile,
+        "run": {
+            "success": run.success,
+            "exit_code": run.exit_code,
+            "stdout": run.stdout,
+            "stderr": run.stderr,
+            "duration_ms": run.duration.as_millis(),
+            "limit": run.limit.map(|limit| limit.describe()),
+        },
+        "exercises": exercises,
+        "output": output,
+    }))
+}
+
+// 和 check --report json 中的一个模块相同，另外附上给界面显示的文本
+fn check(course: &Course, id: &str) -> Result<Value, String> {
+    let lesson = course.find(id)?;
+    let module = report::check_module(course, lesson);
+    if let Some(error) = &module.error {
+        return Err(error.clone());
+    }
+
+    let mut output = String::new();
+    if let Some(error) = &module.parse_error {
+        output += &t!("❌ demo.rs 有语法错误: {}", error);
+        output.push('\n');
+    }
+    for exercise in &module.exercises {
+        output += &format!(
+            "{} {}  {}  ({})\n",
+            exercise.status.icon(),
+            exercise.title,
+            exercise.status.label(),
+            exercise.location
+        );
+        for error in &exercise.errors {
+            output += &format!("      {}\n", error.message);
+        }
+        if let Some(outcome) = &exercise.outcome {
+            if *outcome != harness::Outcome::Passed {
+                output += &format!("      {} {}\n", outcome.icon(), outcome.describe());
+            }
+        }
+    }
+    if let Some(tests) = &module.tests {
+        let cases = tests.functions.iter().flat_map(|group| &group.cases);
+        let passed = cases.clone().filter(|case| case.passed).count();
+        output.push('\n');
+        output += &t!("🧪 隐藏测试: {}/{} 通过", passed, cases.count());
+        output.push('\n');
+    }
+    for lint in &module.lints {
+        let line = lint
+            .line
+            .map(|line| t!("第 {} 行", line))
+            .unwrap_or_default();
+        output += &format!("💡 {} {}\n", line, lint.message);
+    }
+
+    let done = module
+        .exercises
+        .iter()
+        .filter(|exercise| exercise.status == Status::Done)
+        .count();
+    output.push('\n');
+    output += &if module.complete {
+        t!("🎉 {} 的所有练习都已完成！", lesson.id)
+    } else {
+        t!("📊 {}/{} 个练习完成", done, module.exercises.len())
+    };
+
+    let mut value = serde_json::to_value(&module).map_err(|e| t!("无法生成 JSON 报告: {}", e))?;
+    value["output"] = json!(output);
+    Ok(value)
+}
+
+// 请求内容可以是 {"exercise": "练习 3"}，没有时提示第一个没完成的练习
+fn hint(course: &Course, id: &str, body: &[u8]) -> Result<Value, String> {
+    let exercise = serde_json::from_slice::<Value>(body)
+        .ok()
+        .and_then(|value| value["exercise"].as_str().map(str::to_string));
+    let hint = hint::next_hint(course, id, exercise.as_deref())?;
+
+    let mut output = t!(
+        "💡 {} / {} 的提示（第 {}/{} 级，已使用 {} 次）",
+        hint.module,
+        hint.exercise,
+        hint.level,
+        MAX_LEVEL,
+        hint.used
+    );
+    output += &format!("\n\n{}\n", tr("── 第 1 级：模板中的提示 ──"));
+    if hint.inline.is_empty() {
+        output += &format!("{}\n", tr("  （模板中没有针对这个练习的提示）"));
+    }
+    for line in &hint.inline {
+        output += &format!("  • {}\n", line);
+    }
+    if hint.level >= 2 {
+        match &hint.section {
+            Some(section) => {
+                output += &format!(
+                    "\n{}\n{}\n",
+                    t!(
+                        "── 第 2 级：answer.md「{}」（第 {} 行）──",
+                        section.title,
+                        section.line
+                    ),
+                    section.body.trim_end()
+                )
+            }
+            None => {
+                output += &format!(
+                    "\n{}\n{}\n",
+                    tr("── 第 2 级：answer.md ──"),
+                    tr("  （answer.md 中没有找到相关的讲解小节）")
+                )
+            }
+        }
+    }
+    if hint.level >= 3 {
+        output += &format!("\n{}\n", tr("── 第 3 级：参考实现 ──"));
+        match &hint.reference {
+            Some(source) => output += source,
+            None => output += &t!("  （参考答案中没有找到 {}）", hint.exercise),
+        }
+    }
+    if hint.level < MAX_LEVEL {
+        output += &format!("\n{}", tr("🔓 再次点击「提示」查看下一级"));
+    }
+
+    Ok(json!({
+        "module": hint.module,
+        "exercise": hint.exercise,
+        "level": hint.level,
+        "max_level": MAX_LEVEL,
+        "used": hint.used,
+        "inline": hint.inline,
+        "section": hint.section.as_ref().map(|section| json!({
+            "title": section.title,
+            "line": section.line,
+            "body": section.body,
+        })),
+        "reference": hint.reference,
+        "output": output,
+    }))
+}
+
+// 单页界面；文字由服务端按当前语言填入 window.LABELS
+fn page() -> String {
+    // 键值对写成元组而不是 json!，validate 才能在源码中找到这些文案
+    let labels: serde_json::Map<String, Value> = [
+        ("reference", tr("TypeScript 参考 · example.ts")),
+        ("output", tr("输出")),
+        ("run", tr("▶ 运行")),
+        ("check", tr("✅ 检查")),
+        ("hint", tr("💡 提示")),
+        ("save", tr("💾 保存")),
+        ("saved", tr("已保存")),
+        ("unsaved", tr("● 未保存")),
+        ("busy", tr("⏳ 编译中…")),
+        ("discard", tr("当前修改还没有保存，确定要切换模块吗？")),
+        ("keys", tr("Ctrl+S 保存 · Ctrl+Enter 运行")),
+        (
+            "ready",
+            tr("选择模块后在右侧编辑 demo.rs，点击「运行」或「检查」查看结果。"),
+        ),
+    ]
+    .into_iter()
+    .map(|(key, text)| (key.to_string(), json!(text)))
+    .collect();
+    PAGE.replace("{{LANG}}", i18n::current().code())
+        .replace("{{TITLE}}", &escape(tr("Rust 教程 · 在线练习")))
+        .replace(
+            "{{LABELS}}",
+            &Value::Object(labels).to_string().replace("</", "<\\/"),
+        )
+}
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="{{LANG}}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{{TITLE}}</title>
+<style>
+* { box-sizing: border-box; }
+html, body { height: 100%; margin: 0; }
+body { display: flex; flex-direction: column; font: 14px/1.5 -apple-system, "Segoe UI", "PingFang SC", "Microsoft YaHei", sans-serif; color: #1f2328; background: #f6f8fa; }
+header { display: flex; align-items: center; gap: 8px; padding: 8px 12px; border-bottom: 1px solid #d0d7de; background: #fff; }
+header strong { margin-right: 8px; }
+header select, header button { font: inherit; padding: 4px 10px; border: 1px solid #d0d7de; border-radius: 6px; background: #f6f8fa; cursor: pointer; }
+header button:disabled { opacity: 0.5; cursor: wait; }
+#state { margin-left: auto; color: #59636e; }
+.panes { flex: 1; min-height: 0; display: grid; grid-template-columns: 2fr 3fr; grid-template-rows: 3fr 2fr; gap: 1px; background: #d0d7de; }
+.pane { display: flex; flex-direction: column; min-height: 0; background: #fff; }
+.pane h2 { margin: 0; padding: 4px 12px; font-size: 12px; font-weight: 600; color: #59636e; background: #f6f8fa; border-bottom: 1px solid #d0d7de; }
+#reference-pane { grid-row: 1 / 3; }
+pre, textarea { flex: 1; margin: 0; padding: 12px; overflow: auto; font: 13px/1.5 ui-monospace, Menlo, Consolas, monospace; tab-size: 4; }
+textarea { border: none; resize: none; outline: none; white-space: pre; }
+#output { background: #1f2328; color: #e6edf3; white-space: pre-wrap; }
+.kw { color: #cf222e; } .ty { color: #8250df; } .st { color: #0a3069; } .nu { color: #0550ae; } .co { color: #6e7781; font-style: italic; } .ma { color: #953800; }
+</style>
+</head>
+<body>
+<header>
+<strong>🦀 {{TITLE}}</strong>
+<select id="module"></select>
+<button id="save"></button>
+<button id="run"></button>
+<button id="check"></button>
+<button id="hint"></button>
+<span id="state"></span>
+</header>
+<div class="panes">
+<section class="pane" id="reference-pane"><h2 id="reference-title"></h2><pre id="reference"></pre></section>
+<section class="pane"><h2 id="editor-title">demo.rs</h2><textarea id="editor" spellcheck="false"></textarea></section>
+<section class="pane"><h2 id="output-title"></h2><pre id="output"></pre></section>
+</div>
+<script>window.LABELS = {{LABELS}};</script>
+<script>
+(function () {
+  var L = window.LABELS;
+  var $ = function (id) { return document.getElementById(id); };
+  var editor = $('editor'), output = $('output'), select = $('module'), state = $('state');
+  var buttons = ['save', 'run', 'check', 'hint'];
+  var current = null, dirty = false;
+
+  buttons.forEach(function (name) { $(name).textContent = L[name]; });
+  $('reference-title').textContent = L.reference;
+  $('output-title').textContent = L.output;
+  output.textContent = L.ready + '\n' + L.keys;
+
+  // 修改和运行代码的请求都带上 X-Tutorial，服务端据此拒绝其他网站发来的请求
+  function api(method, path, body) {
+    return fetch('/api/' + path, { method: method, body: body, headers: { 'X-Tutorial': '1' } })
+      .then(function (response) {
+        return response.json().then(function (data) {
+          if (!response.ok) throw new Error(data.error);
+          return data;
+        });
+      });
+  }
+
+  function setDirty(value) {
+    dirty = value;
+    state.textContent = value ? L.unsaved : L.saved;
+  }
+
+  function show(text) {
+    output.textContent = text;
+    output.scrollTop = 0;
+  }
+
+  function fail(error) { show('❌ ' + error.message); }
+
+  function refreshProgress() {
+    return api('GET', 'progress').then(function (data) {
+      data.modules.forEach(function (module) {
+        var option = select.querySelector('option[value="' + module.id + '"]');
+        if (!option) return;
+        var done = module.total ? ' (' + module.done + '/' + module.total + ')' : '';
+        option.textContent = (module.complete ? '✅ ' : '') + module.label + done;
+      });
+    });
+  }
+
+  function open(id) {
+    return Promise.all([api('GET', 'modules/' + id + '/demo'), api('GET', 'modules/' + id + '/example')])
+      .then(function (results) {
+        current = id;
+        select.value = id;
+        location.hash = id;
+        editor.value = results[0].source;
+        $('editor-title').textContent = results[0].path;
+        $('reference').innerHTML = results[1].html;
+        setDirty(false);
+      })
+      .catch(fail);
+  }
+
+  function save() {
+    if (!dirty) return Promise.resolve();
+    return api('PUT', 'modules/' + current + '/demo', editor.value).then(function () { setDirty(false); });
+  }
+
+  // 运行、检查、提示之前先保存，服务端读取的是磁盘上的 demo.rs
+  function action(name, body) {
+    buttons.forEach(function (button) { $(button).disabled = true; });
+    show(L.busy);
+    return save()
+      .then(function () { return api('POST', 'modules/' + current + '/' + name, body); })
+      .then(function (data) { show(data.output); return refreshProgress(); })
+      .catch(fail)
+      .then(function () { buttons.forEach(function (button) { $(button).disabled = false; }); });
+  }
+
+  $('save').onclick = function () { save().catch(fail); };
+  $('run').onclick = function () { action('run'); };
+  $('check').onclick = function () { action('check'); };
+  $('hint').onclick = function () { action('hint', '{}'); };
+
+  select.onchange = function () {
+    if (dirty && !confirm(L.discard)) {
+      select.value = current;
+      return;
+    }
+    open(select.value);
+  };
+
+  editor.addEventListener('input', function () { if (!dirty) setDirty(true); });
+  editor.addEventListener('keydown', function (event) {
+    var modifier = event.ctrlKey || event.metaKey;
+    if (event.key === 'Tab' && !modifier) {
+      event.preventDefault();
+      var start = editor.selectionStart;
+      editor.setRangeText('    ', start, editor.selectionEnd, 'end');
+      setDirty(true);
+    } else if (modifier && event.key === 's') {
+      event.preventDefault();
+      save().catch(fail);
+    } else if (modifier && event.key === 'Enter') {
+      event.preventDefault();
+      action('run');
+    }
+  });
+  window.addEventListener('beforeunload', function (event) {
+    if (dirty) event.preventDefault();
+  });
+
+  api('GET', 'modules').then(function (modules) {
+    modules.forEach(function (module) {
+      var option = document.createElement('option');
+      option.value = module.id;
+      option.textContent = module.label;
+      select.appendChild(option);
+    });
+    var wanted = location.hash.slice(1);
+    var first = modules.some(function (module) { return module.id === wanted; }) ? wanted : modules[0].id;
+    return open(first).then(refreshProgress);
+  }).catch(fail);
+})();
+</script>
+</body>
+</html>
+"#;
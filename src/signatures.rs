@@ -0,0 +1,325 @@
+// 签名一致性检查
+// demo.rs 模板向学习者承诺了函数签名、泛型、派生和字段，隐藏测试也依赖它们；
+// 这里对比模板和 answer.md 中的参考答案，任何一处不一致都会被 validate 报告
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Fields, Generics, ImplItem, Item, Token};
+
+use crate::checker;
+use crate::i18n::{t, tr};
+
+// 一个条目的某个方面，例如 struct User 的「派生」
+struct Part {
+    aspect: &'static str,
+    // 用于比较：token 序列，不受空白和换行影响
+    key: String,
+    // 用于显示：源码原文（合并空白）
+    display: String,
+}
+
+struct Signature {
+    // 与 check 显示的名称一致（divide、Counter::next、struct User），impl 为 "impl Iterator for Counter"
+    name: String,
+    line: usize,
+    parts: Vec<Part>,
+}
+
+// 返回 demo.rs 与参考答案之间的所有不一致，每条是一段可以直接显示的说明
+pub fn compare(demo: &str, answer: &str) -> Result<Vec<String>, String> {
+    let demo_items = collect(demo).map_err(|e| t!("demo.rs 无法解析: {}", e))?;
+    let answer_items = collect(answer).map_err(|e| t!("参考答案无法解析: {}", e))?;
+
+    let mut problems = Vec::new();
+    for item in &demo_items {
+        let candidates: Vec<&Signature> = answer_items
+            .iter()
+            .filter(|other| other.name == item.name)
+            .collect();
+        if candidates.is_empty() {
+            problems.push(t!(
+                "{}（demo.rs 第 {} 行）在参考答案中不存在",
+                item.name,
+                item.line
+            ));
+            continue;
+        }
+
+        // 同名的 impl 块可能有多个，只要有一个完全一致即可
+        let mismatches: Vec<Vec<String>> = candidates
+            .iter()
+            .map(|other| differences(item, other))
+            .collect();
+        if mismatches.iter().any(|m| m.is_empty()) {
+            continue;
+        }
+        let closest = mismatches
+            .into_iter()
+            .min_by_key(|m| m.len())
+            .unwrap_or_default();
+        for difference in closest {
+            problems.push(t!(
+                "{}（demo.rs 第 {} 行）{}",
+                item.name,
+                item.line,
+                difference
+            ));
+        }
+    }
+
+    Ok(problems)
+}
+
+// 只比较模板中出现的方面：模板里留空的字段或变体由学习者补全，不要求一致
+fn differences(demo: &Signature, answer: &Signature) -> Vec<String> {
+    demo.parts
+        .iter()
+        .filter_map(|part| {
+            let other = answer
+                .parts
+                .iter()
+                .find(|other| other.aspect == part.aspect);
+            match other {
+                Some(other) if other.key == part.key => None,
+                Some(other) => Some(t!(
+                    "的{}不一致\n       demo.rs:   {}\n       answer.md: {}",
+                    part.aspect,
+                    part.display,
+                    other.display
+                )),
+                None => Some(t!(
+                    "的{}在参考答案中缺失\n       demo.rs:   {}",
+                    part.aspect,
+                    part.display
+                )),
+            }
+        })
+        .collect()
+}
+
+fn collect(source: &str) -> Result<Vec<Signature>, String> {
+    let file = syn::parse_file(source).map_err(|e| t!("第 {} 行: {}", e.span().start().line, e))?;
+    let text = |node: &dyn Spanned| source_text(source, node.span());
+    let mut items = Vec::new();
+
+    for item in &file.items {
+        let line = item.span().start().line;
+        match item {
+            Item::Fn(function) if function.sig.ident == "main" => {}
+            Item::Fn(function) => items.push(Signature {
+                name: function.sig.ident.to_string(),
+                line,
+                parts: vec![part(tr("签名"), &function.sig, text(&function.sig))],
+            }),
+            Item::Struct(definition) => {
+                let mut parts = vec![
+                    generics(&definition.generics, text(&definition.generics)),
+                    derives(&definition.attrs),
+                ];
+                let fields = match &definition.fields {
+                    Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+                    Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+                    Fields::Unit => Vec::new(),
+                };
+                if !fields.is_empty() {
+                    let key = fields
+                        .iter()
+                        .map(|field| {
+                            let ident = field.ident.to_token_stream();
+                            let ty = field.ty.to_token_stream();
+                            format!("{} : {}", ident, ty)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" , ");
+                    let display = fields
+                        .iter()
+                        .map(|field| text(*field))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    parts.push(Part {
+                        aspect: tr("字段"),
+                        key,
+                        display,
+                    });
+                }
+                items.push(Signature {
+                    name: format!("struct {}", definition.ident),
+                    line,
+                    parts,
+                });
+            }
+            Item::Enum(definition) => {
+                let mut parts = vec![
+                    generics(&definition.generics, text(&definition.generics)),
+                    derives(&definition.attrs),
+                ];
+                if !definition.variants.is_empty() {
+                    let key = definition
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            let mut variant = variant.clone();
+                            variant.attrs.clear();
+                            variant.to_token_stream().to_string()
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" , ");
+                    let display = definition
+                        .variants
+                        .iter()
+                        .map(|variant| text(variant))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    parts.push(Part {
+                        aspect: tr("变体"),
+                        key,
+                        display,
+                    });
+                }
+                items.push(Signature {
+                    name: format!("enum {}", definition.ident),
+                    line,
+                    parts,
+                });
+            }
+            Item::Impl(block) => {
+                let owner = checker::type_name(&block.self_ty);
+                // trait 只看最后一段，fmt::Display 与 std::fmt::Display 视为相同
+                let name = match &block.trait_ {
+                    Some((path, _)) => {
+                        let last = path.segments.last().map(|s| s.to_token_stream());
+                        format!("impl {} for {}", quote::quote!(#last), owner)
+                    }
+                    None => format!("impl {}", owner),
+                };
+                let header = format!(
+                    "{} {} {}",
+                    block.generics.to_token_stream(),
+                    block.self_ty.to_token_stream(),
+                    block.generics.where_clause.to_token_stream()
+                );
+                // impl 头部通常只有一行，显示到 { 为止
+                let display = source
+                    .lines()
+                    .nth(line - 1)
+                    .and_then(|text| text.split('{').next())
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string();
+                items.push(Signature {
+                    name,
+                    line,
+                    parts: vec![Part {
+                        aspect: tr("泛型"),
+                        key: header,
+                        display,
+                    }],
+                });
+
+                for impl_item in &block.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        items.push(Signature {
+                            name: format!("{}::{}", owner, method.sig.ident),
+                            line: method.span().start().line,
+                            parts: vec![part(tr("签名"), &method.sig, text(&method.sig))],
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(items)
+}
+
+fn part(aspect: &'static str, node: &dyn ToTokens, display: String) -> Part {
+    Part {
+        aspect,
+        key: node.to_token_stream().to_string(),
+        display,
+    }
+}
+
+// 泛型参数加上 where 子句
+fn generics(generics: &Generics, display: String) -> Part {
+    let key = format!(
+        "{} {}",
+        generics.to_token_stream(),
+        generics.where_clause.to_token_stream()
+    );
+    let display = match (&generics.where_clause, display.is_empty()) {
+        (_, true) if generics.params.is_empty() => tr("（没有泛型参数）").to_string(),
+        (Some(clause), _) => format!("{} {}", display, clause.to_token_stream()),
+        (None, _) => display,
+    };
+    Part {
+        aspect: tr("泛型"),
+        key,
+        display,
+    }
+}
+
+// #[derive(...)] 中的名称，顺序无关
+fn derives(attrs: &[Attribute]) -> Part {
+    let mut names: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .map(|path| {
+            path.segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+    names.sort();
+
+    let display = if names.is_empty() {
+        tr("（没有 derive）").to_string()
+    } else {
+        format!("#[derive({})]", names.join(", "))
+    };
+    Part {
+        aspect: tr("派生"),
+        key: names.join(","),
+        display,
+    }
+}
+
+// span 对应的源码原文，多行时合并为一行
+pub fn source_text(source: &str, span: Span) -> String {
+    let (start, end) = (span.start(), span.end());
+    let lines: Vec<&str> = source.lines().collect();
+    let mut text = String::new();
+
+    for line_number in start.line..=end.line {
+        let Some(line) = lines.get(line_number - 1) else {
+            break;
+        };
+        let chars: Vec<char> = line.chars().collect();
+        let from = if line_number == start.line {
+            start.column
+        } else {
+            0
+        };
+        let to = if line_number == end.line {
+            end.column.min(chars.len())
+        } else {
+            chars.len()
+        };
+        let piece: String = chars[from.min(to)..to].iter().collect();
+        // 去掉行尾注释，避免把模板里的 TODO 当成签名的一部分
+        let piece = piece.split("//").next().unwrap_or_default();
+        text.push_str(piece);
+        text.push(' ');
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
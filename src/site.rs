@@ -0,0 +1,393 @@
+// 静态网站
+// build-site 把每个模块的讲解（answer.md）、example.ts 和 demo.rs 的原始模板生成为 HTML，每个模块一页，
+// 侧边栏按课程顺序导航，TypeScript / Rust 用标签页切换，另有站内搜索；
+// 样式、脚本和搜索索引都写进输出目录，不引用任何外部资源，离线直接打开 index.html 也能浏览。
+// 界面文字跟随 --lang，选择英文时讲解使用 answer.en.md
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::course::{Course, Lesson};
+use crate::highlight::Language;
+use crate::i18n::{self, t, tr, Lang};
+use crate::markdown::{self, escape};
+use crate::reset;
+
+const STYLE: &str = r#"* { box-sizing: border-box; }
+body { margin: 0; display: flex; min-height: 100vh; font: 16px/1.7 -apple-system, "Segoe UI", "PingFang SC", "Microsoft YaHei", sans-serif; color: #1f2328; background: #fff; }
+a { color: #0969da; text-decoration: none; }
+a:hover { text-decoration: underline; }
+.sidebar { width: 280px; flex-shrink: 0; padding: 20px; border-right: 1px solid #d0d7de; background: #f6f8fa; position: sticky; top: 0; height: 100vh; overflow-y: auto; }
+.brand { display: block; font-weight: 600; font-size: 18px; color: #1f2328; margin-bottom: 16px; }
+#search { width: 100%; padding: 6px 10px; border: 1px solid #d0d7de; border-radius: 6px; font: inherit; }
+#results { list-style: none; margin: 8px 0; padding: 0; }
+#results li { padding: 6px 0; border-bottom: 1px solid #d0d7de; font-size: 14px; }
+#results small { display: block; color: #59636e; }
+.sidebar h2 { border: none; font-size: 13px; color: #59636e; text-transform: uppercase; margin: 20px 0 8px; }
+.sidebar ol { margin: 0; padding-left: 22px; }
+.sidebar li { margin: 4px 0; }
+.sidebar li.current > a { font-weight: 600; color: #1f2328; }
+main { flex: 1; min-width: 0; max-width: 960px; padding: 24px 40px 60px; }
+.meta { color: #59636e; font-size: 14px; }
+h1 { border-bottom: 1px solid #d0d7de; padding-bottom: 8px; }
+h2 { border-bottom: 1px solid #d0d7de; padding-bottom: 4px; margin-top: 36px; }
+pre.code { background: #f6f8fa; border: 1px solid #d0d7de; border-radius: 6px; padding: 12px 16px; overflow-x: auto; font: 14px/1.5 ui-monospace, Menlo, Consolas, monospace; }
+code { font-family: ui-monospace, Menlo, Consolas, monospace; background: #eff1f3; padding: 1px 4px; border-radius: 4px; font-size: 90%; }
+pre code { background: none; padding: 0; font-size: 100%; }
+blockquote { margin: 0; padding: 0 16px; border-left: 4px solid #d0d7de; color: #59636e; }
+table { border-collapse: collapse; margin: 12px 0; }
+th, td { border: 1px solid #d0d7de; padding: 6px 12px; }
+th { background: #f6f8fa; }
+li.nested { margin-left: 24px; }
+.kw { color: #cf222e; } .ty { color: #8250df; } .st { color: #0a3069; } .nu { color: #0550ae; } .co { color: #6e7781; font-style: italic; } .ma { color: #953800; }
+.tabs > input { display: none; }
+.tabs > label { display: inline-block; padding: 8px 16px; border: 1px solid transparent; border-bottom: none; border-radius: 6px 6px 0 0; cursor: pointer; color: #59636e; }
+.tabs > input:checked + label { border-color: #d0d7de; background: #fff; color: #1f2328; font-weight: 600; margin-bottom: -1px; }
+.panel { display: none; border-top: 1px solid #d0d7de; padding-top: 8px; }
+#tab-answer:checked ~ #panel-answer, #tab-ts:checked ~ #panel-ts, #tab-rs:checked ~ #panel-rs { display: block; }
+.pager { display: flex; justify-content: space-between; margin-top: 48px; padding-top: 16px; border-top: 1px solid #d0d7de; }
+.lessons { list-style: none; padding: 0; }
+.lessons li { border: 1px solid #d0d7de; border-radius: 6px; padding: 12px 16px; margin: 12px 0; }
+@media (max-width: 800px) { body { display: block; } .sidebar { width: auto; height: auto; position: static; } main { padding: 16px; } }
+"#;
+
+// 搜索：索引来自 search-index.js（window.SEARCH_INDEX），用 <script> 引入，file:// 打开时也能用；
+// 标签页：地址带 #example / #demo 时打开对应的标签
+const SCRIPT: &str = r#"(function () {
+  var input = document.getElementById('search');
+  var results = document.getElementById('results');
+  var index = window.SEARCH_INDEX || [];
+
+  function excerpt(text, at, length) {
+    var start = Math.max(0, at - 30);
+    return (start > 0 ? '…' : '') + text.substr(start, length + 60) + '…';
+  }
+
+  input.addEventListener('input', function () {
+    var query = input.value.trim().toLowerCase();
+    results.innerHTML = '';
+    results.hidden = !query;
+    if (!query) return;
+    var hits = 0;
+    for (var i = 0; i < index.length && hits < 20; i++) {
+      var entry = index[i];
+      var at = (entry.title + ' ' + entry.text).toLowerCase().indexOf(query);
+      if (at < 0) continue;
+      hits++;
+      var item = document.createElement('li');
+      var link = document.createElement('a');
+      link.href = entry.url;
+      link.textContent = entry.lesson + ' · ' + entry.title;
+      var detail = document.createElement('small');
+      detail.textContent = excerpt(entry.text, Math.max(0, at - entry.title.length - 1), query.length);
+      item.appendChild(link);
+      item.appendChild(detail);
+      results.appendChild(item);
+    }
+    if (!hits) {
+      var empty = document.createElement('li');
+      empty.textContent = input.getAttribute('data-empty');
+      results.appendChild(empty);
+    }
+  });
+
+  var tabs = { '#example': 'tab-ts', '#demo': 'tab-rs' };
+  var tab = document.getElementById(tabs[location.hash] || '');
+  if (tab) tab.checked = true;
+})();
+"#;
+
+// 搜索索引（search-index.json，以及供 file:// 页面引入的 assets/search-index.js）中的一项：
+// 讲解中的一个小节，或者整个模块
+#[derive(Serialize)]
+struct Entry {
+    url: String,
+    lesson: String,
+    title: String,
+    text: String,
+}
+
+pub fn build_site(course: &Course, out: Option<&str>) -> Result<(), String> {
+    let out = out
+        .map(PathBuf::from)
+        .unwrap_or_else(|| course.root.join("site"));
+    let assets = out.join("assets");
+    fs::create_dir_all(&assets).map_err(|e| t!("无法创建目录 {}: {}", assets.display(), e))?;
+
+    let mut index = Vec::new();
+    for (position, lesson) in course.lessons.iter().enumerate() {
+        let answer_path = lesson.localized_answer_path();
+        let answer = fs::read_to_string(&answer_path)
+            .map_err(|e| t!("无法读取 {}: {}", answer_path.display(), e))?;
+        let example_path = lesson.example_path();
+        let example = fs::read_to_string(&example_path)
+            .map_err(|e| t!("无法读取 {}: {}", example_path.display(), e))?;
+        // 学习者可能已经改过 demo.rs，网站上展示 templates/ 中的原始模板
+        let demo = reset::pristine(course, lesson)
+            .ok_or_else(|| t!("{0}: 缺少原始模板 templates/{0}/demo.rs", lesson.id))?;
+
+        let body = lesson_page(course, position, &answer, &example, &demo);
+        write(
+            &out.join(page_name(lesson)),
+            &page(course, Some(lesson), &body),
+        )?;
+        index.extend(entries(lesson, &answer));
+    }
+    write(&out.join("index.html"), &page(course, None, &home(course)))?;
+
+    let json = serde_json::to_string(&index).map_err(|e| t!("无法生成搜索索引: {}", e))?;
+    write(&out.join("search-index.json"), &json)?;
+    write(
+        &assets.join("search-index.js"),
+        &format!("window.SEARCH_INDEX = {};\n", json),
+    )?;
+    write(&assets.join("style.css"), STYLE)?;
+    write(&assets.join("site.js"), SCRIPT)?;
+
+    println!(
+        "{}",
+        t!(
+            "🌐 已生成 {} 个页面（{} 个模块 + 首页）: {}",
+            course.lessons.len() + 1,
+            course.lessons.len(),
+            out.display()
+        )
+    );
+    println!(
+        "{}",
+        t!(
+            "   用浏览器打开 {} 即可离线浏览",
+            out.join("index.html").display()
+        )
+    );
+    Ok(())
+}
+
+fn page_name(lesson: &Lesson) -> String {
+    format!("{}.html", lesson.id)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| t!("无法写入 {}: {}", path.display(), e))
+}
+
+// 所有页面共用的外框：侧边栏（搜索 + 课程目录）和正文
+fn page(course: &Course, current: Option<&Lesson>, body: &str) -> String {
+    let site_title = tr("Rust 教程 · 面向 TypeScript 开发者");
+    let title = match current {
+        Some(lesson) => format!("{} · {}", lesson.display_title(), site_title),
+        None => site_title.to_string(),
+    };
+    let mut nav = String::new();
+    for (position, lesson) in course.lessons.iter().enumerate() {
+        let class = if current.is_some_and(|current| current.id == lesson.id) {
+            " class=\"current\""
+        } else {
+            ""
+        };
+        nav.push_str(&format!(
+            "<li{}><a href=\"{}\">{}. {}</a></li>\n",
+            class,
+            page_name(lesson),
+            position + 1,
+            escape(&lesson.label())
+        ));
+    }
+    let lang = match i18n::current() {
+        Lang::Zh => "zh-CN",
+        Lang::En => "en",
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<link rel="stylesheet" href="assets/style.css">
+</head>
+<body>
+<aside class="sidebar">
+<a class="brand" href="index.html">🦀 {site_title}</a>
+<input id="search" type="search" placeholder="{placeholder}" data-empty="{empty}">
+<ul id="results" hidden></ul>
+<h2>{contents}</h2>
+<ol>
+{nav}</ol>
+</aside>
+<main>
+{body}</main>
+<script src="assets/search-index.js"></script>
+<script src="assets/site.js"></script>
+</body>
+</html>
+"#,
+        title = escape(&title),
+        site_title = escape(site_title),
+        placeholder = escape(tr("搜索课程内容…")),
+        empty = escape(tr("没有找到匹配的内容")),
+        contents = escape(tr("课程目录")),
+    )
+}
+
+fn home(course: &Course) -> String {
+    let mut body = format!(
+        "<h1>🦀 {}</h1>\n<p>{}</p>\n<p class=\"meta\">{}</p>\n<ol class=\"lessons\">\n",
+        escape(tr("Rust 教程 · 面向 TypeScript 开发者")),
+        escape(tr(
+            "通过和 TypeScript 对比学习 Rust：每个模块都有 TypeScript 示例、Rust 练习模板和完整讲解。"
+        )),
+        escape(tr(
+            "本网站由 cargo run -- build-site 生成；练习请在本地完成，用 cargo run -- check <module> 检查。"
+        )),
+    );
+    for (position, lesson) in course.lessons.iter().enumerate() {
+        body.push_str(&format!(
+            "<li><a href=\"{}\"><strong>{}. {}</strong></a><div class=\"meta\">{}</div></li>\n",
+            page_name(lesson),
+            position + 1,
+            escape(&lesson.label()),
+            lesson_meta(course, position)
+        ));
+    }
+    body.push_str("</ol>\n");
+    body
+}
+
+// 模块序号、难度和先修模块
+fn lesson_meta(course: &Course, position: usize) -> String {
+    let lesson = &course.lessons[position];
+    let mut meta = vec![escape(&t!(
+        "模块 {} / {}",
+        position + 1,
+        course.lessons.len()
+    ))];
+    if let Some(difficulty) = lesson.difficulty {
+        meta.push(escape(difficulty.label()));
+    }
+    if !lesson.prerequisites.is_empty() {
+        let links: Vec<String> = lesson
+            .prerequisites
+            .iter()
+            .map(
+                |id| match course.lessons.iter().find(|other| &other.id == id) {
+                    Some(other) => format!(
+                        "<a href=\"{}\">{}</a>",
+                        page_name(other),
+                        escape(other.display_title())
+                    ),
+                    None => escape(id),
+                },
+            )
+            .collect();
+        meta.push(format!("{}{}", escape(tr("先修: ")), links.join(", ")));
+    }
+    meta.join(" · ")
+}
+
+fn lesson_page(
+    course: &Course,
+    position: usize,
+    answer: &str,
+    example: &str,
+    demo: &str,
+) -> String {
+    let lesson = &course.lessons[position];
+    let mut body = format!("<p class=\"meta\">{}</p>\n", lesson_meta(course, position));
+
+    body.push_str("<div class=\"tabs\">\n");
+    for (id, label, checked) in [
+        ("tab-answer", tr("📖 讲解"), true),
+        ("tab-ts", "TypeScript · example.ts", false),
+        ("tab-rs", tr("Rust · demo.rs 练习模板"), false),
+    ] {
+        body.push_str(&format!(
+            "<input type=\"radio\" name=\"tab\" id=\"{0}\"{1}><label for=\"{0}\">{2}</label>\n",
+            id,
+            if checked { " checked" } else { "" },
+            escape(label)
+        ));
+    }
+    body.push_str(&format!(
+        "<section class=\"panel\" id=\"panel-answer\">\n{}</section>\n",
+        markdown::to_html(answer)
+    ));
+    body.push_str(&format!(
+        "<section class=\"panel\" id=\"panel-ts\">\n<pre class=\"code\"><code>{}</code></pre>\n</section>\n",
+        markdown::highlight_html(example, Some(Language::TypeScript))
+    ));
+    body.push_str(&format!(
+        "<section class=\"panel\" id=\"panel-rs\">\n<p class=\"meta\">{}</p>\n<pre class=\"code\"><code>{}</code></pre>\n</section>\n",
+        escape(&t!(
+            "在本地的 {} 中完成练习，然后运行 cargo run -- check {} 检查",
+            format!("basic/{}/demo.rs", lesson.id),
+            lesson.id
+        )),
+        markdown::highlight_html(demo, Some(Language::Rust))
+    ));
+    body.push_str("</div>\n");
+
+    // 上一课 / 下一课
+    body.push_str("<nav class=\"pager\">");
+    match position
+        .checked_sub(1)
+        .map(|previous| &course.lessons[previous])
+    {
+        Some(previous) => body.push_str(&format!(
+            "<a href=\"{}\">{}</a>",
+            page_name(previous),
+            escape(&t!("⬅️ 上一课: {}", previous.display_title()))
+        )),
+        None => body.push_str("<span></span>"),
+    }
+    if let Some(next) = course.lessons.get(position + 1) {
+        body.push_str(&format!(
+            "<a href=\"{}\">{}</a>",
+            page_name(next),
+            escape(&t!("下一课: {} ➡️", next.display_title()))
+        ));
+    }
+    body.push_str("</nav>\n");
+    body
+}
+
+// 每个模块一项，讲解中的每个二级标题（小节）一项，链接到 id="section-N"
+fn entries(lesson: &Lesson, answer: &str) -> Vec<Entry> {
+    let page = page_name(lesson);
+    let mut entries = vec![Entry {
+        url: page.clone(),
+        lesson: lesson.display_title().to_string(),
+        title: lesson.label(),
+        text: String::new(),
+    }];
+    let mut in_code = false;
+
+    for line in answer.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        match line.strip_prefix("## ") {
+            Some(title) if !in_code => entries.push(Entry {
+                url: format!("{}#section-{}", page, entries.len()),
+                lesson: lesson.display_title().to_string(),
+                title: title.trim().to_string(),
+                text: String::new(),
+            }),
+            _ => {
+                let line = line.trim().trim_start_matches('#').trim_start();
+                // 第一个小节之前的内容归到模块本身
+                if let (Some(entry), false) = (entries.last_mut(), line.is_empty()) {
+                    if !entry.text.is_empty() {
+                        entry.text.push(' ');
+                    }
+                    entry.text.push_str(line);
+                }
+            }
+        }
+    }
+    entries
+}
@@ -0,0 +1,196 @@
+// 参考答案提取
+// answer.md 的 "## Rust 完整实现" 小节里是完整的 Rust 程序，
+// 把其中的 rust 代码块拼成独立的 solution.rs 并编译，确保答案本身可以运行
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::TokenStream;
+
+use crate::answer::{self, Answer, Snippet};
+use crate::course::{Course, Lesson};
+use crate::diff;
+use crate::i18n::{t, tr};
+use crate::runner::{self, BuildDir, CompileOutput};
+
+const SOLUTION_SECTION: &str = "## Rust 完整实现";
+
+// 拼装参考答案源码
+pub fn assemble(lesson: &Lesson) -> Result<String, String> {
+    let answer = Answer::load(lesson)?;
+
+    let mut in_section = false;
+    let mut section = String::new();
+    for line in answer.content.lines() {
+        if line.starts_with("## ") {
+            in_section = line.trim() == SOLUTION_SECTION;
+            continue;
+        }
+        if in_section {
+            section.push_str(line);
+            section.push('\n');
+        }
+    }
+
+    let blocks: Vec<String> = Answer { content: section }
+        .code_blocks()
+        .into_iter()
+        .filter(|block| block.lang == "rust")
+        .map(|block| block.code)
+        .collect();
+
+    if blocks.is_empty() {
+        return Err(t!(
+            "{} 的 answer.md 中没有找到「{}」小节的 rust 代码块",
+            lesson.id,
+            SOLUTION_SECTION
+        ));
+    }
+
+    Ok(t!(
+        "// 由 basic/{}/answer.md 自动生成的参考答案\n\n{}",
+        lesson.id,
+        blocks.join("\n")
+    ))
+}
+
+// 把参考答案写入构建目录并编译
+pub fn build(lesson: &Lesson, build_dir: &Path) -> Result<(PathBuf, CompileOutput), String> {
+    let source = assemble(lesson)?;
+    let path = build_dir.join("solution.rs");
+    fs::write(&path, source).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+
+    let compiled = runner::compile(&path, build_dir)?;
+    Ok((path, compiled))
+}
+
+pub fn solution(
+    course: &Course,
+    module: &str,
+    diff: bool,
+    name: Option<&str>,
+) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let build_dir = BuildDir::new(&lesson.id)?;
+    let (path, compiled) = build(lesson, build_dir.path())?;
+
+    if !compiled.success {
+        eprint!("{}", compiled.rendered());
+        return Err(t!(
+            "{} 的参考答案无法编译（{} 个错误），请检查 answer.md",
+            lesson.id,
+            compiled.errors()
+        ));
+    }
+
+    let source = fs::read_to_string(&path).map_err(|e| t!("无法读取 {}: {}", path.display(), e))?;
+
+    if diff {
+        return diff_functions(lesson, &source, name);
+    }
+
+    let shown = match name {
+        Some(name) => {
+            let snippets = answer::snippets(&source)?;
+            let snippet =
+                find_snippet(&snippets, name).ok_or_else(|| t!("参考答案中没有 '{}'", name))?;
+            snippet.source.clone()
+        }
+        None => source,
+    };
+
+    println!(
+        "{}",
+        t!(
+            "📗 {} 参考答案（编译通过，{} 个警告，{}）",
+            lesson.id,
+            compiled.warnings(),
+            runner::format_duration(compiled.duration)
+        )
+    );
+    println!();
+    print!("{}", shown);
+    Ok(())
+}
+
+// 逐个函数/类型对比学习者的 demo.rs 和参考答案，忽略注释和空白的差异
+fn diff_functions(lesson: &Lesson, reference: &str, only: Option<&str>) -> Result<(), String> {
+    let demo_path = lesson.demo_path();
+    let learner = fs::read_to_string(&demo_path)
+        .map_err(|e| t!("无法读取 {}: {}", demo_path.display(), e))?;
+
+    let reference = named(answer::snippets(reference)?);
+    let learner = named(answer::snippets(&learner).map_err(|e| t!("demo.rs 无法解析: {}", e))?);
+
+    let mut names: Vec<&str> = reference.iter().map(|(name, _)| name.as_str()).collect();
+    for (name, _) in &learner {
+        if !names.contains(&name.as_str()) {
+            names.push(name);
+        }
+    }
+    if let Some(only) = only {
+        names.retain(|name| *name == only);
+        if names.is_empty() {
+            return Err(t!("demo.rs 和参考答案中都没有 '{}'", only));
+        }
+    }
+
+    println!("{}", t!("🔀 {} 的 demo.rs 与参考答案逐项对比", lesson.id));
+
+    let mut identical = 0;
+    for name in &names {
+        let ours = learner
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, s)| s);
+        let theirs = reference
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, s)| s);
+
+        println!();
+        match (ours, theirs) {
+            (None, Some(_)) => println!("{}", t!("➕ {}: 参考答案中有，demo.rs 中还没有", name)),
+            (Some(_), None) => println!("{}", t!("➖ {}: 只在 demo.rs 中出现", name)),
+            (Some(ours), Some(theirs)) if same_tokens(&ours.source, &theirs.source) => {
+                identical += 1;
+                println!("{}", t!("✅ {}: 与参考答案一致", name));
+            }
+            (Some(ours), Some(theirs)) => {
+                println!("{}", t!("✏️  {}: 与参考答案不同", name));
+                let changes = diff::lines(&ours.source, &theirs.source);
+                diff::print(&changes, "demo.rs", tr("参考答案"));
+            }
+            (None, None) => {}
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        t!("📊 {}/{} 项与参考答案一致", identical, names.len())
+    );
+    Ok(())
+}
+
+fn named(snippets: Vec<Snippet>) -> Vec<(String, Snippet)> {
+    snippets
+        .into_iter()
+        .filter_map(|snippet| Some((snippet.name.clone()?, snippet)))
+        .filter(|(name, _)| !name.starts_with("练习"))
+        .collect()
+}
+
+fn find_snippet<'a>(snippets: &'a [Snippet], name: &str) -> Option<&'a Snippet> {
+    snippets
+        .iter()
+        .find(|snippet| snippet.name.as_deref() == Some(name))
+}
+
+// 词法层面比较：注释、空行和缩进都不影响结果
+fn same_tokens(a: &str, b: &str) -> bool {
+    match (a.parse::<TokenStream>(), b.parse::<TokenStream>()) {
+        (Ok(a), Ok(b)) => a.to_string() == b.to_string(),
+        _ => a == b,
+    }
+}
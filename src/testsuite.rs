@@ -0,0 +1,593 @@
+// 隐藏测试
+// basic/<id>/tests.rs 按被测函数分成子模块（mod check_age { ... }），
+// 与学习者的 demo.rs 拼成一个文件，用 rustc --test 编译后逐个函数报告结果。
+// demo.rs 中还有编译错误的函数会把函数体换成 todo!() 再编译，不影响其他函数的测试；
+// 测试本身编译不过（签名或类型定义与课程不一致）时跳过对应的子模块。
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+
+use proc_macro2::LineColumn;
+use serde::Serialize;
+use syn::spanned::Spanned;
+use syn::{ImplItem, Item};
+
+use crate::checker;
+use crate::course::Lesson;
+use crate::diagnostics::Diagnostic;
+use crate::i18n::{t, tr};
+use crate::runner::{self, BuildDir};
+
+// 替换函数体时使用的 panic 信息，用来区分"没写完"和"有编译错误"
+const BROKEN_MARKER: &str = "还有编译错误，测试时已替换为 todo!()";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Passed,
+    Failed,
+    Unimplemented,
+    Broken,
+}
+
+impl Verdict {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Verdict::Passed => "✅",
+            Verdict::Failed => "❌",
+            Verdict::Unimplemented => "⬜",
+            Verdict::Broken => "🧱",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Verdict::Passed => tr("通过"),
+            Verdict::Failed => tr("未通过"),
+            Verdict::Unimplemented => tr("未实现"),
+            Verdict::Broken => tr("无法编译"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TestCase {
+    pub name: String,
+    pub passed: bool,
+    // panic 信息（断言失败的说明和左右两边的值）
+    pub message: Option<String>,
+}
+
+// 一个被测函数的全部测试
+#[derive(Debug)]
+pub struct Group {
+    pub name: String,
+    pub cases: Vec<TestCase>,
+    // 测试代码无法与 demo.rs 一起编译时的第一条错误
+    pub compile_error: Option<String>,
+}
+
+impl Group {
+    pub fn passed(&self) -> usize {
+        self.cases.iter().filter(|case| case.passed).count()
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        let messages = || self.cases.iter().filter_map(|case| case.message.as_deref());
+        if self.compile_error.is_some() || messages().any(|m| m.contains(BROKEN_MARKER)) {
+            Verdict::Broken
+        } else if self.cases.iter().all(|case| case.passed) {
+            Verdict::Passed
+        } else if messages().any(|m| m.starts_with("not yet implemented")) {
+            Verdict::Unimplemented
+        } else {
+            Verdict::Failed
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SuiteReport {
+    pub groups: Vec<Group>,
+    // demo.rs 中有无法隔离的编译错误（例如类型定义有误）时，整个测试无法运行
+    pub blocked: Vec<Diagnostic>,
+}
+
+impl SuiteReport {
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.groups
+            .iter()
+            .filter(|group| group.verdict() == verdict)
+            .count()
+    }
+
+    pub fn is_passing(&self) -> bool {
+        self.blocked.is_empty() && self.count(Verdict::Passed) == self.groups.len()
+    }
+}
+
+// demo.rs 中的函数（包括 impl 中的方法）
+struct Function {
+    name: String,
+    start_line: usize,
+    end_line: usize,
+    // 函数体 { ... } 在源码中的字节范围
+    body: (usize, usize),
+    // 还没开始写（只有 TODO 注释或占位代码）
+    empty: bool,
+}
+
+// tests.rs 中的子模块
+struct Module {
+    name: String,
+    start_line: usize,
+    end_line: usize,
+}
+
+// 模块没有 tests.rs 时返回 None
+pub fn run(lesson: &Lesson) -> Result<Option<SuiteReport>, String> {
+    let tests_path = lesson.tests_path();
+    if !tests_path.exists() {
+        return Ok(None);
+    }
+
+    let demo_path = lesson.demo_path();
+    let source = fs::read_to_string(&demo_path)
+        .map_err(|e| t!("无法读取 {}: {}", demo_path.display(), e))?;
+    let tests = fs::read_to_string(&tests_path)
+        .map_err(|e| t!("无法读取 {}: {}", tests_path.display(), e))?;
+
+    let demo_file =
+        syn::parse_file(&source).map_err(|e| t!("demo.rs 有语法错误，无法运行测试: {}", e))?;
+    let tests_file =
+        syn::parse_file(&tests).map_err(|e| t!("{} 有语法错误: {}", tests_path.display(), e))?;
+
+    let functions = functions(&demo_file, &source);
+    let modules = modules(&tests_file);
+
+    // main 不参与测试，它的练习代码有错误也不影响
+    let mut stubbed: BTreeSet<usize> = functions
+        .iter()
+        .position(|function| function.name == "main")
+        .into_iter()
+        .collect();
+    let mut skipped: Vec<(String, String)> = Vec::new();
+
+    let build_dir = BuildDir::new(&format!("{}-tests", lesson.id))?;
+    let harness_path = build_dir.path().join("tests.rs");
+
+    let compiled = loop {
+        let prefix = format!(
+            "{}\n// ---- {} ----\n",
+            stub_functions(&source, &functions, &stubbed),
+            tests_path.display()
+        );
+        // 测试代码从这一行之后开始；demo.rs 末尾不一定有换行，所以按实际写入的内容计算
+        let offset = prefix.bytes().filter(|&byte| byte == b'\n').count();
+        let harness = prefix + &skip_modules(&tests, &modules, &skipped);
+        fs::write(&harness_path, harness)
+            .map_err(|e| t!("无法写入 {}: {}", harness_path.display(), e))?;
+
+        let compiled = runner::compile_tests(&harness_path, build_dir.path())?;
+        if compiled.success {
+            break compiled;
+        }
+
+        let mut progress = false;
+        for error in compiled.diagnostics.iter().filter(|d| d.is_error()) {
+            let Some(line) = error.line else { continue };
+
+            if line > offset {
+                let Some(module) = modules.iter().find(|m| m.contains(line - offset)) else {
+                    continue;
+                };
+                if !skipped.iter().any(|(name, _)| *name == module.name) {
+                    skipped.push((module.name.clone(), summary_at(error, line - offset)));
+                    progress = true;
+                }
+            } else if let Some(index) = innermost(&functions, line) {
+                progress |= stubbed.insert(index);
+            }
+        }
+
+        if !progress {
+            let blocked = compiled
+                .diagnostics
+                .into_iter()
+                .filter(|d| d.is_error() && d.line.is_some_and(|line| line <= offset))
+                .collect();
+            return Ok(Some(SuiteReport {
+                groups: Vec::new(),
+                blocked,
+            }));
+        }
+    };
+
+    let output = runner::execute(&compiled.binary, &[], false)?;
+    let mut groups = match output.limit {
+        None => parse_results(&output.stdout),
+        // 某个测试卡住时整个测试程序都会被终止，失败信息也来不及输出；
+        // 这时按函数逐个重新运行，只有真正卡住的那个记为被终止
+        Some(_) => {
+            let mut groups = Vec::new();
+            for module in &modules {
+                if !skipped.iter().any(|(name, _)| *name == module.name) {
+                    groups.push(run_group(&compiled.binary, &module.name)?);
+                }
+            }
+            groups
+        }
+    };
+
+    // 按 tests.rs 中的顺序排列，跳过的子模块也要列出来
+    let mut ordered = Vec::new();
+    for module in &modules {
+        if let Some((_, error)) = skipped.iter().find(|(name, _)| *name == module.name) {
+            ordered.push(Group {
+                name: module.name.clone(),
+                cases: Vec::new(),
+                compile_error: Some(error.clone()),
+            });
+        } else if let Some(index) = groups.iter().position(|g| g.name == module.name) {
+            ordered.push(groups.remove(index));
+        }
+    }
+    ordered.extend(groups);
+
+    Ok(Some(SuiteReport {
+        groups: ordered,
+        blocked: Vec::new(),
+    }))
+}
+
+pub fn print(report: &SuiteReport) {
+    println!("{}", tr("🧪 隐藏测试:"));
+
+    if !report.blocked.is_empty() {
+        println!(
+            "{}",
+            tr("  🧱 demo.rs 中有函数以外的编译错误（例如类型定义），测试无法运行:")
+        );
+        for error in report.blocked.iter().take(3) {
+            println!("     {}", error.summary());
+        }
+        return;
+    }
+
+    let color = io::stdout().is_terminal();
+    for group in &report.groups {
+        let verdict = group.verdict();
+        if let Some(error) = &group.compile_error {
+            println!("  {} {} ({})", verdict.icon(), group.name, verdict.label());
+            println!(
+                "{}",
+                t!("     测试无法编译，请检查函数签名和类型定义: {}", error)
+            );
+            continue;
+        }
+        println!(
+            "  {} {} ({}/{} {})",
+            verdict.icon(),
+            group.name,
+            group.passed(),
+            group.cases.len(),
+            verdict.label()
+        );
+
+        if verdict != Verdict::Failed {
+            continue;
+        }
+        for case in group.cases.iter().filter(|case| !case.passed) {
+            println!("     ✗ {}", case.name);
+            for line in case.message.as_deref().unwrap_or("").lines() {
+                if color {
+                    println!("       \x1b[2m{}\x1b[0m", line);
+                } else {
+                    println!("       {}", line);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        t!(
+            "  📊 {}/{} 个函数通过测试，未通过 {}，未实现 {}，无法编译 {}",
+            report.count(Verdict::Passed),
+            report.groups.len(),
+            report.count(Verdict::Failed),
+            report.count(Verdict::Unimplemented),
+            report.count(Verdict::Broken)
+        )
+    );
+}
+
+impl Module {
+    fn contains(&self, line: usize) -> bool {
+        self.start_line <= line && line <= self.end_line
+    }
+}
+
+// 测试代码中的行号换算回 tests.rs 后的摘要
+fn summary_at(error: &Diagnostic, line: usize) -> String {
+    let code = error
+        .code
+        .as_deref()
+        .map(|code| format!("{} ", code))
+        .unwrap_or_default();
+    t!("{}tests.rs 第 {} 行: {}", code, line, error.message)
+}
+
+fn functions(file: &syn::File, source: &str) -> Vec<Function> {
+    let starts = line_starts(source);
+    let function = |name: String, span: proc_macro2::Span, block: &syn::Block| Function {
+        name,
+        start_line: span.start().line,
+        end_line: span.end().line,
+        body: (
+            offset(source, &starts, block.span().start()),
+            offset(source, &starts, block.span().end()),
+        ),
+        empty: checker::is_scaffold(&block.stmts),
+    };
+
+    let mut functions = Vec::new();
+    for item in &file.items {
+        match item {
+            Item::Fn(item_fn) => functions.push(function(
+                item_fn.sig.ident.to_string(),
+                item_fn.span(),
+                &item_fn.block,
+            )),
+            Item::Impl(block) => {
+                let owner = checker::type_name(&block.self_ty);
+                for impl_item in &block.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        functions.push(function(
+                            format!("{}::{}", owner, method.sig.ident),
+                            method.span(),
+                            &method.block,
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    functions
+}
+
+fn modules(file: &syn::File) -> Vec<Module> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Mod(module) => Some(Module {
+                name: module.ident.to_string(),
+                start_line: module.span().start().line,
+                end_line: module.span().end().line,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+// 包含这一行的范围最小的函数
+fn innermost(functions: &[Function], line: usize) -> Option<usize> {
+    functions
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.start_line <= line && line <= f.end_line)
+        .min_by_key(|(_, f)| f.end_line - f.start_line)
+        .map(|(index, _)| index)
+}
+
+// 把函数体换成 todo!()，保留换行使后面的行号不变
+fn stub_functions(source: &str, functions: &[Function], stubbed: &BTreeSet<usize>) -> String {
+    let mut bodies: Vec<(&Function, (usize, usize))> = stubbed
+        .iter()
+        .map(|&index| (&functions[index], functions[index].body))
+        .collect();
+    bodies.sort_by_key(|(_, body)| body.0);
+
+    let mut result = String::new();
+    let mut cursor = 0;
+    for (function, (start, end)) in bodies {
+        if start < cursor {
+            continue;
+        }
+        result.push_str(&source[cursor..start]);
+        let newlines = source[start..end].matches('\n').count();
+        // 还没开始写的函数按"未实现"处理，不算编译错误
+        let message = match function.empty {
+            true => String::new(),
+            false => t!("\"demo.rs 中的 {} {}\"", function.name, BROKEN_MARKER),
+        };
+        result.push_str(&format!(
+            "{{ todo!({}) {}}}",
+            message,
+            "\n".repeat(newlines)
+        ));
+        cursor = end;
+    }
+    result.push_str(&source[cursor..]);
+    result
+}
+
+// 把跳过的子模块替换成空行
+fn skip_modules(tests: &str, modules: &[Module], skipped: &[(String, String)]) -> String {
+    tests
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let skip = modules.iter().any(|module| {
+                module.contains(index + 1) && skipped.iter().any(|(name, _)| *name == module.name)
+            });
+            if skip {
+                "\n".to_string()
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
+}
+
+// 解析 libtest 的输出：
+//   test check_age::boundaries ... ok
+//   ---- check_age::boundaries stdout ----
+//   thread 'check_age::boundaries' panicked at tests.rs:12:9:
+//   assertion `left == right` failed: 12 岁应该是儿童
+// 单独运行一个函数的测试（libtest 按名称过滤），超出运行限制时加上一条被终止的记录
+fn run_group(binary: &Path, name: &str) -> Result<Group, String> {
+    let filter = format!("{}::", name);
+    let output = runner::execute(binary, &[filter.as_str()], false)?;
+    let mut group = parse_results(&output.stdout)
+        .into_iter()
+        .find(|group| group.name == name)
+        .unwrap_or_else(|| Group {
+            name: name.to_string(),
+            cases: Vec::new(),
+            compile_error: None,
+        });
+    if let Some(limit) = output.limit {
+        // 只输出了 "test greet::x ... " 还没有结果的就是被终止的测试
+        let unfinished = output
+            .stdout
+            .lines()
+            .filter_map(|line| line.trim_end().strip_prefix("test ")?.strip_suffix(" ..."))
+            .find_map(|path| path.split_once("::").map(|(_, case)| case.to_string()));
+        group.cases.push(TestCase {
+            name: unfinished.unwrap_or_else(|| tr("运行被终止").to_string()),
+            passed: false,
+            message: Some(limit.describe()),
+        });
+    }
+    Ok(group)
+}
+
+fn parse_results(stdout: &str) -> Vec<Group> {
+    let mut groups: Vec<Group> = Vec::new();
+
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("test ") else {
+            continue;
+        };
+        // 被终止的测试只有 "test x ... "，没有结果
+        let Some((path, result)) = rest.rsplit_once(" ... ") else {
+            continue;
+        };
+        if result.trim().is_empty() {
+            continue;
+        }
+        let (group_name, case_name) = path.split_once("::").unwrap_or((path, path));
+
+        let case = TestCase {
+            name: case_name.to_string(),
+            passed: result == "ok",
+            message: panic_message(stdout, path),
+        };
+        match groups.iter_mut().find(|group| group.name == group_name) {
+            Some(group) => group.cases.push(case),
+            None => groups.push(Group {
+                name: group_name.to_string(),
+                cases: vec![case],
+                compile_error: None,
+            }),
+        }
+    }
+
+    groups
+}
+
+fn panic_message(stdout: &str, path: &str) -> Option<String> {
+    let header = format!("---- {} stdout ----", path);
+    let section = stdout.split_once(&header)?.1;
+
+    let mut lines = section
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"));
+    lines.next()?;
+    let message: Vec<&str> = lines
+        .take_while(|line| {
+            !line.is_empty()
+                && !line.starts_with("note:")
+                && !line.starts_with("stack backtrace:")
+                && !line.starts_with("---- ")
+                && !line.starts_with("failures:")
+        })
+        .collect();
+    Some(message.join("\n"))
+}
+
+fn line_starts(source: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(source.match_indices('\n').map(|(index, _)| index + 1));
+    starts
+}
+
+// proc-macro2 的位置是 (行, 字符列)，换算成字节偏移
+fn offset(source: &str, starts: &[usize], position: LineColumn) -> usize {
+    let start = starts[position.line - 1];
+    source[start..]
+        .char_indices()
+        .nth(position.column)
+        .map(|(index, _)| start + index)
+        .unwrap_or(source.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STDOUT: &str = "
+running 4 tests
+test divide::by_zero ... ok
+test divide::whole_numbers ... FAILED
+test parse_number::valid ... FAILED
+test find_user::missing ... 
+test find_user::existing ... ok
+
+failures:
+
+---- divide::whole_numbers stdout ----
+
+thread 'divide::whole_numbers' panicked at tests.rs:12:9:
+assertion `left == right` failed
+  left: 2.5
+ right: 2.0
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- parse_number::valid stdout ----
+
+thread 'parse_number::valid' panicked at demo.rs:40:5:
+not yet implemented
+
+failures:
+    divide::whole_numbers
+    parse_number::valid
+";
+
+    #[test]
+    fn groups_cases_by_function() {
+        let groups = parse_results(STDOUT);
+        let names: Vec<&str> = groups.iter().map(|group| group.name.as_str()).collect();
+        assert_eq!(names, ["divide", "parse_number", "find_user"]);
+        assert_eq!(groups[0].cases.len(), 2);
+        // 没有结果的 "test x ... " 是被终止的测试，不计入
+        assert_eq!(groups[2].cases.len(), 1);
+    }
+
+    #[test]
+    fn keeps_panic_messages_and_verdicts() {
+        let groups = parse_results(STDOUT);
+        assert_eq!(groups[0].passed(), 1);
+        assert_eq!(
+            groups[0].cases[1].message.as_deref(),
+            Some("assertion `left == right` failed\n  left: 2.5\n right: 2.0")
+        );
+        assert_eq!(groups[0].verdict(), Verdict::Failed);
+        assert_eq!(groups[1].verdict(), Verdict::Unimplemented);
+        assert_eq!(groups[2].verdict(), Verdict::Passed);
+    }
+}
@@ -0,0 +1,146 @@
+// 终端输出辅助函数
+// 中文和 emoji 在终端里占两列，format! 的 {:<N} 按字符数对齐会错位
+
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // 组合用的附加符号、零宽连接符和 emoji 变体选择符不占列
+        0x0300..=0x036F | 0x200B..=0x200F | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0
+        | 0x23F3
+        | 0x2614..=0x2615
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26D4
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x274E
+        | 0x2753..=0x2755
+        | 0x2757
+        | 0x2795..=0x2797
+        | 0x27B0
+        | 0x27BF
+        | 0x2B50..=0x2B55
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1FAFF => 2,
+        _ => 1,
+    }
+}
+
+// 左对齐，补空格到指定显示宽度
+pub fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+// 右对齐
+pub fn pad_left(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{}", " ".repeat(padding), text)
+}
+
+// 输出到终端时包上 ANSI 颜色，重定向到文件或管道时原样返回
+pub fn paint(code: &str, text: &str, color: bool) -> String {
+    if color && !code.is_empty() {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+// 去掉 ANSI 颜色代码，用于在终端以外显示 rustc 的输出
+pub fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // ESC [ ... 字母
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+// 终端的列数和行数：优先用 COLUMNS / LINES，其次询问终端，都拿不到时按 80x24
+pub fn terminal_size() -> (usize, usize) {
+    let from_env = |name: &str| {
+        env::var(name)
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .filter(|value| *value > 0)
+    };
+    let (columns, rows) = window_size().unwrap_or((80, 24));
+    (
+        from_env("COLUMNS").unwrap_or(columns),
+        from_env("LINES").unwrap_or(rows),
+    )
+}
+
+#[cfg(unix)]
+fn window_size() -> Option<(usize, usize)> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } != 0 {
+        return None;
+    }
+    (size.ws_col > 0 && size.ws_row > 0).then_some((size.ws_col as usize, size.ws_row as usize))
+}
+
+#[cfg(not(unix))]
+fn window_size() -> Option<(usize, usize)> {
+    None
+}
+
+// 超过一屏的输出交给分页器（$PAGER，默认 less -R）；不是终端或分页器无法启动时直接打印
+pub fn page(text: &str) {
+    let (_, rows) = terminal_size();
+    if !io::stdout().is_terminal() || text.lines().count() < rows {
+        print!("{}", text);
+        return;
+    }
+
+    let pager = env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| "less -R".to_string());
+    let mut parts = pager.split_whitespace();
+    let Some(program) = parts.next() else {
+        print!("{}", text);
+        return;
+    };
+    let child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn();
+    let Ok(mut child) = child else {
+        print!("{}", text);
+        return;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // 在分页器中提前退出时写入会失败，忽略即可
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    let _ = child.wait();
+}
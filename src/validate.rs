@@ -0,0 +1,588 @@
+// 课程清单校验
+// 检查 course.toml 与 basic/ 目录、各模块的 demo.rs / answer.md、templates/ 中的原始模板以及 Cargo.toml 是否一致，
+// 以及 ts_names / rust_only / exercises_en 引用的名称是否存在、中文练习名称有没有英文标题，
+// 同时对比 demo.rs 与参考答案中的函数签名、泛型、派生和字段（见 signatures.rs），
+// 检查每个模块的英文讲解 answer.en.md 和 locales/en.toml 中有没有未翻译的内容，
+// 有任何不一致都返回错误；--fix 会根据清单重新生成 Cargo.toml 中的 [[example]] 列表
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::checker;
+use crate::compile_fail;
+use crate::course::{self, Course, Lesson, Manifest, ModuleEntry, LESSON_FILES};
+use crate::coverage;
+use crate::hint;
+use crate::i18n::{self, t, tr};
+use crate::signatures;
+use crate::solution;
+
+pub fn validate(course: &Course, fix: bool) -> Result<(), String> {
+    let manifest_path = Manifest::path(&course.root);
+    let Some(manifest) = &course.manifest else {
+        return Err(t!(
+            "找不到课程清单 {}，课程顺序和标题无从校验",
+            manifest_path.display()
+        ));
+    };
+
+    println!("{}", t!("🔎 校验 {}", manifest_path.display()));
+    let mut problems = Vec::new();
+
+    let mut seen: Vec<&str> = Vec::new();
+    for entry in &manifest.modules {
+        if seen.contains(&entry.id.as_str()) {
+            problems.push(t!("{} 在清单中出现了多次", entry.id));
+        }
+        check_entry(course, entry, &seen, &mut problems);
+        seen.push(&entry.id);
+    }
+
+    check_translations(&course.root, &mut problems);
+
+    // basic/ 下有、清单里没有的目录
+    for lesson in &course.lessons {
+        if manifest.entry(&lesson.id).is_none() {
+            problems.push(t!("basic/{} 没有列在 course.toml 中", lesson.id));
+        }
+    }
+    for lesson in &course.incomplete {
+        if manifest.entry(&lesson.id).is_none() {
+            problems.push(t!(
+                "basic/{} 没有列在 course.toml 中（而且缺少 {}）",
+                lesson.id,
+                lesson.missing.join(", ")
+            ));
+        }
+    }
+
+    let cargo_path = course.root.join("Cargo.toml");
+    let read_cargo = || {
+        fs::read_to_string(&cargo_path).map_err(|e| t!("无法读取 {}: {}", cargo_path.display(), e))
+    };
+    if fix {
+        fix_examples(&cargo_path, &read_cargo()?, &examples_section(manifest))?;
+    }
+    let examples_differ = check_cargo(&read_cargo()?, manifest, &mut problems)?;
+
+    if problems.is_empty() {
+        println!(
+            "{}",
+            t!(
+                "✅ course.toml 与 basic/ 目录一致（{} 个模块）",
+                manifest.modules.len()
+            )
+        );
+        return Ok(());
+    }
+
+    println!();
+    for problem in &problems {
+        println!("  ❌ {}", problem);
+    }
+    if examples_differ {
+        println!(
+            "{}",
+            tr("  💡 运行 cargo run -- validate --fix 可以重新生成 [[example]]")
+        );
+    }
+    println!();
+    Err(t!("course.toml 有 {} 处不一致", problems.len()))
+}
+
+fn check_entry(course: &Course, entry: &ModuleEntry, earlier: &[&str], problems: &mut Vec<String>) {
+    let id = &entry.id;
+    let dir = course.root.join("basic").join(id);
+    if !dir.is_dir() {
+        problems.push(t!("{}: 目录 basic/{} 不存在", id, id));
+        return;
+    }
+    for file in LESSON_FILES {
+        if !dir.join(file).is_file() {
+            problems.push(t!("{}: 缺少 {}", id, file));
+        }
+    }
+    // reset 使用的原始模板；demo.rs 会被学习者修改，练习清单和签名都以模板为准
+    let template = course.root.join("templates").join(id).join("demo.rs");
+    if !template.is_file() {
+        problems.push(t!("{0}: 缺少原始模板 templates/{0}/demo.rs", id));
+    }
+
+    if let Some(title) = course::read_title(&dir.join("answer.md")) {
+        if title != entry.title {
+            problems.push(t!(
+                "{}: 标题是「{}」，但 answer.md 的标题是「{}」",
+                id,
+                entry.title,
+                title
+            ));
+        }
+    }
+
+    for prerequisite in &entry.prerequisites {
+        if !earlier.contains(&prerequisite.as_str()) {
+            problems.push(t!("{}: 先修模块 {} 不存在或排在它后面", id, prerequisite));
+        }
+    }
+
+    check_english_answer(&dir, entry, problems);
+    check_exercises(&template, entry, problems);
+    check_exercise_titles(&dir, entry, problems);
+    check_coverage_names(&dir.join("example.ts"), entry, problems);
+    if let Some(lesson) = course.lessons.iter().find(|lesson| lesson.id == *id) {
+        check_signatures(lesson, &template, problems);
+    }
+}
+
+// answer.en.md 是 answer.md 的英文版：标题与 title_en 一致，代码块保持原样，代码块之外不应再有中文
+fn check_english_answer(dir: &Path, entry: &ModuleEntry, problems: &mut Vec<String>) {
+    let path = dir.join("answer.en.md");
+    let Ok(content) = fs::read_to_string(&path) else {
+        problems.push(t!("{}: 缺少英文讲解 answer.en.md", entry.id));
+        return;
+    };
+
+    if let Some(title) = course::read_title(&path) {
+        if title != entry.title_en {
+            problems.push(t!(
+                "{}: 英文标题是「{}」，但 answer.en.md 的标题是「{}」",
+                entry.id,
+                entry.title_en,
+                title
+            ));
+        }
+    }
+
+    let original = fs::read_to_string(dir.join("answer.md")).unwrap_or_default();
+    let code = |content: String| -> Vec<String> {
+        Answer { content }
+            .code_blocks()
+            .into_iter()
+            .map(|block| block.code)
+            .collect()
+    };
+    if code(original) != code(content.clone()) {
+        problems.push(t!(
+            "{}: answer.en.md 的代码块与 answer.md 不一致（只翻译讲解文字，代码保持原样）",
+            entry.id
+        ));
+    }
+
+    let mut in_code = false;
+    let mut untranslated = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if !in_code && i18n::has_chinese(line) {
+            untranslated.push((index + 1).to_string());
+        }
+    }
+    if !untranslated.is_empty() {
+        problems.push(t!(
+            "{}: answer.en.md 第 {} 行还没有翻译",
+            entry.id,
+            untranslated.join(", ")
+        ));
+    }
+}
+
+// 源码中 tr / t! 用到的文案都要在 locales/en.toml 中有译文，占位符数量一致，目录中也不留已经不用的文案；
+// 只有课程内容、没有源码的目录跳过这项检查
+fn check_translations(root: &Path, problems: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(root.join("src")) else {
+        return;
+    };
+    let catalog_path = root.join("locales").join("en.toml");
+    let catalog = fs::read_to_string(&catalog_path)
+        .map_err(|e| t!("无法读取 {}: {}", catalog_path.display(), e))
+        .and_then(|content| i18n::parse_catalog(&content));
+    let catalog = match catalog {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            problems.push(e);
+            return;
+        }
+    };
+
+    let mut files: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    files.sort();
+
+    let mut used = BTreeSet::new();
+    for file in files {
+        let Ok(source) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        let keys = match i18n::keys(&source) {
+            Ok(keys) => keys,
+            Err(e) => {
+                problems.push(t!("src/{} 无法解析，{}", name, e));
+                continue;
+            }
+        };
+        for (key, line) in keys {
+            match catalog.get(&key) {
+                None => problems.push(t!("未翻译的文案（src/{}:{}）: {}", name, line, key)),
+                Some(value) if i18n::placeholders(value) != i18n::placeholders(&key) => problems
+                    .push(t!(
+                        "译文的占位符数量与原文不一致（src/{}:{}）: {}",
+                        name,
+                        line,
+                        key
+                    )),
+                Some(_) => {}
+            }
+            used.insert(key);
+        }
+    }
+
+    for key in catalog.keys() {
+        if !used.contains(key) {
+            problems.push(t!("locales/en.toml 中的文案已经不再使用: {}", key));
+        }
+    }
+}
+
+// 模板中承诺的签名、泛型、派生和字段必须与 answer.md 的参考答案一致
+fn check_signatures(lesson: &Lesson, template: &Path, problems: &mut Vec<String>) {
+    let Ok(demo) = fs::read_to_string(template) else {
+        return;
+    };
+    let result = solution::assemble(lesson).and_then(|answer| signatures::compare(&demo, &answer));
+    match result {
+        Ok(differences) => problems.extend(
+            differences
+                .into_iter()
+                .map(|difference| format!("{}: {}", lesson.id, difference)),
+        ),
+        Err(e) => problems.push(t!("{}: 无法对比签名，{}", lesson.id, e)),
+    }
+}
+
+// 清单中的练习必须和模板 demo.rs 中 check 识别出的练习一一对应
+fn check_exercises(demo: &Path, entry: &ModuleEntry, problems: &mut Vec<String>) {
+    let Ok(source) = fs::read_to_string(demo) else {
+        return;
+    };
+    let names = match checker::task_names(&source) {
+        Ok(names) => names,
+        Err(e) => {
+            problems.push(t!("{}: demo.rs 无法解析，{}", entry.id, e));
+            return;
+        }
+    };
+
+    for exercise in &entry.exercises {
+        if !names.contains(exercise) {
+            problems.push(t!(
+                "{}: 清单中的练习「{}」在 demo.rs 中找不到",
+                entry.id,
+                exercise
+            ));
+        }
+    }
+    for name in &names {
+        if !entry.exercises.contains(name) {
+            problems.push(t!(
+                "{}: demo.rs 中的练习「{}」没有列在清单中",
+                entry.id,
+                name
+            ));
+        }
+    }
+}
+
+// main 中的练习和编译失败练习的名称是中文，都要在 exercises_en 中有英文标题；
+// 函数和类型名本身就是英文，不需要翻译
+fn check_exercise_titles(dir: &Path, entry: &ModuleEntry, problems: &mut Vec<String>) {
+    let compile_fail = match compile_fail::names(dir) {
+        Ok(names) => names,
+        Err(e) => {
+            problems.push(format!("{}: {}", entry.id, e));
+            return;
+        }
+    };
+    let named: Vec<&String> = entry
+        .exercises
+        .iter()
+        .filter(|name| hint::exercise_number(name).is_some())
+        .chain(&compile_fail)
+        .collect();
+
+    for name in &named {
+        if !entry.exercises_en.contains_key(*name) {
+            problems.push(t!(
+                "{}: 练习「{}」在 exercises_en 中没有英文标题",
+                entry.id,
+                name
+            ));
+        }
+    }
+    for name in entry.exercises_en.keys() {
+        if !named.contains(&name) {
+            problems.push(t!(
+                "{}: exercises_en 中的「{}」不是 main 中的练习或编译失败练习",
+                entry.id,
+                name
+            ));
+        }
+    }
+}
+
+// ts_names 的键必须是 example.ts 中的声明，值和 rust_only 必须是清单中的练习
+fn check_coverage_names(example: &Path, entry: &ModuleEntry, problems: &mut Vec<String>) {
+    let Ok(source) = fs::read_to_string(example) else {
+        return;
+    };
+    let declared = coverage::parse_typescript(&source);
+
+    for (ts, rust) in &entry.ts_names {
+        if !declared.iter().any(|item| item.name == *ts) {
+            problems.push(t!(
+                "{}: ts_names 中的「{}」在 example.ts 中找不到",
+                entry.id,
+                ts
+            ));
+        }
+        if !rust.is_empty() && !entry.exercises.contains(rust) {
+            problems.push(t!(
+                "{}: ts_names 把「{}」对应到「{}」，但它不是清单中的练习",
+                entry.id,
+                ts,
+                rust
+            ));
+        }
+    }
+    for name in &entry.rust_only {
+        if !entry.exercises.contains(name) {
+            problems.push(t!(
+                "{}: rust_only 中的「{}」不是清单中的练习",
+                entry.id,
+                name
+            ));
+        }
+    }
+}
+
+// Cargo.toml 中的 features 和 [[example]] 必须与清单一致，返回 [[example]] 是否需要重新生成
+fn check_cargo(
+    cargo: &str,
+    manifest: &Manifest,
+    problems: &mut Vec<String>,
+) -> Result<bool, String> {
+    let table: toml::Table = toml::from_str(cargo).map_err(|e| t!("Cargo.toml 格式错误: {}", e))?;
+    let before = problems.len();
+
+    let examples: Vec<Example> = table
+        .get("example")
+        .and_then(|value| value.as_array())
+        .map(|examples| examples.iter().map(Example::from_toml).collect())
+        .unwrap_or_default();
+
+    for entry in &manifest.modules {
+        let expected = Example::from_entry(entry);
+        match examples.iter().find(|example| example.name == expected.name) {
+            None => problems.push(t!(
+                "{}: Cargo.toml 中缺少 [[example]] {}",
+                entry.id, expected.name
+            )),
+            Some(example) if *example != expected => problems.push(t!(
+                "{}: Cargo.toml 中的 [[example]] {} 与清单不一致（path = {}，required-features = {}）",
+                entry.id, expected.name, format!("{:?}", expected.path), format!("{:?}", expected.features)
+            )),
+            Some(_) => {}
+        }
+    }
+    for example in &examples {
+        if !manifest
+            .modules
+            .iter()
+            .any(|entry| course::example_name(&entry.id) == example.name)
+        {
+            problems.push(t!(
+                "Cargo.toml 中的 [[example]] {} 不对应清单中的任何模块",
+                example.name
+            ));
+        }
+    }
+
+    let names: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+    let expected: Vec<String> = manifest
+        .modules
+        .iter()
+        .map(|entry| course::example_name(&entry.id))
+        .collect();
+    if problems.len() == before && names != expected {
+        problems.push(tr("Cargo.toml 中 [[example]] 的顺序与清单不一致").to_string());
+    }
+    let examples_differ = problems.len() > before;
+
+    let features = table.get("features").and_then(|value| value.as_table());
+    for entry in &manifest.modules {
+        for feature in &entry.features {
+            if !features.is_some_and(|features| features.contains_key(feature)) {
+                problems.push(t!(
+                    "{}: Cargo.toml 中没有定义 feature {}",
+                    entry.id,
+                    feature
+                ));
+            }
+        }
+    }
+    Ok(examples_differ)
+}
+
+#[derive(Debug, PartialEq)]
+struct Example {
+    name: String,
+    path: String,
+    features: Vec<String>,
+}
+
+impl Example {
+    fn from_entry(entry: &ModuleEntry) -> Example {
+        Example {
+            name: course::example_name(&entry.id),
+            path: format!("basic/{}/demo.rs", entry.id),
+            features: entry.features.clone(),
+        }
+    }
+
+    fn from_toml(value: &toml::Value) -> Example {
+        let text = |key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let features = value
+            .get("required-features")
+            .and_then(|v| v.as_array())
+            .map(|features| {
+                features
+                    .iter()
+                    .filter_map(|feature| feature.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Example {
+            name: text("name"),
+            path: text("path"),
+            features,
+        }
+    }
+}
+
+// 按清单顺序生成的 [[example]] 列表
+fn examples_section(manifest: &Manifest) -> String {
+    let mut section =
+        String::from("# 示例程序配置（由 course.toml 生成：cargo run -- validate --fix）\n");
+    for entry in &manifest.modules {
+        let example = Example::from_entry(entry);
+        section.push_str(&format!(
+            "[[example]]\nname = \"{}\"\npath = \"{}\"\n",
+            example.name, example.path
+        ));
+        if !example.features.is_empty() {
+            let features: Vec<String> = example
+                .features
+                .iter()
+                .map(|feature| format!("\"{}\"", feature))
+                .collect();
+            section.push_str(&format!("required-features = [{}]\n", features.join(", ")));
+        }
+        section.push('\n');
+    }
+    section.truncate(section.trim_end().len());
+    section.push('\n');
+    section
+}
+
+// [[example]] 必须位于 Cargo.toml 末尾，从第一个 [[example]]（及其上方的注释）开始整段替换
+fn fix_examples(path: &Path, cargo: &str, section: &str) -> Result<(), String> {
+    let lines: Vec<&str> = cargo.lines().collect();
+    let Some(first) = lines.iter().position(|line| line.trim() == "[[example]]") else {
+        return Err(tr("Cargo.toml 中没有 [[example]]，无法自动修复").to_string());
+    };
+    if lines[first..]
+        .iter()
+        .any(|line| line.trim_start().starts_with('[') && line.trim() != "[[example]]")
+    {
+        return Err(tr("Cargo.toml 中 [[example]] 之后还有其他配置段，无法自动修复").to_string());
+    }
+
+    let mut start = first;
+    while start > 0 && lines[start - 1].trim_start().starts_with('#') {
+        start -= 1;
+    }
+
+    let mut updated: String = lines[..start]
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect();
+    updated.push_str(section);
+    if updated == cargo {
+        println!("{}", tr("✨ Cargo.toml 中的 [[example]] 已经与清单一致"));
+        return Ok(());
+    }
+
+    fs::write(path, &updated).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    println!(
+        "{}",
+        tr("🔧 已根据清单重新生成 Cargo.toml 中的 [[example]]")
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn learner_helpers_in_demo_do_not_fail_validation() {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root =
+            std::env::temp_dir().join(format!("rust-tutorial-validate-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        copy_dir(&source.join("basic"), &root.join("basic"));
+        copy_dir(&source.join("templates"), &root.join("templates"));
+        fs::copy(source.join("course.toml"), root.join("course.toml")).unwrap();
+
+        // 学习者在 demo.rs 里加了自己的辅助函数
+        let demo = root.join("basic").join("functions").join("demo.rs");
+        let mut content = fs::read_to_string(&demo).unwrap();
+        content.push_str("\nfn helper() -> i32 {\n    1\n}\n");
+        fs::write(&demo, content).unwrap();
+
+        let course = Course::load(&root).unwrap();
+        let manifest = course.manifest.as_ref().unwrap();
+        let mut problems = Vec::new();
+        let mut seen = Vec::new();
+        for entry in &manifest.modules {
+            check_entry(&course, entry, &seen, &mut problems);
+            seen.push(entry.id.as_str());
+        }
+        fs::remove_dir_all(&root).unwrap();
+        assert!(problems.is_empty(), "{:?}", problems);
+    }
+}
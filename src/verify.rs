@@ -0,0 +1,278 @@
+// 输出对比
+// 运行 answer.md 中的参考程序，把标准输出保存为 basic/<id>/expected_output.txt，
+// 再运行学习者的 demo.rs，逐行对比两者的输出
+
+use std::fs;
+use std::path::Path;
+
+use crate::course::{Course, Lesson};
+use crate::diff::{self, Change};
+use crate::i18n::{t, tr};
+use crate::runner::{self, BuildDir};
+use crate::solution;
+
+// 对格式差异的容忍程度；耗时（Duration 的 Debug 输出）每次运行都不同，始终忽略
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance {
+    // 5 和 5.0、2.50 和 2.5 视为相同
+    pub floats: bool,
+    // 忽略行首行尾空白，连续空白视为一个空格
+    pub whitespace: bool,
+}
+
+pub fn verify(
+    course: &Course,
+    module: &str,
+    update: bool,
+    tolerance: Tolerance,
+) -> Result<(), String> {
+    let lesson = course.find(module)?;
+    let golden_path = lesson.golden_path();
+    let build_dir = BuildDir::new(&lesson.id)?;
+
+    if update || !golden_path.exists() {
+        let lines = record_golden(lesson, build_dir.path())?;
+        println!(
+            "{}",
+            t!(
+                "📝 已根据参考答案生成 {}（{} 行）",
+                golden_path.display(),
+                lines
+            )
+        );
+        if update {
+            return Ok(());
+        }
+    }
+
+    let expected = fs::read_to_string(&golden_path)
+        .map_err(|e| t!("无法读取 {}: {}", golden_path.display(), e))?;
+
+    println!("{}", t!("🔨 编译 {} ...", lesson.demo_path().display()));
+    let compiled = runner::compile(&lesson.demo_path(), build_dir.path())?;
+    if !compiled.success {
+        eprint!("{}", compiled.rendered());
+        return Err(t!(
+            "{} 未能通过编译（{} 个错误），先用 check 完成练习",
+            lesson.id,
+            compiled.errors()
+        ));
+    }
+
+    let output = runner::execute(&compiled.binary, &[], false)?;
+    if let Some(limit) = output.limit {
+        println!("{}", t!("⛔ {}，只对比已经输出的内容", limit.describe()));
+    } else if !output.success {
+        println!(
+            "{}",
+            t!(
+                "⚠️  程序{}，只对比已经输出的内容",
+                runner::describe_status(output.success, output.exit_code)
+            )
+        );
+        eprint!("{}", output.stderr);
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = output.stdout.lines().collect();
+    let changes = diff::pairs(&expected_lines, &actual_lines, |a, b| {
+        normalize(a, tolerance) == normalize(b, tolerance)
+    });
+
+    let count = |wanted: fn(&Change) -> bool| changes.iter().filter(|c| wanted(c)).count();
+    let tolerated = count(|c| matches!(c, Change::Similar(a, b) if !only_timing(a, b)));
+    let missing = count(|c| matches!(c, Change::Removed(_)));
+    let extra = count(|c| matches!(c, Change::Added(_)));
+
+    println!(
+        "{}",
+        t!(
+            "🧪 {} 的输出与 {} 对比",
+            lesson.id,
+            golden_path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+        )
+    );
+
+    if missing == 0 && extra == 0 {
+        println!(
+            "{}",
+            t!("✅ 输出与参考答案一致（{} 行）", expected_lines.len())
+        );
+        print_tolerated(&changes, tolerated);
+        return if output.success {
+            Ok(())
+        } else {
+            Err(t!("{} 的输出一致，但程序没有正常退出", lesson.id))
+        };
+    }
+
+    println!();
+    diff::print(&changes, tr("参考输出"), tr("你的输出"));
+    println!();
+    println!(
+        "{}",
+        t!(
+            "📊 {} 行一致，{} 行缺失，{} 行多出",
+            changes.len() - missing - extra,
+            missing,
+            extra
+        )
+    );
+    print_tolerated(&changes, tolerated);
+    Err(t!("{} 的输出与参考答案不一致", lesson.id))
+}
+
+// 编译并运行参考答案，把标准输出写入 golden 文件，返回行数
+fn record_golden(lesson: &Lesson, build_dir: &Path) -> Result<usize, String> {
+    let (_, compiled) = solution::build(lesson, build_dir)?;
+    if !compiled.success {
+        eprint!("{}", compiled.rendered());
+        return Err(t!("{} 的参考答案无法编译，请检查 answer.md", lesson.id));
+    }
+
+    let output = runner::execute(&compiled.binary, &[], false)?;
+    if !output.success {
+        eprint!("{}", output.stderr);
+        let reason = match output.limit {
+            Some(limit) => limit.describe(),
+            None => runner::describe_status(output.success, output.exit_code),
+        };
+        return Err(t!("{} 的参考答案运行失败（{}）", lesson.id, reason));
+    }
+
+    let path = lesson.golden_path();
+    fs::write(&path, &output.stdout).map_err(|e| t!("无法写入 {}: {}", path.display(), e))?;
+    Ok(output.stdout.lines().count())
+}
+
+// 仅格式不同的行单独列出，方便学习者决定是否要完全一致
+fn print_tolerated(changes: &[Change], tolerated: usize) {
+    if tolerated == 0 {
+        return;
+    }
+    println!("{}", t!("🟡 {} 行仅浮点格式或空白不同，已忽略:", tolerated));
+    for change in changes {
+        if let Change::Similar(expected, actual) = change {
+            if only_timing(expected, actual) {
+                continue;
+            }
+            println!("{}", t!("   参考: {}", expected));
+            println!("{}", t!("   你的: {}", actual));
+        }
+    }
+}
+
+// 只有耗时不同的行不需要提醒学习者
+fn only_timing(a: &str, b: &str) -> bool {
+    let exact = Tolerance {
+        floats: false,
+        whitespace: false,
+    };
+    normalize(a, exact) == normalize(b, exact)
+}
+
+// 把一行输出转换成用于比较的形式
+fn normalize(line: &str, tolerance: Tolerance) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let starts_number = c.is_ascii_digit()
+            && (i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_'));
+        if !starts_number {
+            result.push(c);
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i + 1 < chars.len() && chars[i] == '.' && chars[i + 1].is_ascii_digit() {
+            i += 1;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+        let number: String = chars[start..i].iter().collect();
+
+        if let Some(unit) = duration_unit(&chars[i..]) {
+            result.push_str("<耗时>");
+            i += unit;
+        } else if tolerance.floats && number.contains('.') {
+            result.push_str(number.trim_end_matches('0').trim_end_matches('.'));
+        } else {
+            result.push_str(&number);
+        }
+    }
+
+    if tolerance.whitespace {
+        result.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        result
+    }
+}
+
+// 数字后面紧跟的时间单位（1.2ms、35µs、2s），返回单位占用的字符数
+fn duration_unit(rest: &[char]) -> Option<usize> {
+    ["ns", "µs", "us", "ms", "s"].iter().find_map(|unit| {
+        let len = unit.chars().count();
+        let matches = rest.len() >= len && rest[..len].iter().copied().eq(unit.chars());
+        let ends = rest.get(len).is_none_or(|c| !c.is_alphanumeric());
+        (matches && ends).then_some(len)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LENIENT: Tolerance = Tolerance {
+        floats: true,
+        whitespace: true,
+    };
+    const STRICT: Tolerance = Tolerance {
+        floats: false,
+        whitespace: false,
+    };
+
+    #[test]
+    fn trims_trailing_zeros_when_floats_are_tolerated() {
+        assert_eq!(
+            normalize("面积: 5.0", LENIENT),
+            normalize("面积: 5", LENIENT)
+        );
+        assert_eq!(normalize("价格 2.50 元", LENIENT), "价格 2.5 元");
+        assert_eq!(normalize("价格 2.50 元", STRICT), "价格 2.50 元");
+        // 整数末尾的 0 不能去掉
+        assert_eq!(normalize("100", LENIENT), "100");
+    }
+
+    #[test]
+    fn digits_inside_identifiers_are_not_numbers() {
+        assert_eq!(normalize("v2.0 x1.50", LENIENT), "v2.0 x1.50");
+        assert_eq!(normalize("user_10.0", LENIENT), "user_10.0");
+    }
+
+    #[test]
+    fn collapses_whitespace_only_when_tolerated() {
+        assert_eq!(normalize("  a   b\t", LENIENT), "a b");
+        assert_eq!(normalize("  a   b\t", STRICT), "  a   b\t");
+    }
+
+    #[test]
+    fn durations_are_always_masked() {
+        assert_eq!(normalize("耗时 1.25ms", STRICT), "耗时 <耗时>");
+        assert_eq!(normalize("35µs / 2s", STRICT), "<耗时> / <耗时>");
+        // 单位后面还有字母时不是时间
+        assert_eq!(normalize("3 steps, 4sides", STRICT), "3 steps, 4sides");
+        assert!(only_timing("用时 3ms", "用时 12ms"));
+        assert!(!only_timing("结果 3.0", "结果 3"));
+    }
+}
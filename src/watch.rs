@@ -0,0 +1,198 @@
+// 监视模式：保存 demo.rs 后自动重新检查
+// 通过轮询文件修改时间实现，不需要额外依赖；全部练习和隐藏测试通过后自动进入下一个模块
+
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::checker::{self, CheckReport, Status, TaskKind};
+use crate::course::{Course, Lesson};
+use crate::explain;
+use crate::i18n::{t, tr};
+use crate::progress::{self, Progress};
+use crate::testsuite;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn watch(course: &Course, module: Option<&str>) -> Result<(), String> {
+    let mut lesson = match module {
+        Some(module) => course.find(module)?.clone(),
+        None => first_unfinished(course)?,
+    };
+
+    loop {
+        // 在检查之前记下修改时间，检查期间保存的修改也能触发下一次检查
+        let checked = modified(&lesson.demo_path())?;
+        if check_once(course, &lesson)? {
+            println!("{}", t!("🎉 {} 的所有练习都已通过！", lesson.id));
+            match course.next_after(&lesson.id) {
+                Some(next) => {
+                    println!(
+                        "{}",
+                        t!("➡️  进入下一个模块: {} - {}", next.id, next.label())
+                    );
+                    println!();
+                    lesson = next.clone();
+                    continue;
+                }
+                None => {
+                    println!(
+                        "{}",
+                        t!("🏆 恭喜完成全部 {} 个基础模块！", course.lessons.len())
+                    );
+                    return Ok(());
+                }
+            }
+        }
+
+        println!();
+        println!(
+            "{}",
+            t!(
+                "👀 正在监视 {}（Ctrl+C 退出）",
+                lesson.demo_path().display()
+            )
+        );
+        wait_for_change(&lesson.demo_path(), checked)?;
+    }
+}
+
+// 没有指定模块时，从第一个还没完成的模块开始
+fn first_unfinished(course: &Course) -> Result<Lesson, String> {
+    let progress = Progress::load(&Progress::path(course))?;
+    course
+        .lessons
+        .iter()
+        .find(|lesson| {
+            !progress
+                .module(&lesson.id)
+                .is_some_and(|module| module.is_complete())
+        })
+        .or(course.lessons.last())
+        .cloned()
+        .ok_or_else(|| tr("课程中没有任何模块").to_string())
+}
+
+// 检查一次并记录进度，返回练习和隐藏测试是否全部通过
+fn check_once(course: &Course, lesson: &Lesson) -> Result<bool, String> {
+    if io::stdout().is_terminal() {
+        // 清屏并把光标移到左上角
+        print!("\x1b[2J\x1b[H");
+    }
+
+    println!("{}", t!("🔍 检查 {} - {}", lesson.id, lesson.label()));
+    let report = checker::check(lesson)?;
+    // 练习还没全部完成时模块肯定没有完成，不必再花时间编译隐藏测试
+    let suite = if report.is_complete() {
+        testsuite::run(lesson)?
+    } else {
+        None
+    };
+    let tests_passing = suite.as_ref().is_none_or(|suite| suite.is_passing());
+    progress::update(course, |progress| {
+        progress.record_check(&lesson.id, &report, tests_passing)
+    });
+
+    println!(
+        "{}",
+        t!(
+            "📊 完成 {}/{}，可编译 {}，有错误 {}，未开始 {}",
+            report.count(Status::Done),
+            report.tasks.len(),
+            report.count(Status::Compiles),
+            report.count(Status::Broken),
+            report.count(Status::Empty)
+        )
+    );
+
+    print_first_failure(&report);
+    if let Some(suite) = suite.filter(|suite| !suite.is_passing()) {
+        println!();
+        testsuite::print(&suite);
+    }
+    Ok(report.is_complete() && tests_passing)
+}
+
+// 只显示第一个没通过的练习，避免被一屏错误淹没
+fn print_first_failure(report: &CheckReport) {
+    if let Some(error) = &report.parse_error {
+        println!();
+        println!("{}", t!("❌ 语法错误: {}", error));
+        eprint!("{}", report.compile.rendered());
+        explain::print_for(&report.compile.diagnostics);
+        return;
+    }
+
+    let Some(task) = report.tasks.iter().find(|task| task.status != Status::Done) else {
+        if !report.other_errors.is_empty() {
+            println!();
+            println!("{}", tr("❌ 还有不属于任何练习的编译错误:"));
+            for error in &report.other_errors {
+                eprint!("{}", error.rendered);
+            }
+            explain::print_for(&report.other_errors);
+        }
+        return;
+    };
+
+    println!();
+    println!(
+        "{}",
+        t!(
+            "👉 下一个练习: {} {}（{}，{}）",
+            task.status.icon(),
+            task.title,
+            task.status.label(),
+            task.location()
+        )
+    );
+
+    match (task.status, task.kind) {
+        (Status::Empty, _) => println!("{}", tr("   还没有开始，动手写第一行代码吧")),
+        (Status::Compiles, TaskKind::CompileFail) => {
+            println!(
+                "{}",
+                tr("   这个文件还能编译通过，预期的编译错误还没有出现")
+            )
+        }
+        // 运行过时显示本练习的结果；整个文件还不能编译时没有运行结果
+        (Status::Compiles, _) => match &task.outcome {
+            Some(outcome) => println!("   {} {}", outcome.icon(), outcome.describe()),
+            None => println!(
+                "{}",
+                tr("   本练习可以编译，等其他练习完成后整个文件才能运行")
+            ),
+        },
+        _ => {}
+    }
+
+    if task.status == Status::Broken
+        || (task.status == Status::Compiles && task.kind == TaskKind::CompileFail)
+    {
+        println!();
+        for error in &task.errors {
+            eprint!("{}", error.rendered);
+        }
+        explain::print_for(&task.errors);
+    }
+}
+
+fn wait_for_change(path: &Path, initial: SystemTime) -> Result<(), String> {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        // 编辑器保存时可能短暂删除文件，读取失败就继续等待
+        if let Ok(current) = modified(path) {
+            if current != initial {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Result<SystemTime, String> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| t!("无法读取 {} 的修改时间: {}", path.display(), e))
+}